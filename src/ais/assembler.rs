@@ -0,0 +1,326 @@
+use super::bits::BitVector;
+use super::structs::{ClassBStaticData, StaticDataPart, StaticDataReport};
+use crate::error::Error;
+use crate::fields::identity::AISChannel;
+use crate::messages::VDMMessage;
+use heapless::Vec;
+
+/// Maximum number of multi-sentence messages being reassembled at the same time
+pub const MAX_PENDING_MESSAGES: usize = 4;
+
+#[derive(Debug)]
+struct Pending {
+    message_id: Option<u8>,
+    channel: Option<AISChannel>,
+    fragment_count: u8,
+    received: u8,
+    bits: BitVector,
+}
+
+/// Reassembles AIS messages split into several VDM/VDO sentences.
+///
+/// Fragments are matched by sequential message identifier and channel,
+/// and must be received in order. When more than `MAX_PENDING_MESSAGES` messages
+/// are being reassembled, the oldest one is dropped.
+///
+/// # Examples
+///
+/// ```
+/// # use nmea_0183::ais::FragmentAssembler;
+/// # use nmea_0183::{parse, Message};
+/// let mut assembler = FragmentAssembler::new();
+/// let sentence = parse("!AIVDM,1,1,,B,177KQJ5000G?tO`K>RA1wUbN0TKH,0*5C\r\n").unwrap();
+/// if let Message::VDM(vdm) = sentence.message {
+///     let bits = assembler.push(&vdm).unwrap().unwrap();
+///     assert_eq!(168, bits.len());
+/// }
+/// ```
+#[derive(Debug, Default)]
+pub struct FragmentAssembler {
+    pending: Vec<Pending, MAX_PENDING_MESSAGES>,
+}
+
+impl FragmentAssembler {
+    pub const fn new() -> Self {
+        FragmentAssembler {
+            pending: Vec::new(),
+        }
+    }
+
+    /// Drops the messages being reassembled.
+    pub fn reset(&mut self) {
+        self.pending.clear();
+    }
+
+    /// Feeds a fragment to the assembler.
+    ///
+    /// Returns the bits of the message once its last fragment has been received,
+    /// and `Error::UnexpectedFragment` if the fragment doesn't follow the previous one.
+    pub fn push(&mut self, fragment: &VDMMessage) -> Result<Option<BitVector>, Error> {
+        if fragment.fragment_number > fragment.fragment_count {
+            return Err(Error::UnexpectedFragment);
+        }
+        if fragment.fragment_count == 1 {
+            return BitVector::from_armoured(fragment.payload, fragment.fill_bits).map(Some);
+        }
+
+        let position = self.pending.iter().position(|pending| {
+            pending.message_id == fragment.message_id && pending.channel == fragment.channel
+        });
+
+        if fragment.fragment_number == 1 {
+            if let Some(position) = position {
+                self.pending.remove(position);
+            } else if self.pending.is_full() {
+                self.pending.remove(0);
+            }
+            let bits = BitVector::from_armoured(fragment.payload, fragment.fill_bits)?;
+            // There is room since a message has been removed if the list was full
+            let _ = self.pending.push(Pending {
+                message_id: fragment.message_id,
+                channel: fragment.channel,
+                fragment_count: fragment.fragment_count,
+                received: 1,
+                bits,
+            });
+            return Ok(None);
+        }
+
+        let position = position.ok_or(Error::UnexpectedFragment)?;
+        let pending = &mut self.pending[position];
+        if pending.fragment_count != fragment.fragment_count
+            || pending.received + 1 != fragment.fragment_number
+        {
+            self.pending.remove(position);
+            return Err(Error::UnexpectedFragment);
+        }
+        if let Err(err) = pending
+            .bits
+            .push_armoured(fragment.payload, fragment.fill_bits)
+        {
+            self.pending.remove(position);
+            return Err(err);
+        }
+        pending.received += 1;
+
+        if pending.received == pending.fragment_count {
+            Ok(Some(self.pending.remove(position).bits))
+        } else {
+            Ok(None)
+        }
+    }
+}
+
+/// Maximum number of vessels whose static data report is waiting for its other part
+pub const MAX_PENDING_STATIC_DATA: usize = 8;
+
+/// Combines both parts of the Class B static data reports, matched by MMSI.
+///
+/// Parts can be received in any order. When more than `MAX_PENDING_STATIC_DATA` vessels
+/// are waiting for their other part, the oldest one is dropped.
+#[derive(Debug, Default)]
+pub struct StaticDataAssembler {
+    pending: Vec<StaticDataReport, MAX_PENDING_STATIC_DATA>,
+}
+
+impl StaticDataAssembler {
+    pub const fn new() -> Self {
+        StaticDataAssembler {
+            pending: Vec::new(),
+        }
+    }
+
+    /// Drops the parts waiting for their other part.
+    pub fn reset(&mut self) {
+        self.pending.clear();
+    }
+
+    /// Feeds a part to the assembler.
+    ///
+    /// Returns the static data of the vessel once both parts have been received.
+    /// A part replaces the previous part of the same kind received for the same vessel.
+    pub fn push(&mut self, report: &StaticDataReport) -> Option<ClassBStaticData> {
+        let position = self
+            .pending
+            .iter()
+            .position(|pending| pending.mmsi == report.mmsi);
+        if let Some(position) = position {
+            let pending = self.pending.remove(position);
+            match (pending.part, &report.part) {
+                (StaticDataPart::A { name }, StaticDataPart::B(data)) => {
+                    return Some(ClassBStaticData {
+                        mmsi: report.mmsi,
+                        name,
+                        data: data.clone(),
+                    });
+                }
+                (StaticDataPart::B(data), StaticDataPart::A { name }) => {
+                    return Some(ClassBStaticData {
+                        mmsi: report.mmsi,
+                        name: name.clone(),
+                        data,
+                    });
+                }
+                _ => {}
+            }
+        } else if self.pending.is_full() {
+            self.pending.remove(0);
+        }
+        // There is room since a report has been removed if the list was full
+        let _ = self.pending.push(report.clone());
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ais::{ShipDimensions, StaticDataPartB};
+    use core::convert::TryFrom;
+    use heapless::String;
+
+    fn fragment(count: u8, number: u8, id: u8, payload: &str, fill_bits: u8) -> VDMMessage<'_> {
+        VDMMessage {
+            fragment_count: count,
+            fragment_number: number,
+            message_id: Some(id),
+            channel: Some(AISChannel::B),
+            payload,
+            fill_bits,
+        }
+    }
+
+    const FIRST: &str = "55P5TL01VIaAL@7WKO@mBplU@<PDhh000000001S;AJ::4A80?4i@E53";
+    const SECOND: &str = "1@0000000000000";
+
+    #[test]
+    fn test_reassemble() {
+        let mut assembler = FragmentAssembler::new();
+        assert_eq!(Ok(None), assembler.push(&fragment(2, 1, 3, FIRST, 0)));
+        let bits = assembler
+            .push(&fragment(2, 2, 3, SECOND, 2))
+            .unwrap()
+            .unwrap();
+        assert_eq!(424, bits.len());
+        // Message type
+        assert_eq!(Some(5), bits.unsigned(0, 6));
+    }
+
+    #[test]
+    fn test_reassemble_interleaved() {
+        let mut assembler = FragmentAssembler::new();
+        assert_eq!(Ok(None), assembler.push(&fragment(2, 1, 3, FIRST, 0)));
+        assert_eq!(Ok(None), assembler.push(&fragment(2, 1, 4, FIRST, 0)));
+        assert!(assembler
+            .push(&fragment(2, 2, 3, SECOND, 2))
+            .unwrap()
+            .is_some());
+        assert!(assembler
+            .push(&fragment(2, 2, 4, SECOND, 2))
+            .unwrap()
+            .is_some());
+    }
+
+    #[test]
+    fn test_missing_fragment() {
+        let mut assembler = FragmentAssembler::new();
+        assert_eq!(
+            Err(Error::UnexpectedFragment),
+            assembler.push(&fragment(2, 2, 3, SECOND, 2))
+        );
+        assert_eq!(Ok(None), assembler.push(&fragment(3, 1, 3, FIRST, 0)));
+        assert_eq!(
+            Err(Error::UnexpectedFragment),
+            assembler.push(&fragment(3, 3, 3, SECOND, 2))
+        );
+        // The message has been dropped
+        assert_eq!(
+            Err(Error::UnexpectedFragment),
+            assembler.push(&fragment(3, 2, 3, SECOND, 0))
+        );
+    }
+
+    #[test]
+    fn test_oldest_message_dropped() {
+        let mut assembler = FragmentAssembler::new();
+        for id in 0..=MAX_PENDING_MESSAGES as u8 {
+            assert_eq!(Ok(None), assembler.push(&fragment(2, 1, id, FIRST, 0)));
+        }
+        assert_eq!(
+            Err(Error::UnexpectedFragment),
+            assembler.push(&fragment(2, 2, 0, SECOND, 2))
+        );
+        assert!(assembler
+            .push(&fragment(2, 2, 1, SECOND, 2))
+            .unwrap()
+            .is_some());
+    }
+
+    fn part_a(mmsi: u32, name: &str) -> StaticDataReport {
+        StaticDataReport {
+            repeat: 0,
+            mmsi,
+            part: StaticDataPart::A {
+                name: String::try_from(name).unwrap(),
+            },
+        }
+    }
+
+    fn part_b(mmsi: u32) -> StaticDataReport {
+        StaticDataReport {
+            repeat: 0,
+            mmsi,
+            part: StaticDataPart::B(part_b_data()),
+        }
+    }
+
+    fn part_b_data() -> StaticDataPartB {
+        StaticDataPartB {
+            ship_type: Some(37),
+            vendor_id: String::try_from("SRT").unwrap(),
+            unit_model: 1,
+            serial_number: 1234,
+            call_sign: String::try_from("MA1234").unwrap(),
+            dimensions: Some(ShipDimensions {
+                to_bow: 8,
+                to_stern: 4,
+                to_port: 2,
+                to_starboard: 2,
+            }),
+            mothership_mmsi: None,
+        }
+    }
+
+    #[test]
+    fn test_combine_static_data() {
+        let mut assembler = StaticDataAssembler::new();
+        assert_eq!(None, assembler.push(&part_a(1, "FIRST")));
+        assert_eq!(None, assembler.push(&part_b(2)));
+        assert_eq!(None, assembler.push(&part_a(1, "RENAMED")));
+
+        let expected = ClassBStaticData {
+            mmsi: 1,
+            name: String::try_from("RENAMED").unwrap(),
+            data: part_b_data(),
+        };
+        assert_eq!(Some(expected), assembler.push(&part_b(1)));
+        let expected = ClassBStaticData {
+            mmsi: 2,
+            name: String::try_from("SECOND").unwrap(),
+            data: part_b_data(),
+        };
+        assert_eq!(Some(expected), assembler.push(&part_a(2, "SECOND")));
+        // Both vessels have been combined
+        assert_eq!(None, assembler.push(&part_b(1)));
+    }
+
+    #[test]
+    fn test_oldest_static_data_dropped() {
+        let mut assembler = StaticDataAssembler::new();
+        for mmsi in 0..=MAX_PENDING_STATIC_DATA as u32 {
+            assert_eq!(None, assembler.push(&part_a(mmsi, "NAME")));
+        }
+        assert_eq!(None, assembler.push(&part_b(0)));
+        assert!(assembler.push(&part_b(2)).is_some());
+    }
+}
@@ -0,0 +1,201 @@
+use crate::error::Error;
+use heapless::String;
+
+/// Maximum length of an AIS message, 5 slots of payload
+pub const MAX_PAYLOAD_BITS: usize = 1008;
+
+/// Converts an armoured payload character to its six bits value
+pub(crate) fn sixbit(c: char) -> Option<u8> {
+    match c {
+        '0'..='W' => Some(c as u8 - 48),
+        '`'..='w' => Some(c as u8 - 56),
+        _ => None,
+    }
+}
+
+/// Bits of an AIS message, de-armoured from the payload of one or more VDM/VDO sentences
+#[derive(Debug, Clone, PartialEq)]
+pub struct BitVector {
+    data: [u8; MAX_PAYLOAD_BITS / 8],
+    len: usize,
+}
+
+impl Default for BitVector {
+    fn default() -> Self {
+        BitVector::new()
+    }
+}
+
+impl BitVector {
+    pub const fn new() -> Self {
+        BitVector {
+            data: [0; MAX_PAYLOAD_BITS / 8],
+            len: 0,
+        }
+    }
+
+    /// De-armours a payload, dropping the fill bits padding its last character.
+    pub fn from_armoured(payload: &str, fill_bits: u8) -> Result<Self, Error> {
+        let mut bits = BitVector::new();
+        bits.push_armoured(payload, fill_bits)?;
+        Ok(bits)
+    }
+
+    /// Number of bits
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Appends a de-armoured payload, dropping the fill bits padding its last character.
+    ///
+    /// Fails with `Error::InvalidPayload` if a character isn't six-bit armoured, and with
+    /// `Error::BufferOverflow` if the message grows longer than `MAX_PAYLOAD_BITS`.
+    /// Nothing is appended on failure.
+    pub fn push_armoured(&mut self, payload: &str, fill_bits: u8) -> Result<(), Error> {
+        if !payload.chars().all(|c| sixbit(c).is_some()) {
+            return Err(Error::InvalidPayload);
+        }
+        let len = (payload.len() * 6).saturating_sub(fill_bits as usize);
+        if self.len + len > MAX_PAYLOAD_BITS {
+            return Err(Error::BufferOverflow);
+        }
+        let start = self.len;
+        for value in payload.chars().filter_map(sixbit) {
+            for shift in (0..6).rev() {
+                if self.len == start + len {
+                    break;
+                }
+                self.push((value >> shift) & 1 == 1);
+            }
+        }
+        Ok(())
+    }
+
+    fn push(&mut self, bit: bool) {
+        if bit {
+            self.data[self.len / 8] |= 0x80 >> (self.len % 8);
+        }
+        self.len += 1;
+    }
+
+    /// Returns the bit at the given index, the first bit being the most significant of the first character
+    pub fn bit(&self, index: usize) -> Option<bool> {
+        if index < self.len {
+            Some(self.data[index / 8] & (0x80 >> (index % 8)) != 0)
+        } else {
+            None
+        }
+    }
+
+    /// Reads an unsigned integer of up to 32 bits
+    pub fn unsigned(&self, start: usize, len: usize) -> Option<u32> {
+        if len > 32 || start + len > self.len {
+            return None;
+        }
+        let mut value = 0;
+        for index in start..start + len {
+            value = (value << 1) | self.bit(index)? as u32;
+        }
+        Some(value)
+    }
+
+    /// Reads a two's complement signed integer of up to 32 bits
+    pub fn signed(&self, start: usize, len: usize) -> Option<i32> {
+        if len == 0 {
+            return Some(0);
+        }
+        let value = self.unsigned(start, len)?;
+        // Sign extension
+        let shift = 32 - len as u32;
+        Some(((value << shift) as i32) >> shift)
+    }
+
+    /// Reads a six-bit ASCII text of `N` characters, with its trailing `@` padding and spaces stripped
+    pub fn text<const N: usize>(&self, start: usize) -> Option<String<N>> {
+        let mut text = String::new();
+        for index in 0..N {
+            let value = self.unsigned(start + index * 6, 6)? as u8;
+            let c = if value < 32 { value + 64 } else { value };
+            // Cannot overflow, the string has room for N characters
+            let _ = text.push(c as char);
+        }
+        let len = text.trim_end_matches(['@', ' ']).len();
+        text.truncate(len);
+        Some(text)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core::convert::TryFrom;
+
+    #[test]
+    fn test_sixbit() {
+        assert_eq!(Some(0), sixbit('0'));
+        assert_eq!(Some(39), sixbit('W'));
+        assert_eq!(Some(40), sixbit('`'));
+        assert_eq!(Some(63), sixbit('w'));
+        assert_eq!(None, sixbit('X'));
+    }
+
+    #[test]
+    fn test_from_armoured() {
+        let bits = BitVector::from_armoured("177KQJ5000G?tO`K>RA1wUbN0TKH", 0).unwrap();
+        assert_eq!(168, bits.len());
+        // Message type
+        assert_eq!(Some(1), bits.unsigned(0, 6));
+        // MMSI
+        assert_eq!(Some(477553000), bits.unsigned(8, 30));
+    }
+
+    #[test]
+    fn test_fill_bits() {
+        let bits = BitVector::from_armoured("1@", 2).unwrap();
+        assert_eq!(10, bits.len());
+        assert_eq!(Some(0b00_0001_0100), bits.unsigned(0, 10));
+        assert_eq!(None, bits.bit(10));
+    }
+
+    #[test]
+    fn test_signed() {
+        // 0b111111 0b111110
+        let bits = BitVector::from_armoured("wv", 0).unwrap();
+        assert_eq!(Some(-1), bits.signed(0, 6));
+        assert_eq!(Some(-2), bits.signed(6, 6));
+        assert_eq!(Some(-1), bits.signed(1, 5));
+        assert_eq!(Some(31), bits.unsigned(1, 5));
+    }
+
+    #[test]
+    fn test_text() {
+        let bits = BitVector::from_armoured("H42O55i18tMET00000000000000", 2).unwrap();
+        assert_eq!(
+            Some(String::<20>::try_from("PROGUY").unwrap()),
+            bits.text(40)
+        );
+        assert_eq!(Some(String::<3>::new()), bits.text(142));
+        assert_eq!(None, bits.text::<21>(40));
+    }
+
+    #[test]
+    fn test_invalid_payload() {
+        assert_eq!(
+            Err(Error::InvalidPayload),
+            BitVector::from_armoured("1X", 0)
+        );
+    }
+
+    #[test]
+    fn test_overflow() {
+        let payload = "0".repeat(MAX_PAYLOAD_BITS / 6 + 1);
+        assert_eq!(
+            Err(Error::BufferOverflow),
+            BitVector::from_armoured(&payload, 0)
+        );
+    }
+}
@@ -0,0 +1,201 @@
+mod assembler;
+mod bits;
+mod parsers;
+mod structs;
+
+pub use assembler::*;
+pub(crate) use bits::sixbit;
+pub use bits::{BitVector, MAX_PAYLOAD_BITS};
+pub use parsers::*;
+pub use structs::*;
+
+#[cfg(test)]
+mod position_tests {
+    use super::*;
+    use crate::fields::distance::Degree;
+    use crate::fields::speed::Knot;
+
+    #[test]
+    fn test_decode_position_report() {
+        let bits = BitVector::from_armoured("177KQJ5000G?tO`K>RA1wUbN0TKH", 0).unwrap();
+        let expected = AISMessage::PositionReport(PositionReport {
+            message_type: 1,
+            repeat: 0,
+            mmsi: 477553000,
+            nav_status: NavigationStatus::Moored,
+            rot: Some(0.),
+            sog: Some(Knot(0.)),
+            accuracy: false,
+            lon: Some(Degree(-73407500. / 600_000.)),
+            lat: Some(Degree(28549700. / 600_000.)),
+            cog: Some(Degree(51.)),
+            heading: Some(181),
+            timestamp: Some(15),
+            raim: false,
+        });
+        assert_eq!(Ok(expected), decode_message(&bits));
+    }
+
+    #[test]
+    fn test_decode_class_b_position_report() {
+        let bits = BitVector::from_armoured("B52K>;h00Fc>jpUlNV@ikwpUoP06", 0).unwrap();
+        let expected = AISMessage::ClassBPositionReport(ClassBPositionReport {
+            repeat: 0,
+            mmsi: 338087471,
+            sog: Some(Knot(0.1)),
+            accuracy: false,
+            lon: Some(Degree(-44443279. / 600_000.)),
+            lat: Some(Degree(24410724. / 600_000.)),
+            cog: Some(Degree(79.6)),
+            heading: None,
+            timestamp: Some(49),
+            raim: true,
+        });
+        assert_eq!(Ok(expected), decode_message(&bits));
+    }
+
+    #[test]
+    fn test_decode_extended_class_b_position_report() {
+        let bits =
+            BitVector::from_armoured("C5N3SRgPEnJGEBT>NhWAwwo862PaLELTBJ:V00000000S0D:R220", 0)
+                .unwrap();
+        match decode_message(&bits) {
+            Ok(AISMessage::ExtendedClassBPositionReport(report)) => {
+                assert_eq!(367059850, report.mmsi);
+                assert_eq!(Some(Knot(8.7)), report.sog);
+                assert_eq!(Some(Degree(335.9)), report.cog);
+                assert_eq!(None, report.heading);
+                assert_eq!(Some(46), report.timestamp);
+                assert!(!report.raim);
+                assert_eq!("CAPT.J.RIMES", report.name);
+                assert_eq!(Some(70), report.ship_type);
+                let dimensions = ShipDimensions {
+                    to_bow: 5,
+                    to_stern: 21,
+                    to_port: 4,
+                    to_starboard: 4,
+                };
+                assert_eq!(dimensions, report.dimensions);
+                assert_eq!(PositioningDevice::GPS, report.epfd);
+            }
+            other => panic!("unexpected message {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_decode_not_available_values() {
+        let bits = BitVector::from_armoured("1000000P?w<tSF0l4Q@>4?wp0000", 0).unwrap();
+        let expected = AISMessage::PositionReport(PositionReport {
+            message_type: 1,
+            repeat: 0,
+            mmsi: 0,
+            nav_status: NavigationStatus::UnderWayUsingEngine,
+            rot: None,
+            sog: None,
+            accuracy: false,
+            lon: None,
+            lat: None,
+            cog: None,
+            heading: None,
+            timestamp: None,
+            raim: false,
+        });
+        assert_eq!(Ok(expected), decode_message(&bits));
+    }
+
+    #[test]
+    fn test_decode_short_payload() {
+        let bits = BitVector::from_armoured("177KQJ5000", 0).unwrap();
+        assert_eq!(Err(crate::Error::InvalidPayload), decode_message(&bits));
+    }
+
+    #[test]
+    fn test_decode_unknown_message() {
+        let bits = BitVector::from_armoured("D02VqLPA4I6BD4", 0).unwrap();
+        let expected = AISMessage::Unknown { message_type: 20 };
+        assert_eq!(Ok(expected), decode_message(&bits));
+    }
+}
+
+#[cfg(test)]
+mod static_data_tests {
+    use super::*;
+    use crate::fields::distance::Meter;
+    use core::convert::TryFrom;
+    use heapless::String;
+
+    #[test]
+    fn test_decode_static_and_voyage_data() {
+        let mut bits = BitVector::from_armoured(
+            "55P5TL01VIaAL@7WKO@mBplU@<PDhh000000001S;AJ::4A80?4i@E53",
+            0,
+        )
+        .unwrap();
+        bits.push_armoured("1@0000000000000", 2).unwrap();
+        let expected = AISMessage::StaticAndVoyageData(StaticAndVoyageData {
+            repeat: 0,
+            mmsi: 369190000,
+            ais_version: 0,
+            imo: Some(6710932),
+            call_sign: String::try_from("WDA9674").unwrap(),
+            name: String::try_from("MT.MITCHELL").unwrap(),
+            ship_type: Some(99),
+            dimensions: ShipDimensions {
+                to_bow: 90,
+                to_stern: 90,
+                to_port: 10,
+                to_starboard: 10,
+            },
+            epfd: PositioningDevice::GPS,
+            eta: ETA {
+                month: Some(1),
+                day: Some(2),
+                hour: Some(8),
+                minute: Some(0),
+            },
+            draught: Some(Meter(6.)),
+            destination: String::try_from("SEATTLE").unwrap(),
+            dte: true,
+        });
+        assert_eq!(Ok(expected), decode_message(&bits));
+    }
+
+    #[test]
+    fn test_decode_static_data_report() {
+        let part_a = BitVector::from_armoured("H42O55i18tMET00000000000000", 2).unwrap();
+        let part_b = BitVector::from_armoured("H42O55lti4hhhilD3nink000?050", 0).unwrap();
+        let expected_a = StaticDataReport {
+            repeat: 0,
+            mmsi: 271041815,
+            part: StaticDataPart::A {
+                name: String::try_from("PROGUY").unwrap(),
+            },
+        };
+        let expected_b = StaticDataReport {
+            repeat: 0,
+            mmsi: 271041815,
+            part: StaticDataPart::B(StaticDataPartB {
+                ship_type: Some(60),
+                vendor_id: String::try_from("1D0").unwrap(),
+                unit_model: 12,
+                serial_number: 199796,
+                call_sign: String::try_from("TC6163").unwrap(),
+                dimensions: Some(ShipDimensions {
+                    to_bow: 0,
+                    to_stern: 15,
+                    to_port: 0,
+                    to_starboard: 5,
+                }),
+                mothership_mmsi: None,
+            }),
+        };
+        assert_eq!(
+            Ok(AISMessage::StaticDataReport(expected_a)),
+            decode_message(&part_a)
+        );
+        assert_eq!(
+            Ok(AISMessage::StaticDataReport(expected_b)),
+            decode_message(&part_b)
+        );
+    }
+}
@@ -0,0 +1,274 @@
+use super::bits::BitVector;
+use super::structs::*;
+use crate::error::Error;
+use crate::fields::distance::{Degree, Meter};
+use crate::fields::speed::Knot;
+
+/// Decodes the bits of a reassembled VDM/VDO payload.
+///
+/// Fails with `Error::InvalidPayload` if the payload is too short for its message type.
+pub fn decode_message(bits: &BitVector) -> Result<AISMessage, Error> {
+    let message_type = unsigned(bits, 0, 6)? as u8;
+    match message_type {
+        1..=3 => decode_position_report(bits, message_type).map(AISMessage::PositionReport),
+        5 => decode_static_and_voyage_data(bits).map(AISMessage::StaticAndVoyageData),
+        18 => decode_class_b_position_report(bits).map(AISMessage::ClassBPositionReport),
+        19 => decode_extended_class_b_position_report(bits)
+            .map(AISMessage::ExtendedClassBPositionReport),
+        24 => decode_static_data_report(bits).map(AISMessage::StaticDataReport),
+        _ => Ok(AISMessage::Unknown { message_type }),
+    }
+}
+
+fn decode_position_report(bits: &BitVector, message_type: u8) -> Result<PositionReport, Error> {
+    Ok(PositionReport {
+        message_type,
+        repeat: unsigned(bits, 6, 2)? as u8,
+        mmsi: unsigned(bits, 8, 30)?,
+        nav_status: navigation_status(unsigned(bits, 38, 4)? as u8),
+        rot: rate_of_turn(signed(bits, 42, 8)?),
+        sog: speed(unsigned(bits, 50, 10)?),
+        accuracy: flag(bits, 60)?,
+        lon: longitude(signed(bits, 61, 28)?),
+        lat: latitude(signed(bits, 89, 27)?),
+        cog: course(unsigned(bits, 116, 12)?),
+        heading: heading(unsigned(bits, 128, 9)?),
+        timestamp: timestamp(unsigned(bits, 137, 6)?),
+        raim: flag(bits, 148)?,
+    })
+}
+
+fn decode_class_b_position_report(bits: &BitVector) -> Result<ClassBPositionReport, Error> {
+    Ok(ClassBPositionReport {
+        repeat: unsigned(bits, 6, 2)? as u8,
+        mmsi: unsigned(bits, 8, 30)?,
+        sog: speed(unsigned(bits, 46, 10)?),
+        accuracy: flag(bits, 56)?,
+        lon: longitude(signed(bits, 57, 28)?),
+        lat: latitude(signed(bits, 85, 27)?),
+        cog: course(unsigned(bits, 112, 12)?),
+        heading: heading(unsigned(bits, 124, 9)?),
+        timestamp: timestamp(unsigned(bits, 133, 6)?),
+        raim: flag(bits, 147)?,
+    })
+}
+
+fn decode_extended_class_b_position_report(
+    bits: &BitVector,
+) -> Result<ExtendedClassBPositionReport, Error> {
+    Ok(ExtendedClassBPositionReport {
+        repeat: unsigned(bits, 6, 2)? as u8,
+        mmsi: unsigned(bits, 8, 30)?,
+        sog: speed(unsigned(bits, 46, 10)?),
+        accuracy: flag(bits, 56)?,
+        lon: longitude(signed(bits, 57, 28)?),
+        lat: latitude(signed(bits, 85, 27)?),
+        cog: course(unsigned(bits, 112, 12)?),
+        heading: heading(unsigned(bits, 124, 9)?),
+        timestamp: timestamp(unsigned(bits, 133, 6)?),
+        raim: flag(bits, 305)?,
+        name: text(bits, 143)?,
+        ship_type: ship_type(unsigned(bits, 263, 8)?),
+        dimensions: dimensions(bits, 271)?,
+        epfd: positioning_device(unsigned(bits, 301, 4)? as u8),
+    })
+}
+
+fn decode_static_and_voyage_data(bits: &BitVector) -> Result<StaticAndVoyageData, Error> {
+    let imo = unsigned(bits, 40, 30)?;
+    let draught = unsigned(bits, 294, 8)?;
+    Ok(StaticAndVoyageData {
+        repeat: unsigned(bits, 6, 2)? as u8,
+        mmsi: unsigned(bits, 8, 30)?,
+        ais_version: unsigned(bits, 38, 2)? as u8,
+        imo: if imo == 0 { None } else { Some(imo) },
+        call_sign: text(bits, 70)?,
+        name: text(bits, 112)?,
+        ship_type: ship_type(unsigned(bits, 232, 8)?),
+        dimensions: dimensions(bits, 240)?,
+        epfd: positioning_device(unsigned(bits, 270, 4)? as u8),
+        eta: ETA {
+            month: not_available(unsigned(bits, 274, 4)?, 0),
+            day: not_available(unsigned(bits, 278, 5)?, 0),
+            hour: not_available(unsigned(bits, 283, 5)?, 24),
+            minute: not_available(unsigned(bits, 288, 6)?, 60),
+        },
+        draught: if draught == 0 {
+            None
+        } else {
+            Some(Meter(draught as f64 / 10.))
+        },
+        destination: text(bits, 302)?,
+        dte: !flag(bits, 422)?,
+    })
+}
+
+fn decode_static_data_report(bits: &BitVector) -> Result<StaticDataReport, Error> {
+    let mmsi = unsigned(bits, 8, 30)?;
+    let part = match unsigned(bits, 38, 2)? {
+        0 => StaticDataPart::A {
+            name: text(bits, 40)?,
+        },
+        1 => {
+            // The MMSI of auxiliary craft is formatted as 98XXXYYYY
+            let auxiliary = mmsi / 10_000_000 == 98;
+            StaticDataPart::B(StaticDataPartB {
+                ship_type: ship_type(unsigned(bits, 40, 8)?),
+                vendor_id: text(bits, 48)?,
+                unit_model: unsigned(bits, 66, 4)? as u8,
+                serial_number: unsigned(bits, 70, 20)?,
+                call_sign: text(bits, 90)?,
+                dimensions: if auxiliary {
+                    None
+                } else {
+                    Some(dimensions(bits, 132)?)
+                },
+                mothership_mmsi: if auxiliary {
+                    Some(unsigned(bits, 132, 30)?)
+                } else {
+                    None
+                },
+            })
+        }
+        _ => return Err(Error::InvalidPayload),
+    };
+    Ok(StaticDataReport {
+        repeat: unsigned(bits, 6, 2)? as u8,
+        mmsi,
+        part,
+    })
+}
+
+fn unsigned(bits: &BitVector, start: usize, len: usize) -> Result<u32, Error> {
+    bits.unsigned(start, len).ok_or(Error::InvalidPayload)
+}
+
+fn signed(bits: &BitVector, start: usize, len: usize) -> Result<i32, Error> {
+    bits.signed(start, len).ok_or(Error::InvalidPayload)
+}
+
+fn flag(bits: &BitVector, index: usize) -> Result<bool, Error> {
+    bits.bit(index).ok_or(Error::InvalidPayload)
+}
+
+fn text<const N: usize>(bits: &BitVector, start: usize) -> Result<heapless::String<N>, Error> {
+    bits.text(start).ok_or(Error::InvalidPayload)
+}
+
+fn not_available(value: u32, sentinel: u32) -> Option<u8> {
+    if value == sentinel {
+        None
+    } else {
+        Some(value as u8)
+    }
+}
+
+fn dimensions(bits: &BitVector, start: usize) -> Result<ShipDimensions, Error> {
+    Ok(ShipDimensions {
+        to_bow: unsigned(bits, start, 9)? as u16,
+        to_stern: unsigned(bits, start + 9, 9)? as u16,
+        to_port: unsigned(bits, start + 18, 6)? as u8,
+        to_starboard: unsigned(bits, start + 24, 6)? as u8,
+    })
+}
+
+// 0 means not available
+fn ship_type(ship_type: u32) -> Option<u8> {
+    not_available(ship_type, 0)
+}
+
+fn positioning_device(epfd: u8) -> PositioningDevice {
+    match epfd {
+        0 => PositioningDevice::Undefined,
+        1 => PositioningDevice::GPS,
+        2 => PositioningDevice::GLONASS,
+        3 => PositioningDevice::CombinedGPSGLONASS,
+        4 => PositioningDevice::LoranC,
+        5 => PositioningDevice::Chayka,
+        6 => PositioningDevice::IntegratedNavigationSystem,
+        7 => PositioningDevice::Surveyed,
+        8 => PositioningDevice::Galileo,
+        15 => PositioningDevice::InternalGNSS,
+        _ => PositioningDevice::Reserved(epfd),
+    }
+}
+
+fn navigation_status(status: u8) -> NavigationStatus {
+    match status {
+        0 => NavigationStatus::UnderWayUsingEngine,
+        1 => NavigationStatus::AtAnchor,
+        2 => NavigationStatus::NotUnderCommand,
+        3 => NavigationStatus::RestrictedManoeuverability,
+        4 => NavigationStatus::ConstrainedByDraught,
+        5 => NavigationStatus::Moored,
+        6 => NavigationStatus::Aground,
+        7 => NavigationStatus::EngagedInFishing,
+        8 => NavigationStatus::UnderWaySailing,
+        14 => NavigationStatus::AISSARTActive,
+        15 => NavigationStatus::NotDefined,
+        _ => NavigationStatus::Reserved(status),
+    }
+}
+
+// ROT_AIS = 4.733 * sqrt(ROT), -128 means not available
+fn rate_of_turn(rot: i32) -> Option<f64> {
+    if rot == -128 {
+        return None;
+    }
+    let rate = rot as f64 / 4.733;
+    Some(rate * rate * rot.signum() as f64)
+}
+
+// 1/10 knot, 1023 means not available
+fn speed(sog: u32) -> Option<Knot> {
+    if sog == 1023 {
+        None
+    } else {
+        Some(Knot(sog as f64 / 10.))
+    }
+}
+
+// 1/10000 minute, to signed decimal degrees, 181 degrees means not available
+fn longitude(lon: i32) -> Option<Degree> {
+    if lon.abs() > 180 * 600_000 {
+        None
+    } else {
+        Some(Degree(lon as f64 / 600_000.))
+    }
+}
+
+// 1/10000 minute, to signed decimal degrees, 91 degrees means not available
+fn latitude(lat: i32) -> Option<Degree> {
+    if lat.abs() > 90 * 600_000 {
+        None
+    } else {
+        Some(Degree(lat as f64 / 600_000.))
+    }
+}
+
+// 1/10 degree, 3600 means not available
+fn course(cog: u32) -> Option<Degree> {
+    if cog >= 3600 {
+        None
+    } else {
+        Some(Degree(cog as f64 / 10.))
+    }
+}
+
+// 511 means not available
+fn heading(heading: u32) -> Option<u16> {
+    if heading >= 360 {
+        None
+    } else {
+        Some(heading as u16)
+    }
+}
+
+// 60 means not available, 61 to 63 are used for positioning system status
+fn timestamp(second: u32) -> Option<u8> {
+    if second >= 60 {
+        None
+    } else {
+        Some(second as u8)
+    }
+}
@@ -0,0 +1,247 @@
+use crate::fields::distance::{Degree, Meter};
+use crate::fields::speed::Knot;
+use heapless::String;
+
+#[derive(Debug, PartialEq)]
+/// Message decoded from the bits of a VDM/VDO payload
+pub enum AISMessage {
+    /// Types 1, 2 and 3
+    PositionReport(PositionReport),
+    /// Type 5
+    StaticAndVoyageData(StaticAndVoyageData),
+    /// Type 18
+    ClassBPositionReport(ClassBPositionReport),
+    /// Type 19
+    ExtendedClassBPositionReport(ExtendedClassBPositionReport),
+    /// Type 24, part A or B
+    StaticDataReport(StaticDataReport),
+    /// A message type the crate doesn't decode
+    Unknown { message_type: u8 },
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+/// Navigational status of a Class A vessel
+pub enum NavigationStatus {
+    UnderWayUsingEngine,
+    AtAnchor,
+    NotUnderCommand,
+    RestrictedManoeuverability,
+    ConstrainedByDraught,
+    Moored,
+    Aground,
+    EngagedInFishing,
+    UnderWaySailing,
+    /// Reserved for future use, 9 to 13
+    Reserved(u8),
+    /// AIS-SART, MOB-AIS or EPIRB-AIS active
+    AISSARTActive,
+    NotDefined,
+}
+
+#[derive(Debug, PartialEq)]
+/// Class A position report
+pub struct PositionReport {
+    /// 1, 2 or 3
+    pub message_type: u8,
+    /// Number of times the message has been repeated
+    pub repeat: u8,
+    /// Maritime Mobile Service Identity
+    pub mmsi: u32,
+    /// Navigational status
+    pub nav_status: NavigationStatus,
+    /// Rate of turn, in degrees per minute, positive to starboard.
+    /// ±720 means turning faster than 5 degrees per 30 seconds, without turn indicator
+    pub rot: Option<f64>,
+    /// Speed over ground
+    pub sog: Option<Knot>,
+    /// The position is accurate to less than 10 meters
+    pub accuracy: bool,
+    /// Longitude, in signed decimal degrees (negative = W), no need for `to_decimal`
+    pub lon: Option<Degree>,
+    /// Latitude, in signed decimal degrees (negative = S), no need for `to_decimal`
+    pub lat: Option<Degree>,
+    /// Course over ground
+    pub cog: Option<Degree>,
+    /// True heading, in degrees
+    pub heading: Option<u16>,
+    /// Second of the UTC minute the report has been generated at
+    pub timestamp: Option<u8>,
+    /// Receiver autonomous integrity monitoring is in use
+    pub raim: bool,
+}
+
+#[derive(Debug, PartialEq)]
+/// Standard Class B position report
+pub struct ClassBPositionReport {
+    /// Number of times the message has been repeated
+    pub repeat: u8,
+    /// Maritime Mobile Service Identity
+    pub mmsi: u32,
+    /// Speed over ground
+    pub sog: Option<Knot>,
+    /// The position is accurate to less than 10 meters
+    pub accuracy: bool,
+    /// Longitude, in signed decimal degrees (negative = W), no need for `to_decimal`
+    pub lon: Option<Degree>,
+    /// Latitude, in signed decimal degrees (negative = S), no need for `to_decimal`
+    pub lat: Option<Degree>,
+    /// Course over ground
+    pub cog: Option<Degree>,
+    /// True heading, in degrees
+    pub heading: Option<u16>,
+    /// Second of the UTC minute the report has been generated at
+    pub timestamp: Option<u8>,
+    /// Receiver autonomous integrity monitoring is in use
+    pub raim: bool,
+}
+
+#[derive(Debug, PartialEq)]
+/// Extended Class B position report
+pub struct ExtendedClassBPositionReport {
+    /// Number of times the message has been repeated
+    pub repeat: u8,
+    /// Maritime Mobile Service Identity
+    pub mmsi: u32,
+    /// Speed over ground
+    pub sog: Option<Knot>,
+    /// The position is accurate to less than 10 meters
+    pub accuracy: bool,
+    /// Longitude, in signed decimal degrees (negative = W), no need for `to_decimal`
+    pub lon: Option<Degree>,
+    /// Latitude, in signed decimal degrees (negative = S), no need for `to_decimal`
+    pub lat: Option<Degree>,
+    /// Course over ground
+    pub cog: Option<Degree>,
+    /// True heading, in degrees
+    pub heading: Option<u16>,
+    /// Second of the UTC minute the report has been generated at
+    pub timestamp: Option<u8>,
+    /// Vessel name
+    pub name: String<20>,
+    /// Type of ship and cargo
+    pub ship_type: Option<u8>,
+    /// Position of the position reference point
+    pub dimensions: ShipDimensions,
+    /// Type of the electronic position fixing device
+    pub epfd: PositioningDevice,
+    /// Receiver autonomous integrity monitoring is in use
+    pub raim: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+/// Type of electronic position fixing device
+pub enum PositioningDevice {
+    Undefined,
+    GPS,
+    GLONASS,
+    CombinedGPSGLONASS,
+    LoranC,
+    Chayka,
+    IntegratedNavigationSystem,
+    Surveyed,
+    Galileo,
+    /// Internal GNSS of the transponder
+    InternalGNSS,
+    /// Reserved for future use, 9 to 14
+    Reserved(u8),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+/// Distances from the position reference point to the ends of the ship, in meters.
+/// 0 means not available, and the highest value means that distance or more
+pub struct ShipDimensions {
+    pub to_bow: u16,
+    pub to_stern: u16,
+    pub to_port: u8,
+    pub to_starboard: u8,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+/// Estimated time of arrival, in UTC
+pub struct ETA {
+    pub month: Option<u8>,
+    pub day: Option<u8>,
+    pub hour: Option<u8>,
+    pub minute: Option<u8>,
+}
+
+#[derive(Debug, PartialEq)]
+/// Class A static and voyage related data
+pub struct StaticAndVoyageData {
+    /// Number of times the message has been repeated
+    pub repeat: u8,
+    /// Maritime Mobile Service Identity
+    pub mmsi: u32,
+    /// 0 for ITU-R M.1371-1, up to 3 for future editions
+    pub ais_version: u8,
+    /// IMO ship identification number
+    pub imo: Option<u32>,
+    /// Call sign, empty when not available
+    pub call_sign: String<7>,
+    /// Vessel name, empty when not available
+    pub name: String<20>,
+    /// Type of ship and cargo
+    pub ship_type: Option<u8>,
+    /// Position of the position reference point
+    pub dimensions: ShipDimensions,
+    /// Type of the electronic position fixing device
+    pub epfd: PositioningDevice,
+    /// Estimated time of arrival
+    pub eta: ETA,
+    /// Maximum present static draught
+    pub draught: Option<Meter>,
+    /// Destination, empty when not available
+    pub destination: String<20>,
+    /// The data terminal equipment is ready
+    pub dte: bool,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+/// Class B static data report, sent in two parts
+pub struct StaticDataReport {
+    /// Number of times the message has been repeated
+    pub repeat: u8,
+    /// Maritime Mobile Service Identity
+    pub mmsi: u32,
+    pub part: StaticDataPart,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+/// Content of a Class B static data report
+pub enum StaticDataPart {
+    A {
+        /// Vessel name, empty when not available
+        name: String<20>,
+    },
+    B(StaticDataPartB),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+/// Part B of a Class B static data report
+pub struct StaticDataPartB {
+    /// Type of ship and cargo
+    pub ship_type: Option<u8>,
+    /// Manufacturer mnemonic
+    pub vendor_id: String<3>,
+    /// Unit model code
+    pub unit_model: u8,
+    /// Unit serial number
+    pub serial_number: u32,
+    /// Call sign, empty when not available
+    pub call_sign: String<7>,
+    /// Position of the position reference point, unless the ship is an auxiliary craft
+    pub dimensions: Option<ShipDimensions>,
+    /// MMSI of the mother ship of an auxiliary craft
+    pub mothership_mmsi: Option<u32>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+/// Class B static data, combined from both parts of a static data report
+pub struct ClassBStaticData {
+    /// Maritime Mobile Service Identity
+    pub mmsi: u32,
+    /// Vessel name, empty when not available
+    pub name: String<20>,
+    /// Content of part B
+    pub data: StaticDataPartB,
+}
@@ -0,0 +1,593 @@
+use crate::ais::AISMessage;
+use crate::fields::distance::{Degree, NauticalMile};
+use crate::fields::parameter::{BearingReference, TargetStatus};
+use crate::fields::speed::{CourseOverGroundUnit, Knot};
+use crate::math_utils::normalize;
+use crate::messages::{RMCMessage, TLLMessage, TTMMessage, VTGMessage};
+use crate::navigation::{bearing, distance, position, position_from_rmc};
+use crate::route::WaypointPosition;
+use crate::wind::{speed_from_rmc, speed_from_vtg};
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+/// Identifier of a tracked target
+pub enum TargetId {
+    /// AIS target, by Maritime Mobile Service Identity
+    Mmsi(u32),
+    /// Radar target, by target number
+    Radar(u8),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+/// Limits under which a target is considered dangerous
+pub struct GuardThresholds {
+    /// Closest point of approach
+    pub cpa: NauticalMile,
+    /// Time to the closest point of approach, in minutes
+    pub tcpa: f64,
+    /// Targets without an update for this long are dropped
+    pub timeout: Duration,
+}
+
+impl Default for GuardThresholds {
+    fn default() -> Self {
+        GuardThresholds {
+            cpa: NauticalMile(1.),
+            tcpa: 12.,
+            timeout: Duration::from_secs(360),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+/// Change in the state of a target
+pub enum CollisionEvent {
+    /// The target entered the guard thresholds
+    Danger(TargetId),
+    /// The target is no longer dangerous
+    Clear(TargetId),
+    /// The target is lost or hasn't been updated within the timeout
+    Dropped(TargetId),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+/// A target of the table
+pub struct Target {
+    pub id: TargetId,
+    /// Name reported in TTM or TLL sentences, empty for AIS targets
+    pub name: String,
+    /// Position, in decimal degrees, when reported by AIS or TLL
+    pub position: Option<WaypointPosition>,
+    /// Range from the own ship
+    pub distance: Option<NauticalMile>,
+    /// Bearing from the own ship, in degrees true
+    pub bearing: Option<Degree>,
+    /// Speed over ground
+    pub speed: Option<Knot>,
+    /// Course over ground, in degrees true
+    pub course: Option<Degree>,
+    /// Closest point of approach
+    pub cpa: Option<NauticalMile>,
+    /// Time to the closest point of approach, in minutes, negative once passed
+    pub tcpa: Option<f64>,
+    /// The target is within the guard thresholds
+    pub dangerous: bool,
+    /// Time of the latest update
+    pub updated: Instant,
+}
+
+impl Target {
+    fn new(id: TargetId, now: Instant) -> Self {
+        Target {
+            id,
+            name: String::new(),
+            position: None,
+            distance: None,
+            bearing: None,
+            speed: None,
+            course: None,
+            cpa: None,
+            tcpa: None,
+            dangerous: false,
+            updated: now,
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+struct OwnShip {
+    position: Option<WaypointPosition>,
+    speed: Option<Knot>,
+    course: Option<Degree>,
+}
+
+impl OwnShip {
+    fn velocity(&self) -> Option<(f64, f64)> {
+        Some(velocity(self.speed?, self.course.as_ref()?))
+    }
+}
+
+/// Keeps track of AIS and radar targets,
+/// and computes their closest point of approach to the own ship.
+///
+/// The own ship position, speed and course are taken from RMC and VTG sentences.
+/// Relative bearings of TTM sentences are converted using the own ship course over ground,
+/// relative courses are taken as the motion of the target relative to the own ship.
+///
+/// Every update drops the targets which haven't been updated within the timeout.
+/// The events raised by the updates are collected until `take_events` is called.
+///
+/// # Examples
+///
+/// ```
+/// # use nmea_0183::collision::{CollisionEvent, GuardThresholds, TargetId, TargetTable};
+/// # use nmea_0183::{parse, Message};
+/// # use std::time::Instant;
+/// let mut table = TargetTable::new(GuardThresholds::default());
+/// let now = Instant::now();
+/// for line in &[
+///     "$GPRMC,083559.00,A,4717.11437,N,00833.91522,E,0.0,0.0,091202,,,A,V*2E\r\n",
+///     "$RATTM,01,2.0,90.0,T,12.0,270.0,T,0.0,10.0,N,TGT01,T,,083600.00,A*71\r\n",
+/// ] {
+///     match parse(line).unwrap().message {
+///         Message::RMC(rmc) => table.update_rmc(&rmc, now),
+///         Message::TTM(ttm) => table.update_ttm(&ttm, now),
+///         _ => {}
+///     }
+/// }
+/// let target = table.target(&TargetId::Radar(1)).unwrap();
+/// assert!(target.cpa.unwrap().0 < 1e-9);
+/// assert!((target.tcpa.unwrap() - 10.).abs() < 1e-9);
+/// assert_eq!(vec![CollisionEvent::Danger(TargetId::Radar(1))], table.take_events());
+/// ```
+#[derive(Debug)]
+pub struct TargetTable {
+    guard: GuardThresholds,
+    own_ship: OwnShip,
+    targets: HashMap<TargetId, Target>,
+    events: Vec<CollisionEvent>,
+}
+
+impl TargetTable {
+    pub fn new(guard: GuardThresholds) -> Self {
+        TargetTable {
+            guard,
+            own_ship: OwnShip::default(),
+            targets: HashMap::new(),
+            events: Vec::new(),
+        }
+    }
+
+    pub fn guard(&self) -> &GuardThresholds {
+        &self.guard
+    }
+
+    /// Changes the guard thresholds, and reassesses the targets.
+    pub fn set_guard(&mut self, guard: GuardThresholds) {
+        self.guard = guard;
+        self.assess_all();
+    }
+
+    pub fn target(&self, id: &TargetId) -> Option<&Target> {
+        self.targets.get(id)
+    }
+
+    /// Tracked targets, in no particular order
+    pub fn targets(&self) -> impl Iterator<Item = &Target> {
+        self.targets.values()
+    }
+
+    /// Events raised since the previous call
+    pub fn take_events(&mut self) -> Vec<CollisionEvent> {
+        std::mem::take(&mut self.events)
+    }
+
+    /// Updates the own ship position, speed and course from a valid RMC message.
+    pub fn update_rmc(&mut self, message: &RMCMessage, now: Instant) {
+        self.expire(now);
+        if let Some(position) = position_from_rmc(message) {
+            self.own_ship.position = Some(position);
+        }
+        if let Some(speed) = speed_from_rmc(message) {
+            self.own_ship.speed = Some(speed);
+            self.own_ship.course = message.cog.clone();
+        }
+        self.assess_all();
+    }
+
+    /// Updates the own ship speed and course from a VTG message.
+    pub fn update_vtg(&mut self, message: &VTGMessage, now: Instant) {
+        self.expire(now);
+        if let Some(speed) = speed_from_vtg(message) {
+            self.own_ship.speed = Some(speed);
+            self.own_ship.course = match message.cogt_unit {
+                Some(CourseOverGroundUnit::DegreesTrue) => message.cogt.map(Degree),
+                _ => None,
+            };
+        }
+        self.assess_all();
+    }
+
+    /// Updates a target from an AIS position report, other messages are ignored.
+    pub fn update_ais(&mut self, message: &AISMessage, now: Instant) {
+        self.expire(now);
+        let (mmsi, lat, lon, sog, cog) = match message {
+            AISMessage::PositionReport(report) => (
+                report.mmsi,
+                &report.lat,
+                &report.lon,
+                report.sog,
+                &report.cog,
+            ),
+            AISMessage::ClassBPositionReport(report) => (
+                report.mmsi,
+                &report.lat,
+                &report.lon,
+                report.sog,
+                &report.cog,
+            ),
+            AISMessage::ExtendedClassBPositionReport(report) => (
+                report.mmsi,
+                &report.lat,
+                &report.lon,
+                report.sog,
+                &report.cog,
+            ),
+            _ => return,
+        };
+        let target = self.entry(TargetId::Mmsi(mmsi), now);
+        target.position = match (lat, lon) {
+            (Some(lat), Some(lon)) => Some(WaypointPosition {
+                lat: lat.clone(),
+                lon: lon.clone(),
+            }),
+            _ => None,
+        };
+        target.distance = None;
+        target.bearing = None;
+        target.speed = sog;
+        target.course = cog.clone();
+        self.assess(TargetId::Mmsi(mmsi));
+    }
+
+    /// Updates a radar target from a TTM message, lost targets are dropped.
+    pub fn update_ttm(&mut self, message: &TTMMessage, now: Instant) {
+        self.expire(now);
+        let id = match message.target {
+            Some(target) => TargetId::Radar(target),
+            None => return,
+        };
+        if message.status == Some(TargetStatus::Lost) {
+            self.drop_target(id);
+            return;
+        }
+        let own_course = self.own_ship.course.as_ref().map(|course| course.0);
+        let own_velocity = self.own_ship.velocity();
+
+        let bearing = match (&message.bearing, message.bearing_reference) {
+            (Some(bearing), Some(BearingReference::Relative)) => {
+                own_course.map(|course| Degree(normalize(course + bearing.0)))
+            }
+            (Some(bearing), _) => Some(bearing.clone()),
+            _ => None,
+        };
+        let motion = match (message.speed_knots(), &message.course) {
+            (Some(speed), Some(course)) => Some(velocity(speed, course)),
+            _ => None,
+        };
+        let motion = match (motion, message.course_reference, own_velocity) {
+            (Some((east, north)), Some(BearingReference::Relative), Some(own)) => {
+                Some((east + own.0, north + own.1))
+            }
+            (Some(_), Some(BearingReference::Relative), None) => None,
+            (motion, _, _) => motion,
+        };
+
+        let target = self.entry(id, now);
+        if !message.name.is_empty() {
+            target.name = message.name.to_string();
+        }
+        target.position = None;
+        target.distance = message.distance_nautical_miles();
+        target.bearing = bearing;
+        target.speed = motion.map(|(east, north)| Knot(east.hypot(north)));
+        target.course =
+            motion.map(|(east, north)| Degree(normalize(east.atan2(north).to_degrees())));
+        self.assess(id);
+    }
+
+    /// Updates the position of a radar target from a TLL message, lost targets are dropped.
+    pub fn update_tll(&mut self, message: &TLLMessage, now: Instant) {
+        self.expire(now);
+        let id = match message.target {
+            Some(target) => TargetId::Radar(target),
+            None => return,
+        };
+        if message.status == Some(TargetStatus::Lost) {
+            self.drop_target(id);
+            return;
+        }
+        let position = match (&message.ns, &message.ew) {
+            (Some(ns), Some(ew)) => position(&message.lat, ns, &message.lon, ew),
+            _ => None,
+        };
+
+        let target = self.entry(id, now);
+        if !message.name.is_empty() {
+            target.name = message.name.to_string();
+        }
+        if position.is_some() {
+            target.position = position;
+        }
+        self.assess(id);
+    }
+
+    /// Drops the targets which haven't been updated within the timeout.
+    pub fn expire(&mut self, now: Instant) {
+        let timeout = self.guard.timeout;
+        let stale: Vec<TargetId> = self
+            .targets
+            .values()
+            .filter(|target| now.saturating_duration_since(target.updated) > timeout)
+            .map(|target| target.id)
+            .collect();
+        for id in stale {
+            self.drop_target(id);
+        }
+    }
+
+    fn entry(&mut self, id: TargetId, now: Instant) -> &mut Target {
+        let target = self
+            .targets
+            .entry(id)
+            .or_insert_with(|| Target::new(id, now));
+        target.updated = now;
+        target
+    }
+
+    fn drop_target(&mut self, id: TargetId) {
+        if self.targets.remove(&id).is_some() {
+            self.events.push(CollisionEvent::Dropped(id));
+        }
+    }
+
+    fn assess(&mut self, id: TargetId) {
+        if let Some(target) = self.targets.get_mut(&id) {
+            assess(target, &self.own_ship, &self.guard, &mut self.events);
+        }
+    }
+
+    fn assess_all(&mut self) {
+        for target in self.targets.values_mut() {
+            assess(target, &self.own_ship, &self.guard, &mut self.events);
+        }
+    }
+}
+
+fn assess(
+    target: &mut Target,
+    own_ship: &OwnShip,
+    guard: &GuardThresholds,
+    events: &mut Vec<CollisionEvent>,
+) {
+    if let (Some(position), Some(own_position)) = (&target.position, &own_ship.position) {
+        target.distance = Some(distance(own_position, position));
+        target.bearing = Some(bearing(own_position, position));
+    }
+    let approach = match (
+        target.distance,
+        &target.bearing,
+        target.speed,
+        &target.course,
+        own_ship.velocity(),
+    ) {
+        (Some(range), Some(bearing), Some(speed), Some(course), Some(own)) => Some(
+            closest_approach(range, bearing, velocity(speed, course), own),
+        ),
+        _ => None,
+    };
+    target.cpa = approach.map(|(cpa, _)| cpa);
+    target.tcpa = approach.map(|(_, tcpa)| tcpa);
+
+    let dangerous = match approach {
+        Some((cpa, tcpa)) => cpa.0 <= guard.cpa.0 && tcpa >= 0. && tcpa <= guard.tcpa,
+        None => false,
+    };
+    if dangerous != target.dangerous {
+        target.dangerous = dangerous;
+        events.push(if dangerous {
+            CollisionEvent::Danger(target.id)
+        } else {
+            CollisionEvent::Clear(target.id)
+        });
+    }
+}
+
+/// Distance and time in minutes to the closest point of approach,
+/// on a plane tangent at the own ship position
+fn closest_approach(
+    range: NauticalMile,
+    bearing: &Degree,
+    target: (f64, f64),
+    own: (f64, f64),
+) -> (NauticalMile, f64) {
+    let bearing = bearing.0.to_radians();
+    let (x, y) = (range.0 * bearing.sin(), range.0 * bearing.cos());
+    let (vx, vy) = (target.0 - own.0, target.1 - own.1);
+    let speed_squared = vx * vx + vy * vy;
+    if speed_squared < 1e-9 {
+        // No relative motion, the range doesn't change
+        return (range, 0.);
+    }
+    let hours = -(x * vx + y * vy) / speed_squared;
+    let cpa = (x + vx * hours).hypot(y + vy * hours);
+    (NauticalMile(cpa), hours * 60.)
+}
+
+/// East and north components of a velocity, in knots
+fn velocity(speed: Knot, course: &Degree) -> (f64, f64) {
+    let course = course.0.to_radians();
+    (speed.0 * course.sin(), speed.0 * course.cos())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ais::{NavigationStatus, PositionReport};
+    use crate::messages::{parse_rmc, parse_tll, parse_ttm, parse_vtg};
+
+    fn assert_close(expected: f64, actual: f64) {
+        assert!(
+            (expected - actual).abs() < 1e-3,
+            "expected {}, got {}",
+            expected,
+            actual
+        );
+    }
+
+    fn position_report(mmsi: u32, lat: f64, lon: f64, sog: f64, cog: f64) -> AISMessage {
+        AISMessage::PositionReport(PositionReport {
+            message_type: 1,
+            repeat: 0,
+            mmsi,
+            nav_status: NavigationStatus::UnderWayUsingEngine,
+            rot: None,
+            sog: Some(Knot(sog)),
+            accuracy: false,
+            lon: Some(Degree(lon)),
+            lat: Some(Degree(lat)),
+            cog: Some(Degree(cog)),
+            heading: None,
+            timestamp: None,
+            raim: false,
+        })
+    }
+
+    #[test]
+    fn test_ais_target_crossing() {
+        let mut table = TargetTable::new(GuardThresholds::default());
+        let now = Instant::now();
+        // Own ship at 0°N 0°E, heading north at 10 knots
+        let rmc = parse_rmc("120000.00,A,0000.000,N,00000.000,E,10.0,0.0,010120,,,A,V")
+            .unwrap()
+            .1;
+        table.update_rmc(&rmc, now);
+        // Target 2 NM to the east, heading west at 10 knots
+        let lon = (2. / 3440.065_f64).to_degrees();
+        table.update_ais(&position_report(227006760, 0., lon, 10., 270.), now);
+
+        let target = table.target(&TargetId::Mmsi(227006760)).unwrap();
+        assert_close(2., target.distance.unwrap().0);
+        assert_close(90., target.bearing.as_ref().unwrap().0);
+        assert_close(2_f64.sqrt(), target.cpa.unwrap().0);
+        assert_close(6., target.tcpa.unwrap());
+        assert!(!target.dangerous);
+        assert_eq!(Vec::<CollisionEvent>::new(), table.take_events());
+
+        table.set_guard(GuardThresholds {
+            cpa: NauticalMile(1.5),
+            tcpa: 15.,
+            ..GuardThresholds::default()
+        });
+        assert_eq!(
+            vec![CollisionEvent::Danger(TargetId::Mmsi(227006760))],
+            table.take_events()
+        );
+
+        // The own ship stops in the path of the target
+        let vtg = parse_vtg("0.0,T,,M,0.0,N,0.0,K,A").unwrap().1;
+        table.update_vtg(&vtg, now);
+        let target = table.target(&TargetId::Mmsi(227006760)).unwrap();
+        assert_close(0., target.cpa.unwrap().0);
+        assert_close(12., target.tcpa.unwrap());
+        assert!(target.dangerous);
+        assert!(table.take_events().is_empty());
+    }
+
+    #[test]
+    fn test_ttm_relative_motion() {
+        let mut table = TargetTable::new(GuardThresholds::default());
+        let now = Instant::now();
+        let vtg = parse_vtg("90.0,T,,M,10.0,N,18.5,K,A").unwrap().1;
+        table.update_vtg(&vtg, now);
+
+        // Dead ahead, closing at 10 knots: the target is stopped
+        let ttm = parse_ttm("02,3.0,0.0,R,10.0,270.0,R,,,N,BUOY,T,,,M")
+            .unwrap()
+            .1;
+        table.update_ttm(&ttm, now);
+        let target = table.target(&TargetId::Radar(2)).unwrap();
+        assert_eq!("BUOY", target.name);
+        assert_close(90., target.bearing.as_ref().unwrap().0);
+        assert_close(0., target.speed.unwrap().0);
+        assert_close(0., target.cpa.unwrap().0);
+        assert_close(18., target.tcpa.unwrap());
+        assert!(!target.dangerous);
+
+        // Passed, the target is no longer closing
+        let ttm = parse_ttm("02,0.5,180.0,R,10.0,270.0,R,,,N,,T,,,M")
+            .unwrap()
+            .1;
+        table.update_ttm(&ttm, now);
+        let target = table.target(&TargetId::Radar(2)).unwrap();
+        assert_eq!("BUOY", target.name);
+        assert_close(0., target.cpa.unwrap().0);
+        assert_close(-3., target.tcpa.unwrap());
+
+        let ttm = parse_ttm("02,,,,,,,,,,,L,,,M").unwrap().1;
+        table.update_ttm(&ttm, now);
+        assert_eq!(None, table.target(&TargetId::Radar(2)));
+        assert_eq!(
+            vec![CollisionEvent::Dropped(TargetId::Radar(2))],
+            table.take_events()
+        );
+    }
+
+    #[test]
+    fn test_tll_position() {
+        let mut table = TargetTable::new(GuardThresholds::default());
+        let now = Instant::now();
+        let rmc = parse_rmc("120000.00,A,4917.160,N,12310.640,W,0.0,0.0,010120,,,A,V")
+            .unwrap()
+            .1;
+        table.update_rmc(&rmc, now);
+
+        let tll = parse_tll("03,4918.16000,N,12310.64000,W,TGT03,120000.00,T,")
+            .unwrap()
+            .1;
+        table.update_tll(&tll, now);
+        let target = table.target(&TargetId::Radar(3)).unwrap();
+        assert_eq!("TGT03", target.name);
+        assert_close(1., target.distance.unwrap().0);
+        assert_close(0., target.bearing.as_ref().unwrap().0);
+        // No motion reported yet
+        assert_eq!(None, target.cpa);
+    }
+
+    #[test]
+    fn test_expire_stale_targets() {
+        let mut table = TargetTable::new(GuardThresholds {
+            timeout: Duration::from_secs(60),
+            ..GuardThresholds::default()
+        });
+        let start = Instant::now();
+        table.update_ais(&position_report(1, 0., 0., 1., 0.), start);
+        table.update_ais(&position_report(2, 0., 0., 1., 0.), start);
+        table.update_ais(
+            &position_report(2, 0., 0., 1., 0.),
+            start + Duration::from_secs(30),
+        );
+
+        table.expire(start + Duration::from_secs(60));
+        assert_eq!(2, table.targets().count());
+
+        table.expire(start + Duration::from_secs(61));
+        assert_eq!(None, table.target(&TargetId::Mmsi(1)));
+        assert!(table.target(&TargetId::Mmsi(2)).is_some());
+        assert_eq!(
+            vec![CollisionEvent::Dropped(TargetId::Mmsi(1))],
+            table.take_events()
+        );
+    }
+}
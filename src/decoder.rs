@@ -0,0 +1,218 @@
+use crate::error::Error;
+use crate::sentence::{parse_sentence, Sentence};
+
+/// Maximum length of a sentence according to IEC 61162-1,
+/// including the start delimiter and the terminating CR LF.
+pub const MAX_SENTENCE_LENGTH: usize = 82;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum State {
+    /// Waiting for a start delimiter
+    Idle,
+    /// Receiving the sentence content, up to the `*`
+    Data,
+    /// Receiving the checksum digits, with the number of digits received so far
+    Checksum(u8),
+    /// Waiting for the CR
+    Cr,
+    /// Waiting for the LF
+    Lf,
+}
+
+/// Decodes sentences fed one byte at a time, for instance from a UART interrupt handler.
+///
+/// The decoder uses a fixed size buffer of `MAX_SENTENCE_LENGTH` bytes
+/// and computes the checksum while the bytes are received.
+/// Bytes received outside of a sentence, including tag blocks, are ignored.
+///
+/// # Examples
+///
+/// ```
+/// # use nmea_0183::decoder::Decoder;
+/// let mut decoder = Decoder::new();
+/// let mut sentences = 0;
+/// for byte in b"$UPGBQ,RMC*21\r\n" {
+///     if let Some(Ok(_sentence)) = decoder.push(*byte) {
+///         sentences += 1;
+///     }
+/// }
+/// assert_eq!(1, sentences);
+/// ```
+pub struct Decoder {
+    buffer: [u8; MAX_SENTENCE_LENGTH],
+    len: usize,
+    state: State,
+    computed: u8,
+    expected: u8,
+}
+
+impl Default for Decoder {
+    fn default() -> Self {
+        Decoder::new()
+    }
+}
+
+impl Decoder {
+    pub const fn new() -> Self {
+        Decoder {
+            buffer: [0; MAX_SENTENCE_LENGTH],
+            len: 0,
+            state: State::Idle,
+            computed: 0,
+            expected: 0,
+        }
+    }
+
+    /// Drops the sentence being received, if any.
+    pub fn reset(&mut self) {
+        self.len = 0;
+        self.state = State::Idle;
+    }
+
+    /// Feeds one byte to the decoder.
+    ///
+    /// Returns `None` while a sentence is being received, and the parsed sentence
+    /// (or the reason it was rejected) once it is complete.
+    /// A start delimiter received in the middle of a sentence
+    /// reports a framing error and starts a new sentence.
+    pub fn push(&mut self, byte: u8) -> Option<Result<Sentence<'_>, Error>> {
+        if byte == b'$' || byte == b'!' {
+            let interrupted = self.state != State::Idle;
+            self.start(byte);
+            return if interrupted {
+                Some(Err(Error::Framing))
+            } else {
+                None
+            };
+        }
+
+        match self.state {
+            State::Idle => None,
+            State::Data => match byte {
+                b'*' => self.store(byte, State::Checksum(0)),
+                b' '..=b'~' => {
+                    self.computed ^= byte;
+                    self.store(byte, State::Data)
+                }
+                _ => self.fail(Error::Framing),
+            },
+            State::Checksum(digits) => match (byte as char).to_digit(16) {
+                Some(digit) => {
+                    self.expected = (self.expected << 4) | digit as u8;
+                    let next = if digits == 1 {
+                        State::Cr
+                    } else {
+                        State::Checksum(digits + 1)
+                    };
+                    self.store(byte, next)
+                }
+                None => self.fail(Error::Framing),
+            },
+            State::Cr if byte == b'\r' => self.store(byte, State::Lf),
+            State::Lf if byte == b'\n' => {
+                if let Some(err) = self.store(byte, State::Idle) {
+                    return Some(err);
+                }
+                if self.expected != self.computed {
+                    return Some(Err(Error::ChecksumMismatch {
+                        expected: self.expected,
+                        computed: self.computed,
+                    }));
+                }
+                // Only printable ASCII characters have been stored
+                let sentence = core::str::from_utf8(&self.buffer[..self.len])
+                    .map_err(|_| Error::Framing)
+                    .and_then(|sentence| parse_sentence(sentence).map(|(_, sentence)| sentence));
+                Some(sentence)
+            }
+            State::Cr | State::Lf => self.fail(Error::Framing),
+        }
+    }
+
+    fn start(&mut self, byte: u8) {
+        self.buffer[0] = byte;
+        self.len = 1;
+        self.state = State::Data;
+        self.computed = 0;
+        self.expected = 0;
+    }
+
+    fn store(&mut self, byte: u8, next: State) -> Option<Result<Sentence<'static>, Error>> {
+        if self.len == MAX_SENTENCE_LENGTH {
+            return self.fail(Error::BufferOverflow);
+        }
+        self.buffer[self.len] = byte;
+        self.len += 1;
+        self.state = next;
+        None
+    }
+
+    fn fail(&mut self, err: Error) -> Option<Result<Sentence<'static>, Error>> {
+        self.reset();
+        Some(Err(err))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::messages::GBQMessage;
+    use crate::Message;
+
+    fn push_all(decoder: &mut Decoder, input: &[u8]) -> Vec<Result<String, Error>> {
+        let mut results = Vec::new();
+        for byte in input {
+            match decoder.push(*byte) {
+                Some(Ok(sentence)) => results.push(Ok(format!("{:?}", sentence.message))),
+                Some(Err(err)) => results.push(Err(err)),
+                None => {}
+            }
+        }
+        results
+    }
+
+    #[test]
+    fn test_decode_sentence() {
+        let mut decoder = Decoder::new();
+        let results = push_all(&mut decoder, b"noise$UPGBQ,RMC*21\r\n\r\n$UPGBQ,RMC*21\r\n");
+        let expected = Ok(format!("{:?}", Message::GBQ(GBQMessage { msg_id: "RMC" })));
+        assert_eq!(vec![expected.clone(), expected], results);
+    }
+
+    #[test]
+    fn test_decode_checksum_mismatch() {
+        let mut decoder = Decoder::new();
+        let results = push_all(&mut decoder, b"$UPGBQ,RMC*2F\r\n");
+        let expected = Error::ChecksumMismatch {
+            expected: 0x2F,
+            computed: 0x21,
+        };
+        assert_eq!(vec![Err(expected)], results);
+    }
+
+    #[test]
+    fn test_decode_framing_errors() {
+        let mut decoder = Decoder::new();
+        let results = push_all(
+            &mut decoder,
+            b"$UPGBQ,R$UPGBQ,RMC*2G\r\n$UPGBQ,RMC*21\n$UPGBQ,\x00RMC*21\r\n$UPGBQ,RMC*21\r\n",
+        );
+        assert_eq!(5, results.len());
+        assert!(results[..4]
+            .iter()
+            .all(|result| result == &Err(Error::Framing)));
+        assert!(results[4].is_ok());
+    }
+
+    #[test]
+    fn test_decode_overflow() {
+        let mut decoder = Decoder::new();
+        let mut input = b"$GPTXT,01,01,02,".to_vec();
+        input.extend(vec![b'A'; MAX_SENTENCE_LENGTH]);
+        input.extend(b"*00\r\n$UPGBQ,RMC*21\r\n");
+        let results = push_all(&mut decoder, &input);
+        assert_eq!(2, results.len());
+        assert_eq!(Err(Error::BufferOverflow), results[0]);
+        assert!(results[1].is_ok());
+    }
+}
@@ -1,12 +1,125 @@
-use nom::Err;
+use core::fmt;
 
-#[derive(Debug, PartialEq)]
-pub enum Error<'a> {
-    ParseError(Err<(&'a str, nom::error::ErrorKind)>),
+/// Maximum number of characters of the input kept in an error,
+/// longer input is truncated.
+pub const ERROR_STRING_CAPACITY: usize = 32;
+
+/// Owned copy of the part of the input an error is about.
+pub type ErrorString = heapless::String<ERROR_STRING_CAPACITY>;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Error {
+    /// The sentence doesn't start with `$` or `!`
+    InvalidStartDelimiter,
+    /// The `*hh` checksum is missing or isn't made of two hexadecimal digits
+    MissingChecksum,
+    /// The checksum provided with the sentence doesn't match its content
+    ChecksumMismatch {
+        /// Checksum transmitted after the `*`
+        expected: u8,
+        /// Checksum computed over the sentence content
+        computed: u8,
+    },
+    /// The sentence doesn't end with CR LF
+    MissingCrLf,
+    /// The talker ID isn't made of two uppercase letters or digits
+    UnknownTalker(ErrorString),
+    /// The message type isn't made of three uppercase letters or digits
+    UnknownMessageType(ErrorString),
+    /// A field of a known message could not be parsed
+    InvalidField {
+        /// Message type, such as `GGA`
+        message: &'static str,
+        /// Position of the field, starting at 0 for the first field after the message type
+        field_index: usize,
+        /// Name of the field in the message struct
+        field_name: &'static str,
+        /// Content of the field
+        raw: ErrorString,
+    },
+    /// A tag block parameter is unknown or its value is invalid
+    InvalidTagBlock(ErrorString),
+    /// An AIS payload contains a character that isn't six-bit armoured,
+    /// or is too short for its message type
+    InvalidPayload,
+    /// An AIS fragment or RTE sentence doesn't follow the previous fragment of its message,
+    /// which has been dropped
+    UnexpectedFragment,
+    /// The sentence is longer than the buffer it is decoded into
+    BufferOverflow,
+    /// The sentence delimiters (start, checksum, CR LF) are missing or misplaced
+    Framing,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Error::InvalidStartDelimiter => f.write_str("sentence doesn't start with '$' or '!'"),
+            Error::MissingChecksum => f.write_str("missing or malformed checksum"),
+            Error::ChecksumMismatch { expected, computed } => write!(
+                f,
+                "checksum mismatch: expected {:02X}, computed {:02X}",
+                expected, computed
+            ),
+            Error::MissingCrLf => f.write_str("sentence doesn't end with CR LF"),
+            Error::UnknownTalker(talker) => write!(f, "unknown talker {:?}", talker),
+            Error::UnknownMessageType(message) => write!(f, "unknown message type {:?}", message),
+            Error::InvalidField {
+                message,
+                field_index,
+                field_name,
+                raw,
+            } => write!(
+                f,
+                "invalid {} field {} ({}): {:?}",
+                message, field_index, field_name, raw
+            ),
+            Error::InvalidTagBlock(parameter) => {
+                write!(f, "invalid tag block parameter {:?}", parameter)
+            }
+            Error::InvalidPayload => f.write_str("invalid AIS payload"),
+            Error::UnexpectedFragment => f.write_str("unexpected fragment"),
+            Error::BufferOverflow => f.write_str("sentence is too long"),
+            Error::Framing => f.write_str("sentence delimiters are missing or misplaced"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for Error {}
+
+/// Copies as much of the input as an `ErrorString` can hold.
+pub(crate) fn error_string(input: &str) -> ErrorString {
+    let mut string = ErrorString::new();
+    for c in input.chars() {
+        if string.push(c).is_err() {
+            break;
+        }
+    }
+    string
 }
 
-impl<'a> std::convert::From<nom::Err<(&'a str, nom::error::ErrorKind)>> for Error<'a> {
-    fn from(err: nom::Err<(&'a str, nom::error::ErrorKind)>) -> Error<'a> {
-        Error::ParseError(err)
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_error_string_truncates() {
+        let input = "this field is definitely longer than the error capacity";
+        assert_eq!(
+            &input[..ERROR_STRING_CAPACITY],
+            error_string(input).as_str()
+        );
+    }
+
+    #[test]
+    fn test_display_error() {
+        let err = Error::InvalidField {
+            message: "GGA",
+            field_index: 1,
+            field_name: "lat",
+            raw: error_string("47x17"),
+        };
+        assert_eq!("invalid GGA field 1 (lat): \"47x17\"", err.to_string());
     }
 }
@@ -1,5 +1,6 @@
 mod parsers;
 mod structs;
+mod writers;
 
 pub(crate) use parsers::*;
 pub use structs::*;
@@ -61,4 +62,42 @@ mod cardinality_tests {
         assert_eq!(expected_output, parse_east_west_indicator(input));
         assert_eq!(expected_output2, parse_north_south_indicator(input));
     }
+
+    #[test]
+    fn test_parse_last_east_west_indicator() {
+        assert_eq!(Ok(("", EastWest::West)), parse_east_west_indicator("W"));
+    }
+
+    #[test]
+    fn test_parse_maybe_east_west_indicator() {
+        assert_eq!(
+            Ok(("", Some(EastWest::East))),
+            parse_maybe_east_west_indicator("E")
+        );
+        assert_eq!(Ok((",W", None)), parse_maybe_east_west_indicator(",,W"));
+        assert_eq!(Ok(("", None)), parse_maybe_east_west_indicator(""));
+    }
+
+    #[test]
+    fn test_parse_maybe_north_south_indicator() {
+        assert_eq!(
+            Ok(("1", Some(NorthSouth::South))),
+            parse_maybe_north_south_indicator("S,1")
+        );
+        assert_eq!(Ok((",1", None)), parse_maybe_north_south_indicator(",,1"));
+    }
+
+    #[test]
+    fn test_parse_maybe_left_right_indicator() {
+        assert_eq!(
+            Ok(("1", Some(LeftRight::Left))),
+            parse_maybe_left_right_indicator("L,1")
+        );
+        assert_eq!(
+            Ok(("", Some(LeftRight::Right))),
+            parse_maybe_left_right_indicator("R")
+        );
+        assert_eq!(Ok((",1", None)), parse_maybe_left_right_indicator(",,1"));
+        assert!(parse_maybe_left_right_indicator("E,").is_err());
+    }
 }
@@ -26,7 +26,7 @@ pub fn parse_maybe_north_south_indicator(input: &str) -> IResult<&str, Option<No
     let (remaining, result) = match input.take(1) {
         "N" => (&input[1..], Some(NorthSouth::North)),
         "S" => (&input[1..], Some(NorthSouth::South)),
-        "," => (&input[1..], None),
+        "," => return Ok((&input[1..], None)),
         _ => {
             return Err(nom::Err::Failure((input, nom::error::ErrorKind::OneOf)));
         }
@@ -35,13 +35,27 @@ pub fn parse_maybe_north_south_indicator(input: &str) -> IResult<&str, Option<No
 }
 
 pub fn parse_maybe_east_west_indicator(input: &str) -> IResult<&str, Option<EastWest>> {
+    // An empty last field
+    if input.is_empty() {
+        return Ok((input, None));
+    }
+    if let Some(remaining) = input.strip_prefix(',') {
+        return Ok((remaining, None));
+    }
+    let (remaining, result) = parse_east_west_indicator(input)?;
+    Ok((remaining, Some(result)))
+}
+
+pub fn parse_east_west_indicator(input: &str) -> IResult<&str, EastWest> {
     if input.len() < 1 {
         return Err(nom::Err::Failure((input, nom::error::ErrorKind::Complete)));
     }
     let (remaining, result) = match input.take(1) {
-        "E" => (&input[1..], Some(EastWest::East)),
-        "W" => (&input[1..], Some(EastWest::West)),
-        "," => (&input[1..], None),
+        "E" => (&input[1..], EastWest::East),
+        "W" => (&input[1..], EastWest::West),
+        "," => {
+            return Err(nom::Err::Failure((input, nom::error::ErrorKind::Complete)));
+        }
         _ => {
             return Err(nom::Err::Failure((input, nom::error::ErrorKind::OneOf)));
         }
@@ -49,13 +63,18 @@ pub fn parse_maybe_east_west_indicator(input: &str) -> IResult<&str, Option<East
     remove_separator_if_next(',', remaining, result)
 }
 
-pub fn parse_east_west_indicator(input: &str) -> IResult<&str, EastWest> {
-    if input.len() < 2 {
-        return Err(nom::Err::Failure((input, nom::error::ErrorKind::Complete)));
-    }
-    match input.take(1) {
-        "E" => Ok((&input[2..], EastWest::East)),
-        "W" => Ok((&input[2..], EastWest::West)),
-        _ => Err(nom::Err::Failure((input, nom::error::ErrorKind::OneOf))),
+pub fn parse_maybe_left_right_indicator(input: &str) -> IResult<&str, Option<LeftRight>> {
+    // An empty last field
+    if input.is_empty() {
+        return Ok((input, None));
     }
+    let (remaining, result) = match input.take(1) {
+        "L" => (&input[1..], Some(LeftRight::Left)),
+        "R" => (&input[1..], Some(LeftRight::Right)),
+        "," => return Ok((&input[1..], None)),
+        _ => {
+            return Err(nom::Err::Failure((input, nom::error::ErrorKind::OneOf)));
+        }
+    };
+    remove_separator_if_next(',', remaining, result)
 }
@@ -13,3 +13,12 @@ pub enum EastWest {
     East,
     West,
 }
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+/// Side of the vessel, such as the side the wind comes from
+pub enum LeftRight {
+    /// Port
+    Left,
+    /// Starboard
+    Right,
+}
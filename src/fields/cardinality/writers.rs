@@ -0,0 +1,29 @@
+use super::structs::*;
+use core::fmt;
+
+impl fmt::Display for NorthSouth {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self {
+            NorthSouth::North => "N",
+            NorthSouth::South => "S",
+        })
+    }
+}
+
+impl fmt::Display for EastWest {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self {
+            EastWest::East => "E",
+            EastWest::West => "W",
+        })
+    }
+}
+
+impl fmt::Display for LeftRight {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self {
+            LeftRight::Left => "L",
+            LeftRight::Right => "R",
+        })
+    }
+}
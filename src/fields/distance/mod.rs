@@ -1,8 +1,10 @@
 mod parsers;
 mod structs;
+mod writers;
 
 pub(crate) use parsers::*;
 pub use structs::*;
+pub(crate) use writers::*;
 
 #[cfg(test)]
 mod tests {
@@ -59,4 +61,27 @@ mod tests {
         let lat = "12.34this field is definitely invalid,";
         assert!(parse_meter(lat).is_err());
     }
+
+    #[test]
+    fn test_parse_foot_and_fathom() {
+        assert_eq!(Ok(("", Some(Foot(36.1)))), parse_foot("36.1,"));
+        assert_eq!(Ok(("", Some(Fathom(6.)))), parse_fathom("6.0"));
+        assert_eq!(Ok(("", None)), parse_fathom(","));
+    }
+
+    #[test]
+    fn test_convert_units() {
+        assert_eq!(Meter(3.048), Meter::from(Foot(10.)));
+        assert_eq!(Meter(1.8288), Meter::from(Fathom(1.)));
+        assert!((Foot::from(Meter(3.048)).0 - 10.).abs() < 1e-9);
+        assert!((Fathom::from(Meter(18.288)).0 - 10.).abs() < 1e-9);
+        assert_eq!(Meter(1852.), Meter::from(NauticalMile(1.)));
+    }
+
+    #[test]
+    fn test_degree_to_decimal() {
+        assert!((Degree(47.3).to_decimal().0 - 47.5).abs() < 1e-9);
+        assert!((Degree(-8.45).to_decimal().0 + 8.75).abs() < 1e-9);
+        assert!((Degree(47.5).to_degrees_minutes().0 - 47.3).abs() < 1e-9);
+    }
 }
@@ -96,6 +96,21 @@ pub fn ensure_meter(input: &str) -> IResult<&str, ()> {
     }
 }
 
+pub fn parse_foot(input: &str) -> IResult<&str, Option<Foot>> {
+    let (remaining, maybe_float) = parse_float(input)?;
+    Ok((remaining, maybe_float.map(Foot)))
+}
+
+pub fn parse_fathom(input: &str) -> IResult<&str, Option<Fathom>> {
+    let (remaining, maybe_float) = parse_float(input)?;
+    Ok((remaining, maybe_float.map(Fathom)))
+}
+
+pub fn parse_nautical_mile(input: &str) -> IResult<&str, Option<NauticalMile>> {
+    let (remaining, maybe_float) = parse_float(input)?;
+    Ok((remaining, maybe_float.map(NauticalMile)))
+}
+
 pub fn parse_residuals(input: &str) -> IResult<&str, [Option<Meter>; 12]> {
     let mut remaining = input;
     let mut residuals = [None; 12];
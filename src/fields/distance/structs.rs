@@ -1,7 +1,27 @@
-#[derive(Debug, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 /// Angle
 pub struct Degree(pub f64);
 
+impl Degree {
+    /// Converts a latitude or longitude parsed from a ddmm.mmmm field,
+    /// which keeps the minutes as the decimal part (47.1711364 for 47°17.11364'),
+    /// to decimal degrees.
+    pub fn to_decimal(&self) -> Degree {
+        // Truncates towards zero, f64::trunc isn't available without std
+        let degrees = self.0 as i32 as f64;
+        let minutes = (self.0 - degrees) * 100.;
+        Degree(degrees + minutes / 60.)
+    }
+
+    /// Converts decimal degrees to the representation of ddmm.mmmm fields,
+    /// the reverse of `to_decimal`.
+    pub fn to_degrees_minutes(&self) -> Degree {
+        let degrees = self.0 as i32 as f64;
+        let minutes = (self.0 - degrees) * 60.;
+        Degree(degrees + minutes / 100.)
+    }
+}
+
 #[derive(Debug, PartialEq)]
 /// 1/60th of a Degree
 pub struct Minute(pub f64);
@@ -13,3 +33,51 @@ pub struct Second(pub f64);
 #[derive(Debug, Clone, Copy, PartialEq)]
 /// The base unit of length in the International System of Units (SI)
 pub struct Meter(pub f64);
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+/// International foot, 0.3048 meter
+pub struct Foot(pub f64);
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+/// Fathom, 6 feet
+pub struct Fathom(pub f64);
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+/// International nautical mile, 1852 meters
+pub struct NauticalMile(pub f64);
+
+impl From<Foot> for Meter {
+    fn from(foot: Foot) -> Self {
+        Meter(foot.0 * 0.3048)
+    }
+}
+
+impl From<Fathom> for Meter {
+    fn from(fathom: Fathom) -> Self {
+        Meter(fathom.0 * 1.8288)
+    }
+}
+
+impl From<Meter> for Foot {
+    fn from(meter: Meter) -> Self {
+        Foot(meter.0 / 0.3048)
+    }
+}
+
+impl From<Meter> for Fathom {
+    fn from(meter: Meter) -> Self {
+        Fathom(meter.0 / 1.8288)
+    }
+}
+
+impl From<NauticalMile> for Meter {
+    fn from(distance: NauticalMile) -> Self {
+        Meter(distance.0 * 1852.)
+    }
+}
+
+impl From<Meter> for NauticalMile {
+    fn from(meter: Meter) -> Self {
+        NauticalMile(meter.0 / 1852.)
+    }
+}
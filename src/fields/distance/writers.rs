@@ -0,0 +1,65 @@
+use super::structs::*;
+use core::fmt;
+
+impl fmt::Display for Degree {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl fmt::Display for Minute {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl fmt::Display for Second {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl fmt::Display for Meter {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl fmt::Display for Foot {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl fmt::Display for Fathom {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl fmt::Display for NauticalMile {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+/// Writes a degree parsed by `parse_degree` back in the ddmm.mmmmm format,
+/// zero padded to the given width (10 for latitudes, 11 for longitudes)
+/// with the given number of decimals for the minutes.
+pub struct DegreesMinutes<'a>(pub &'a Option<Degree>, pub usize, pub usize);
+
+impl<'a> fmt::Display for DegreesMinutes<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.0 {
+            // 47.1711399 is actually 4717.11399
+            Some(degree) => write!(
+                f,
+                "{:0width$.precision$}",
+                degree.0 * 100.,
+                width = self.1,
+                precision = self.2
+            ),
+            None => Ok(()),
+        }
+    }
+}
@@ -1,5 +1,6 @@
 mod parsers;
 mod structs;
+mod writers;
 
 pub(crate) use parsers::*;
 pub use structs::*;
@@ -15,6 +16,13 @@ mod talker_tests {
         assert_eq!(expected_output, parse_talker(input));
     }
 
+    #[test]
+    fn test_parse_other_talker() {
+        let input = "XYtest";
+        let expected_output = Ok(("test", Talker::Other('X', 'Y')));
+        assert_eq!(expected_output, parse_talker(input));
+    }
+
     #[test]
     fn test_parse_wrong_parse_talker() {
         let input = "thisisnotatalker";
@@ -2,6 +2,7 @@ use super::structs::*;
 use crate::fields::distance::parse_raw_degree;
 use crate::fields::parameter::parse_dbhz;
 use crate::parser_utils::*;
+use heapless::Vec;
 use nom::sequence::tuple;
 use nom::IResult;
 
@@ -14,6 +15,7 @@ pub fn parse_talker(input: &str) -> IResult<&str, Talker> {
         "AB" => Ok((remaining, Talker::IndependentAISBaseStation)),
         "AD" => Ok((remaining, Talker::DependentAISBaseStation)),
         "AG" => Ok((remaining, Talker::AutopilotGeneral)),
+        "AI" => Ok((remaining, Talker::MobileAISStation)),
         "AP" => Ok((remaining, Talker::AutopilotMagnetic)),
         "BD" => Ok((remaining, Talker::BeiDou)),
         "BN" => Ok((remaining, Talker::BridgeNavigationalWatchAlarmSystem)),
@@ -40,7 +42,7 @@ pub fn parse_talker(input: &str) -> IResult<&str, Talker> {
         "GN" => Ok((remaining, Talker::GPSGLONASS)),
         "GP" => Ok((remaining, Talker::GPS)),
         "HC" => Ok((remaining, Talker::HeadingMagneticCompass)),
-        "HE" => Ok((remaining, Talker::HeadingNonNorthSeekingGyro)),
+        "HE" => Ok((remaining, Talker::HeadingNorthSeekingGyro)),
         "HN" => Ok((remaining, Talker::HeadingNonNorthSeekingGyro)),
         "II" => Ok((remaining, Talker::IntegratedInstrumentation)),
         "IN" => Ok((remaining, Talker::IntegratedNavigation)),
@@ -84,17 +86,35 @@ pub fn parse_talker(input: &str) -> IResult<&str, Talker> {
         "ZC" => Ok((remaining, Talker::TimekeeperChronometer)),
         "ZQ" => Ok((remaining, Talker::TimekeeperQuartz)),
         "ZV" => Ok((remaining, Talker::TimekeeperRadioUpdate)),
-        _ => Err(nom::Err::Failure((input, nom::error::ErrorKind::OneOf))),
+        _ => {
+            let mut chars = maybe_talker.chars();
+            match (chars.next(), chars.next()) {
+                (Some(first), Some(second))
+                    if first.is_ascii_uppercase()
+                        && (second.is_ascii_uppercase() || second.is_ascii_digit()) =>
+                {
+                    Ok((remaining, Talker::Other(first, second)))
+                }
+                _ => Err(nom::Err::Failure((input, nom::error::ErrorKind::OneOf))),
+            }
+        }
     }
 }
 
-pub fn parse_satellites_in_view(input: &str) -> IResult<&str, Vec<SatelliteInView>> {
+pub fn parse_satellites_in_view(
+    input: &str,
+) -> IResult<&str, Vec<SatelliteInView, MAX_SATELLITES_IN_VIEW>> {
     let mut remaining = input;
     let mut satellites = Vec::new();
     while remaining.len() != 0 {
         let sv = parse_satellite_in_view(remaining)?;
+        if satellites.push(sv.1).is_err() {
+            return Err(nom::Err::Failure((
+                remaining,
+                nom::error::ErrorKind::TooLarge,
+            )));
+        }
         remaining = sv.0;
-        satellites.push(sv.1);
     }
     Ok((remaining, satellites))
 }
@@ -105,6 +125,78 @@ fn parse_satellite_in_view(input: &str) -> IResult<&str, SatelliteInView> {
     Ok((remaining, SatelliteInView { id, elv, az, cno }))
 }
 
+pub fn parse_satellites_status(
+    input: &str,
+) -> IResult<&str, Vec<SatelliteStatus, MAX_SATELLITES_STATUS>> {
+    let mut remaining = input;
+    let mut satellites = Vec::new();
+    while !remaining.is_empty() {
+        let sv = parse_satellite_status(remaining)?;
+        if satellites.push(sv.1).is_err() {
+            return Err(nom::Err::Failure((
+                remaining,
+                nom::error::ErrorKind::TooLarge,
+            )));
+        }
+        remaining = sv.0;
+    }
+    Ok((remaining, satellites))
+}
+
+fn parse_satellite_status(input: &str) -> IResult<&str, SatelliteStatus> {
+    let (remaining, (id, usage, az, elv, cno, lck)) = tuple((
+        parse_u8,
+        parse_satellite_usage,
+        parse_u16,
+        parse_u8,
+        parse_u8,
+        parse_u8,
+    ))(input)?;
+    Ok((
+        remaining,
+        SatelliteStatus {
+            id,
+            usage,
+            az,
+            elv,
+            cno,
+            lck,
+        },
+    ))
+}
+
+fn parse_satellite_usage(input: &str) -> IResult<&str, SatelliteUsage> {
+    if input.is_empty() {
+        return Err(nom::Err::Failure((input, nom::error::ErrorKind::Complete)));
+    }
+    let (remaining, result) = match input.chars().next() {
+        // Index subscription is safe since input has at least 1 char
+        Some('-') => (&input[1..], SatelliteUsage::NotUsed),
+        Some('U') => (&input[1..], SatelliteUsage::Used),
+        Some('e') => (&input[1..], SatelliteUsage::EphemerisAvailable),
+        _ => {
+            return Err(nom::Err::Failure((input, nom::error::ErrorKind::OneOf)));
+        }
+    };
+    remove_separator_if_next(',', remaining, result)
+}
+
+pub fn parse_maybe_ais_channel(input: &str) -> IResult<&str, Option<AISChannel>> {
+    if input.is_empty() {
+        return Err(nom::Err::Failure((input, nom::error::ErrorKind::Complete)));
+    }
+    let (remaining, channel_str) = parse_string(input)?;
+    let result = match channel_str {
+        "" => None,
+        "A" | "1" => Some(AISChannel::A),
+        "B" | "2" => Some(AISChannel::B),
+        _ => {
+            return Err(nom::Err::Failure((input, nom::error::ErrorKind::OneOf)));
+        }
+    };
+    Ok((remaining, result))
+}
+
 pub fn parse_satellite_ids(input: &str) -> IResult<&str, [Option<u8>; 12]> {
     let mut remaining = input;
     let mut ids = [None; 12];
@@ -1,7 +1,7 @@
 use crate::fields::distance::Degree;
 use crate::fields::parameter::DBHZ;
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 /// The type of the transmitting unit that sent the message
 pub enum Talker {
     /// Independent AIS Base Station
@@ -10,6 +10,8 @@ pub enum Talker {
     DependentAISBaseStation,
     /// Autopilot - General
     AutopilotGeneral,
+    /// Mobile AIS Station
+    MobileAISStation,
     /// Autopilot - Magnetic
     AutopilotMagnetic,
     /// BeiDou (China)
@@ -142,9 +144,16 @@ pub enum Talker {
     TimekeeperQuartz,
     /// Timekeeper - Radio Update, WWV or WWVH
     TimekeeperRadioUpdate,
+    /// Proprietary sentence, the talker is followed by a manufacturer mnemonic instead of a message type
+    Proprietary,
+    /// Any other talker, made of an uppercase letter followed by an uppercase letter or a digit
+    Other(char, char),
 }
 
-#[derive(Debug, PartialEq)]
+/// Maximum number of satellites described in a single GSV message
+pub const MAX_SATELLITES_IN_VIEW: usize = 4;
+
+#[derive(Debug, Clone, PartialEq)]
 /// Represents a Satellite
 /// With its position
 pub struct SatelliteInView {
@@ -156,3 +165,45 @@ pub struct SatelliteInView {
     /// Signal strength
     pub cno: Option<DBHZ>,
 }
+
+/// Maximum number of satellites described in a single PUBX,03 message,
+/// the number of channels of u-blox M8 and M9 receivers
+pub const MAX_SATELLITES_STATUS: usize = 72;
+
+#[derive(Debug, Clone, PartialEq)]
+/// Whether a satellite is used in the navigation solution
+pub enum SatelliteUsage {
+    /// Not used
+    NotUsed,
+    /// Used in the solution
+    Used,
+    /// Ephemeris available, but not used in the solution
+    EphemerisAvailable,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+/// Represents a tracked Satellite,
+/// as reported by U-BLOX receivers.
+/// Values are kept as integers, as sent by the receiver, to keep PUBX,03 messages small
+pub struct SatelliteStatus {
+    pub id: Option<u8>,
+    /// Usage in the navigation solution
+    pub usage: SatelliteUsage,
+    /// Azimuth, in degrees
+    pub az: Option<u16>,
+    /// Elevation, in degrees
+    pub elv: Option<u8>,
+    /// Signal strength, in dBHz
+    pub cno: Option<u8>,
+    /// Carrier lock time, in seconds
+    pub lck: Option<u8>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+/// AIS VHF data link channel
+pub enum AISChannel {
+    /// 161.975 MHz, also reported as `1`
+    A,
+    /// 162.025 MHz, also reported as `2`
+    B,
+}
@@ -0,0 +1,129 @@
+use super::structs::*;
+use crate::writer_utils::Optional;
+use core::fmt;
+
+impl fmt::Display for Talker {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self {
+            Talker::IndependentAISBaseStation => "AB",
+            Talker::DependentAISBaseStation => "AD",
+            Talker::AutopilotGeneral => "AG",
+            Talker::MobileAISStation => "AI",
+            Talker::AutopilotMagnetic => "AP",
+            Talker::BeiDou => "GB",
+            Talker::BridgeNavigationalWatchAlarmSystem => "BN",
+            Talker::ComputerProgrammedCalculator => "CC",
+            Talker::DigitalSelectiveCalling => "CD",
+            Talker::ComputerMemoryData => "CM",
+            Talker::CommunicationsSatellite => "CS",
+            Talker::CommunicationsRadioTelephoneMFHF => "CT",
+            Talker::CommunicationsRadioTelephoneVHF => "CV",
+            Talker::CommunicationsScanningReceiver => "CX",
+            Talker::DECCA => "DE",
+            Talker::DirectionFinder => "DF",
+            Talker::VelocitySensorSpeedLogWaterMagnetic => "DM",
+            Talker::DuplexRepeaterStation => "DU",
+            Talker::ElectronicChartDisplayAndInformationSystem => "EC",
+            Talker::EmergencyPositionIndicatingBeacon => "EP",
+            Talker::EngineRoomMonitoringSystems => "ER",
+            Talker::Galileo => "GA",
+            Talker::GLONASS => "GL",
+            Talker::GPSGLONASS => "GN",
+            Talker::GPS => "GP",
+            Talker::HeadingMagneticCompass => "HC",
+            Talker::HeadingNorthSeekingGyro => "HE",
+            Talker::HeadingNonNorthSeekingGyro => "HN",
+            Talker::IntegratedInstrumentation => "II",
+            Talker::IntegratedNavigation => "IN",
+            Talker::LoranAReceiver => "LA",
+            Talker::LoranCReceiver => "LC",
+            Talker::MicrowavePositioningSystem => "MP",
+            Talker::NavigationLightController => "NL",
+            Talker::OMEGANavigationSystem => "OM",
+            Talker::DistressAlarmSystem => "OS",
+            Talker::RADAROrARPA => "RA",
+            Talker::SounderDepth => "SD",
+            Talker::ElectronicPositioningSystem => "SN",
+            Talker::SounderScanning => "SS",
+            Talker::TurnRateIndicator => "TI",
+            Talker::TRANSITNavigationSystem => "TR",
+            Talker::U0 => "U0",
+            Talker::U1 => "U1",
+            Talker::U2 => "U2",
+            Talker::U3 => "U3",
+            Talker::U4 => "U4",
+            Talker::U5 => "U5",
+            Talker::U6 => "U6",
+            Talker::U7 => "U7",
+            Talker::U8 => "U8",
+            Talker::U9 => "U9",
+            Talker::MicroprocessorController => "UP",
+            Talker::QZSS => "QZ",
+            Talker::VelocitySensorDoppler => "VD",
+            Talker::VelocitySensorSpeedLogWaterMechanical => "VW",
+            Talker::WeatherInstruments => "WI",
+            Talker::TransducerTemperature => "YC",
+            Talker::TransducterDisplacementAngularOrLinear => "YD",
+            Talker::TransducerFrequency => "YF",
+            Talker::TransducerLevel => "YL",
+            Talker::TransducerPressure => "YP",
+            Talker::TransducerFlowRate => "YR",
+            Talker::TransducerTachometer => "YT",
+            Talker::TransducerVolume => "YV",
+            Talker::Transducer => "YX",
+            Talker::TimekeeperAtomicClock => "ZA",
+            Talker::TimekeeperChronometer => "ZC",
+            Talker::TimekeeperQuartz => "ZQ",
+            Talker::TimekeeperRadioUpdate => "ZV",
+            Talker::Proprietary => "P",
+            Talker::Other(first, second) => return write!(f, "{}{}", first, second),
+        })
+    }
+}
+
+impl fmt::Display for SatelliteInView {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{:02},{},{},{}",
+            Optional(&self.id),
+            Optional(&self.elv),
+            Optional(&self.az),
+            Optional(&self.cno)
+        )
+    }
+}
+
+impl fmt::Display for SatelliteUsage {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self {
+            SatelliteUsage::NotUsed => "-",
+            SatelliteUsage::Used => "U",
+            SatelliteUsage::EphemerisAvailable => "e",
+        })
+    }
+}
+
+impl fmt::Display for SatelliteStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{:02},{},{:03},{:02},{:02},{:03}",
+            Optional(&self.id),
+            self.usage,
+            Optional(&self.az),
+            Optional(&self.elv),
+            Optional(&self.cno),
+            Optional(&self.lck)
+        )
+    }
+}
+
+impl fmt::Display for AISChannel {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self {
+            AISChannel::A => "A",
+            AISChannel::B => "B",
+        })
+    }
+}
@@ -3,4 +3,6 @@ pub mod distance;
 pub mod identity;
 pub mod parameter;
 pub mod speed;
+pub mod temperature;
 pub mod time;
+pub mod transducer;
@@ -1,8 +1,10 @@
 mod parsers;
 mod structs;
+mod writers;
 
 pub(crate) use parsers::*;
 pub use structs::*;
+pub(crate) use writers::*;
 
 #[cfg(test)]
 mod tests {
@@ -43,4 +45,27 @@ mod tests {
         let status = "foo,";
         assert!(parse_status(status).is_err());
     }
+
+    #[test]
+    fn test_parse_maybe_letter_fields() {
+        assert_eq!(
+            Ok(("1", Some(TargetStatus::Query))),
+            parse_maybe_target_status("Q,1")
+        );
+        assert_eq!(Ok(("1", None)), parse_maybe_bearing_reference(",1"));
+        assert_eq!(
+            Ok(("", Some(SpeedReference::Water))),
+            parse_maybe_speed_reference("W")
+        );
+        assert_eq!(Ok(("", None)), parse_maybe_target_acquisition(""));
+        assert!(parse_maybe_target_status("X,").is_err());
+        assert!(parse_maybe_bearing_reference("TR,").is_err());
+    }
+
+    #[test]
+    fn test_parse_reference_target() {
+        assert_eq!(Ok(("", true)), parse_reference_target("R"));
+        assert_eq!(Ok(("1", false)), parse_reference_target(",1"));
+        assert!(parse_reference_target("T").is_err());
+    }
 }
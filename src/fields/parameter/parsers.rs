@@ -1,5 +1,6 @@
 use super::structs::*;
 use crate::parser_utils::*;
+use heapless::Vec;
 use nom::bytes::complete::take_until;
 use nom::IResult;
 
@@ -82,6 +83,18 @@ pub fn parse_status(input: &str) -> IResult<&str, Status> {
     remove_separator_if_next(',', remaining, result)
 }
 
+pub fn parse_maybe_status(input: &str) -> IResult<&str, Option<Status>> {
+    match input.chars().next() {
+        // An empty last field
+        None => Ok((input, None)),
+        Some(',') => Ok((&input[1..], None)),
+        Some(_) => {
+            let (remaining, status) = parse_status(input)?;
+            Ok((remaining, Some(status)))
+        }
+    }
+}
+
 pub fn parse_quality(input: &str) -> IResult<&str, Fix> {
     if input.len() < 1 {
         return Err(nom::Err::Failure((input, nom::error::ErrorKind::Complete)));
@@ -103,16 +116,143 @@ pub fn parse_quality(input: &str) -> IResult<&str, Fix> {
     remove_separator_if_next(',', remaining, result)
 }
 
-pub fn parse_pos_mode_vec(input: &str) -> IResult<&str, Vec<Fix>> {
+pub fn parse_fix_status(input: &str) -> IResult<&str, FixStatus> {
+    let (remaining, status_str) = parse_string(input)?;
+    let result = match status_str {
+        "NF" => FixStatus::NoFix,
+        "DR" => FixStatus::DeadReckoningOnly,
+        "G2" => FixStatus::StandAlone2D,
+        "G3" => FixStatus::StandAlone3D,
+        "D2" => FixStatus::Differential2D,
+        "D3" => FixStatus::Differential3D,
+        "RK" => FixStatus::CombinedGNSSDeadReckoning,
+        "TT" => FixStatus::TimeOnly,
+        _ => {
+            return Err(nom::Err::Failure((input, nom::error::ErrorKind::OneOf)));
+        }
+    };
+    Ok((remaining, result))
+}
+
+pub fn parse_wind_reference(input: &str) -> IResult<&str, WindReference> {
+    if input.is_empty() {
+        return Err(nom::Err::Failure((input, nom::error::ErrorKind::Complete)));
+    }
+    let (remaining, result) = match input.chars().next() {
+        // Index subscription is safe since input has at least 1 char
+        Some('R') => (&input[1..], WindReference::Relative),
+        Some('T') => (&input[1..], WindReference::True),
+        _ => {
+            return Err(nom::Err::Failure((input, nom::error::ErrorKind::OneOf)));
+        }
+    };
+    remove_separator_if_next(',', remaining, result)
+}
+
+pub fn parse_heading_mode(input: &str) -> IResult<&str, HeadingMode> {
+    if input.is_empty() {
+        return Err(nom::Err::Failure((input, nom::error::ErrorKind::Complete)));
+    }
+    let (remaining, result) = match input.chars().next() {
+        // Index subscription is safe since input has at least 1 char
+        Some('A') => (&input[1..], HeadingMode::Autonomous),
+        Some('E') => (&input[1..], HeadingMode::Estimated),
+        Some('M') => (&input[1..], HeadingMode::Manual),
+        Some('S') => (&input[1..], HeadingMode::Simulator),
+        Some('V') => (&input[1..], HeadingMode::DataNotValid),
+        _ => {
+            return Err(nom::Err::Failure((input, nom::error::ErrorKind::OneOf)));
+        }
+    };
+    remove_separator_if_next(',', remaining, result)
+}
+
+/// Parses a single letter field which may be empty, or missing at the end of the sentence
+fn parse_maybe_letter<T>(input: &str, letter: fn(char) -> Option<T>) -> IResult<&str, Option<T>> {
+    let (remaining, field) = parse_last_string(input)?;
+    let mut chars = field.chars();
+    match (chars.next(), chars.next()) {
+        (None, _) => Ok((remaining, None)),
+        (Some(c), None) => match letter(c) {
+            Some(result) => Ok((remaining, Some(result))),
+            None => Err(nom::Err::Failure((input, nom::error::ErrorKind::OneOf))),
+        },
+        _ => Err(nom::Err::Failure((input, nom::error::ErrorKind::OneOf))),
+    }
+}
+
+pub fn parse_maybe_bearing_reference(input: &str) -> IResult<&str, Option<BearingReference>> {
+    parse_maybe_letter(input, |c| match c {
+        'T' => Some(BearingReference::True),
+        'R' => Some(BearingReference::Relative),
+        _ => None,
+    })
+}
+
+pub fn parse_maybe_target_status(input: &str) -> IResult<&str, Option<TargetStatus>> {
+    parse_maybe_letter(input, |c| match c {
+        'L' => Some(TargetStatus::Lost),
+        'Q' => Some(TargetStatus::Query),
+        'T' => Some(TargetStatus::Tracking),
+        _ => None,
+    })
+}
+
+pub fn parse_maybe_target_acquisition(input: &str) -> IResult<&str, Option<TargetAcquisition>> {
+    parse_maybe_letter(input, |c| match c {
+        'A' => Some(TargetAcquisition::Automatic),
+        'M' => Some(TargetAcquisition::Manual),
+        'R' => Some(TargetAcquisition::Reported),
+        _ => None,
+    })
+}
+
+pub fn parse_maybe_speed_reference(input: &str) -> IResult<&str, Option<SpeedReference>> {
+    parse_maybe_letter(input, |c| match c {
+        'B' => Some(SpeedReference::BottomTrack),
+        'M' => Some(SpeedReference::Manual),
+        'W' => Some(SpeedReference::Water),
+        'R' => Some(SpeedReference::Radar),
+        'P' => Some(SpeedReference::PositioningSystem),
+        _ => None,
+    })
+}
+
+/// Parses the `R` flag of a reference target, which is empty for other targets
+pub fn parse_reference_target(input: &str) -> IResult<&str, bool> {
+    let (remaining, field) = parse_last_string(input)?;
+    match field {
+        "" => Ok((remaining, false)),
+        "R" => Ok((remaining, true)),
+        _ => Err(nom::Err::Failure((input, nom::error::ErrorKind::OneOf))),
+    }
+}
+
+pub fn parse_route_mode(input: &str) -> IResult<&str, RouteMode> {
+    if input.is_empty() {
+        return Err(nom::Err::Failure((input, nom::error::ErrorKind::Complete)));
+    }
+    let (remaining, result) = match input.chars().next() {
+        // Index subscription is safe since input has at least 1 char
+        Some('c') => (&input[1..], RouteMode::Complete),
+        Some('w') => (&input[1..], RouteMode::Working),
+        _ => {
+            return Err(nom::Err::Failure((input, nom::error::ErrorKind::OneOf)));
+        }
+    };
+    remove_separator_if_next(',', remaining, result)
+}
+
+pub fn parse_pos_mode_vec(input: &str) -> IResult<&str, Vec<Fix, MAX_POS_MODES>> {
     let (remaining, mut pos_modes_str) = take_until(",")(input)?;
-    // Should actually be pos_modes_str,len() -1
-    // But i dont want to deal with negative values
-    let mut pos_modes = Vec::with_capacity(pos_modes_str.len());
+    let mut pos_modes = Vec::new();
 
     while pos_modes_str.len() > 0 {
         let res = parse_pos_mode(pos_modes_str)?;
+        if pos_modes.push(res.1).is_err() {
+            return Err(nom::Err::Failure((input, nom::error::ErrorKind::TooLarge)));
+        }
         pos_modes_str = res.0;
-        pos_modes.push(res.1);
     }
 
     remove_separator_if_next(',', remaining, pos_modes)
@@ -137,6 +277,30 @@ pub fn parse_pos_mode(input: &str) -> IResult<&str, Fix> {
     remove_separator_if_next(',', remaining, result)
 }
 
+pub fn parse_maybe_pos_mode(input: &str) -> IResult<&str, Option<Fix>> {
+    // Mode indicators ending the sentence are missing before NMEA 2.3
+    if input.is_empty() {
+        return Ok((input, None));
+    }
+    let (remaining, pos_mode) = parse_pos_mode(input)?;
+    Ok((remaining, Some(pos_mode)))
+}
+
+pub fn parse_flag(input: &str) -> IResult<&str, bool> {
+    if input.is_empty() {
+        return Err(nom::Err::Failure((input, nom::error::ErrorKind::Complete)));
+    }
+    let (remaining, result) = match input.chars().next() {
+        // Index subscription is safe since input has at least 1 char
+        Some('A') => (&input[1..], true),
+        Some('V') => (&input[1..], false),
+        _ => {
+            return Err(nom::Err::Failure((input, nom::error::ErrorKind::OneOf)));
+        }
+    };
+    remove_separator_if_next(',', remaining, result)
+}
+
 pub fn parse_message_type(input: &str) -> IResult<&str, MessageLevel> {
     let (remaining, type_str) = take_until(",")(input)?;
     let result = match type_str {
@@ -1,4 +1,7 @@
-#[derive(Debug, PartialEq)]
+/// Maximum number of constellations a GNS message reports a positioning mode for
+pub const MAX_POS_MODES: usize = 6;
+
+#[derive(Debug, Clone, PartialEq)]
 /// GPS quality indicator
 pub enum Fix {
     /// Fix not valid
@@ -84,5 +87,102 @@ pub enum MessageLevel {
 }
 
 #[derive(Debug, PartialEq)]
+/// U-BLOX navigation status, reported in PUBX,00 messages
+pub enum FixStatus {
+    /// No fix
+    NoFix,
+    /// Dead reckoning only solution
+    DeadReckoningOnly,
+    /// Stand alone 2D solution
+    StandAlone2D,
+    /// Stand alone 3D solution
+    StandAlone3D,
+    /// Differential 2D solution
+    Differential2D,
+    /// Differential 3D solution
+    Differential3D,
+    /// Combined GNSS and dead reckoning solution
+    CombinedGNSSDeadReckoning,
+    /// Time only solution
+    TimeOnly,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+/// Reference of a wind angle
+pub enum WindReference {
+    /// Relative to the bow of the vessel, as measured by a moving vessel
+    Relative,
+    /// Relative to the bow, as if the vessel was stationary,
+    /// or theoretical when computed from the relative wind and the vessel speed
+    True,
+}
+
+#[derive(Debug, PartialEq)]
+/// Mode indicator of a heading sensor
+pub enum HeadingMode {
+    Autonomous,
+    /// Estimated, dead reckoning
+    Estimated,
+    /// Manual input
+    Manual,
+    Simulator,
+    DataNotValid,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+/// Reference of a bearing or course reported by a radar
+pub enum BearingReference {
+    /// Degrees true
+    True,
+    /// Relative to the bow of the own ship
+    Relative,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+/// Tracking status of a radar target
+pub enum TargetStatus {
+    /// The target is lost
+    Lost,
+    /// The target is being acquired
+    Query,
+    /// The target is tracked
+    Tracking,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+/// How a radar target has been acquired
+pub enum TargetAcquisition {
+    Automatic,
+    Manual,
+    /// Reported by another source, such as AIS
+    Reported,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+/// Source of the own ship course and speed
+pub enum SpeedReference {
+    /// Bottom tracking log
+    BottomTrack,
+    /// Manually entered
+    Manual,
+    /// Water referenced
+    Water,
+    /// Radar tracking of a fixed target
+    Radar,
+    /// Positioning system ground reference
+    PositioningSystem,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+/// Content of an RTE route
+pub enum RouteMode {
+    /// Every waypoint of the route
+    Complete,
+    /// Working route, starting with the waypoint the vessel comes from,
+    /// followed by the waypoint it is heading to
+    Working,
+}
+
+#[derive(Debug, Clone, PartialEq)]
 /// Carrier-to-noise-density ratio
 pub struct DBHZ(pub f64);
@@ -0,0 +1,215 @@
+use super::structs::*;
+use core::fmt;
+
+impl fmt::Display for Status {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self {
+            Status::DataInvalid => "V",
+            Status::DataValid => "A",
+        })
+    }
+}
+
+impl fmt::Display for OperationMode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self {
+            OperationMode::Manual => "M",
+            OperationMode::Automatic => "A",
+        })
+    }
+}
+
+impl fmt::Display for ComputationMethod {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self {
+            ComputationMethod::InGGA => "0",
+            ComputationMethod::AfterGGA => "1",
+        })
+    }
+}
+
+impl fmt::Display for NavigationMode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self {
+            NavigationMode::FixNo => "1",
+            NavigationMode::Fix2D => "2",
+            NavigationMode::Fix3D => "3",
+        })
+    }
+}
+
+impl fmt::Display for NavigationalStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self {
+            NavigationalStatus::Safe => "S",
+            NavigationalStatus::Caution => "C",
+            NavigationalStatus::Unsafe => "U",
+            NavigationalStatus::NotValid => "V",
+        })
+    }
+}
+
+impl fmt::Display for FixStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self {
+            FixStatus::NoFix => "NF",
+            FixStatus::DeadReckoningOnly => "DR",
+            FixStatus::StandAlone2D => "G2",
+            FixStatus::StandAlone3D => "G3",
+            FixStatus::Differential2D => "D2",
+            FixStatus::Differential3D => "D3",
+            FixStatus::CombinedGNSSDeadReckoning => "RK",
+            FixStatus::TimeOnly => "TT",
+        })
+    }
+}
+
+impl fmt::Display for WindReference {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self {
+            WindReference::Relative => "R",
+            WindReference::True => "T",
+        })
+    }
+}
+
+impl fmt::Display for HeadingMode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self {
+            HeadingMode::Autonomous => "A",
+            HeadingMode::Estimated => "E",
+            HeadingMode::Manual => "M",
+            HeadingMode::Simulator => "S",
+            HeadingMode::DataNotValid => "V",
+        })
+    }
+}
+
+impl fmt::Display for BearingReference {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self {
+            BearingReference::True => "T",
+            BearingReference::Relative => "R",
+        })
+    }
+}
+
+impl fmt::Display for TargetStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self {
+            TargetStatus::Lost => "L",
+            TargetStatus::Query => "Q",
+            TargetStatus::Tracking => "T",
+        })
+    }
+}
+
+impl fmt::Display for TargetAcquisition {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self {
+            TargetAcquisition::Automatic => "A",
+            TargetAcquisition::Manual => "M",
+            TargetAcquisition::Reported => "R",
+        })
+    }
+}
+
+impl fmt::Display for SpeedReference {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self {
+            SpeedReference::BottomTrack => "B",
+            SpeedReference::Manual => "M",
+            SpeedReference::Water => "W",
+            SpeedReference::Radar => "R",
+            SpeedReference::PositioningSystem => "P",
+        })
+    }
+}
+
+impl fmt::Display for RouteMode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self {
+            RouteMode::Complete => "c",
+            RouteMode::Working => "w",
+        })
+    }
+}
+
+impl fmt::Display for SentenceType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self {
+            SentenceType::Parametric => "$",
+            SentenceType::Encapsulation => "!",
+        })
+    }
+}
+
+impl fmt::Display for MessageLevel {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self {
+            MessageLevel::Error => "00",
+            MessageLevel::Warning => "01",
+            MessageLevel::Notice => "02",
+            MessageLevel::User => "07",
+        })
+    }
+}
+
+impl fmt::Display for DBHZ {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+/// Writes a fix the way GGA messages do, as a quality indicator digit.
+pub struct Quality<'a>(pub &'a Fix);
+
+impl<'a> fmt::Display for Quality<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self.0 {
+            Fix::NoFix => "0",
+            Fix::AutonomousGNSSFix => "1",
+            Fix::DifferentialGNSSFix => "2",
+            Fix::RTKFixed => "4",
+            Fix::RTKFloat => "5",
+            Fix::EstimatedOrDeadReckoningFix => "6",
+        })
+    }
+}
+
+/// Writes a fix the way positioning mode fields do, as a letter.
+pub struct PosMode<'a>(pub &'a Fix);
+
+impl<'a> fmt::Display for PosMode<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self.0 {
+            Fix::NoFix => "N",
+            Fix::AutonomousGNSSFix => "A",
+            Fix::DifferentialGNSSFix => "D",
+            Fix::RTKFixed => "R",
+            Fix::RTKFloat => "F",
+            Fix::EstimatedOrDeadReckoningFix => "E",
+        })
+    }
+}
+
+/// Writes a flag the way A/V fields do, `A` meaning yes.
+pub struct Flag(pub bool);
+
+impl fmt::Display for Flag {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(if self.0 { "A" } else { "V" })
+    }
+}
+
+/// Writes a radar target number on two digits, as ARPA radars do.
+pub struct TargetNumber<'a>(pub &'a Option<u8>);
+
+impl<'a> fmt::Display for TargetNumber<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.0 {
+            Some(number) => write!(f, "{:02}", number),
+            None => Ok(()),
+        }
+    }
+}
@@ -1,5 +1,47 @@
 mod parsers;
 mod structs;
+mod writers;
 
 pub(crate) use parsers::*;
 pub use structs::*;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_speed_units() {
+        let expected = Ok(("1", Some(SpeedOverGroundUnit::MetersPerSecond)));
+        assert_eq!(expected, parse_speed_over_ground_unit("M,1"));
+        let expected = Ok(("", Some(SpeedOverGroundUnit::MilesPerHour)));
+        assert_eq!(expected, parse_speed_over_ground_unit("S"));
+        assert_eq!(Ok(("BUOY", None)), parse_speed_over_ground_unit(",BUOY"));
+    }
+
+    #[test]
+    fn test_parse_course_units() {
+        let expected = Ok(("1", Some(CourseOverGroundUnit::DegreesMagnetic)));
+        assert_eq!(expected, parse_course_over_ground_unit("M,1"));
+        assert_eq!(Ok((",A", None)), parse_course_over_ground_unit(",,A"));
+    }
+
+    #[test]
+    fn test_parse_water_distance_units() {
+        let expected = Ok(("1", Some(WaterDistanceUnit::NauticalMile)));
+        assert_eq!(expected, parse_water_distance_unit("N,1"));
+        assert_eq!(Ok((",A", None)), parse_water_distance_unit(",,A"));
+    }
+
+    #[test]
+    fn test_convert_to_knots() {
+        assert_eq!(Knot(3.6), SpeedOverGroundUnit::Knots.to_knots(3.6));
+        let knots = SpeedOverGroundUnit::MetersPerSecond.to_knots(1852. / 3600.);
+        assert!((knots.0 - 1.).abs() < 1e-9);
+        let knots = SpeedOverGroundUnit::KilometersPerHour.to_knots(1.852);
+        assert!((knots.0 - 1.).abs() < 1e-9);
+        let knots = SpeedOverGroundUnit::MilesPerHour.to_knots(1.);
+        assert!((knots.0 - 0.868976).abs() < 1e-6);
+        let speed = MeterPerSecond::from(Knot(1.));
+        assert!((speed.0 - 0.514444).abs() < 1e-6);
+    }
+}
@@ -10,7 +10,7 @@ pub fn parse_course_over_ground_unit(input: &str) -> IResult<&str, Option<Course
         // Index subscription is safe since input has at least 1 char
         Some('T') => (&input[1..], Some(CourseOverGroundUnit::DegreesTrue)),
         Some('M') => (&input[1..], Some(CourseOverGroundUnit::DegreesMagnetic)),
-        Some(',') => (&input[1..], None),
+        Some(',') => return Ok((&input[1..], None)),
         _ => {
             return Err(nom::Err::Failure((input, nom::error::ErrorKind::OneOf)));
         }
@@ -26,7 +26,9 @@ pub fn parse_speed_over_ground_unit(input: &str) -> IResult<&str, Option<SpeedOv
         // Index subscription is safe since input has at least 1 char
         Some('N') => (&input[1..], Some(SpeedOverGroundUnit::Knots)),
         Some('K') => (&input[1..], Some(SpeedOverGroundUnit::KilometersPerHour)),
-        Some(',') => (&input[1..], None),
+        Some('M') => (&input[1..], Some(SpeedOverGroundUnit::MetersPerSecond)),
+        Some('S') => (&input[1..], Some(SpeedOverGroundUnit::MilesPerHour)),
+        Some(',') => return Ok((&input[1..], None)),
         _ => {
             return Err(nom::Err::Failure((input, nom::error::ErrorKind::OneOf)));
         }
@@ -41,7 +43,7 @@ pub fn parse_water_distance_unit(input: &str) -> IResult<&str, Option<WaterDista
     let (remaining, result) = match input.chars().nth(0) {
         // Index subscription is safe since input has at least 1 char
         Some('N') => (&input[1..], Some(WaterDistanceUnit::NauticalMile)),
-        Some(',') => (&input[1..], None),
+        Some(',') => return Ok((&input[1..], None)),
         _ => {
             return Err(nom::Err::Failure((input, nom::error::ErrorKind::OneOf)));
         }
@@ -62,3 +64,13 @@ pub fn parse_knot(input: &str) -> IResult<&str, Option<Knot>> {
     };
     Ok((remaining, maybe_knot))
 }
+
+pub fn parse_meter_per_second(input: &str) -> IResult<&str, Option<MeterPerSecond>> {
+    let (remaining, maybe_float) = parse_float(input)?;
+    Ok((remaining, maybe_float.map(MeterPerSecond)))
+}
+
+pub fn parse_degree_per_minute(input: &str) -> IResult<&str, Option<DegreePerMinute>> {
+    let (remaining, maybe_float) = parse_float(input)?;
+    Ok((remaining, maybe_float.map(DegreePerMinute)))
+}
@@ -1,7 +1,39 @@
-#[derive(Debug, PartialEq)]
+use crate::fields::distance::{Meter, NauticalMile};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
 /// Nautical mile per hour
 pub struct Knot(pub f64);
 
+#[derive(Debug, Clone, Copy, PartialEq)]
+/// The unit of speed in the International System of Units (SI)
+pub struct MeterPerSecond(pub f64);
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+/// Statute mile per hour
+pub struct MilePerHour(pub f64);
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+/// Rate of turn, in degrees per minute, negative when turning to port
+pub struct DegreePerMinute(pub f64);
+
+impl From<MeterPerSecond> for Knot {
+    fn from(speed: MeterPerSecond) -> Self {
+        Knot(speed.0 * 3600. / 1852.)
+    }
+}
+
+impl From<MilePerHour> for Knot {
+    fn from(speed: MilePerHour) -> Self {
+        Knot(speed.0 * 1609.344 / 1852.)
+    }
+}
+
+impl From<Knot> for MeterPerSecond {
+    fn from(speed: Knot) -> Self {
+        MeterPerSecond(speed.0 * 1852. / 3600.)
+    }
+}
+
 #[derive(Debug, PartialEq)]
 /// The unit used to display the distance over water
 pub enum WaterDistanceUnit {
@@ -22,4 +54,30 @@ pub enum CourseOverGroundUnit {
 pub enum SpeedOverGroundUnit {
     Knots,
     KilometersPerHour,
+    MetersPerSecond,
+    /// Statute miles per hour
+    MilesPerHour,
+}
+
+impl SpeedOverGroundUnit {
+    /// Converts a speed expressed in this unit
+    pub fn to_knots(&self, speed: f64) -> Knot {
+        match self {
+            SpeedOverGroundUnit::Knots => Knot(speed),
+            SpeedOverGroundUnit::KilometersPerHour => Knot(speed * 1000. / 1852.),
+            SpeedOverGroundUnit::MetersPerSecond => MeterPerSecond(speed).into(),
+            SpeedOverGroundUnit::MilesPerHour => MilePerHour(speed).into(),
+        }
+    }
+
+    /// Converts a distance expressed in the matching unit of length,
+    /// such as kilometers for kilometers per hour, as radar targets are reported
+    pub fn to_nautical_miles(&self, distance: f64) -> NauticalMile {
+        match self {
+            SpeedOverGroundUnit::Knots => NauticalMile(distance),
+            SpeedOverGroundUnit::KilometersPerHour => NauticalMile(distance * 1000. / 1852.),
+            SpeedOverGroundUnit::MetersPerSecond => Meter(distance).into(),
+            SpeedOverGroundUnit::MilesPerHour => NauticalMile(distance * 1609.344 / 1852.),
+        }
+    }
 }
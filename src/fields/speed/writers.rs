@@ -0,0 +1,54 @@
+use super::structs::*;
+use core::fmt;
+
+impl fmt::Display for Knot {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl fmt::Display for MeterPerSecond {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl fmt::Display for DegreePerMinute {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl fmt::Display for MilePerHour {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl fmt::Display for WaterDistanceUnit {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self {
+            WaterDistanceUnit::NauticalMile => "N",
+        })
+    }
+}
+
+impl fmt::Display for CourseOverGroundUnit {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self {
+            CourseOverGroundUnit::DegreesTrue => "T",
+            CourseOverGroundUnit::DegreesMagnetic => "M",
+        })
+    }
+}
+
+impl fmt::Display for SpeedOverGroundUnit {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self {
+            SpeedOverGroundUnit::Knots => "N",
+            SpeedOverGroundUnit::KilometersPerHour => "K",
+            SpeedOverGroundUnit::MetersPerSecond => "M",
+            SpeedOverGroundUnit::MilesPerHour => "S",
+        })
+    }
+}
@@ -0,0 +1,24 @@
+mod parsers;
+mod structs;
+mod writers;
+
+pub(crate) use parsers::*;
+pub use structs::*;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_celsius() {
+        assert_eq!(Ok(("C", Some(Celsius(-1.5)))), parse_celsius("-1.5,C"));
+        assert_eq!(Ok(("C", None)), parse_celsius(",C"));
+        assert!(parse_celsius("warm,C").is_err());
+    }
+
+    #[test]
+    fn test_convert_celsius() {
+        assert_eq!(Celsius(100.), Celsius::from_fahrenheit(212.));
+        assert_eq!(32., Celsius(0.).to_fahrenheit());
+    }
+}
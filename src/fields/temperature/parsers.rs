@@ -0,0 +1,8 @@
+use super::structs::*;
+use crate::parser_utils::parse_float;
+use nom::IResult;
+
+pub fn parse_celsius(input: &str) -> IResult<&str, Option<Celsius>> {
+    let (remaining, maybe_float) = parse_float(input)?;
+    Ok((remaining, maybe_float.map(Celsius)))
+}
@@ -0,0 +1,13 @@
+#[derive(Debug, Clone, Copy, PartialEq)]
+/// Temperature, in degrees Celsius
+pub struct Celsius(pub f64);
+
+impl Celsius {
+    pub fn from_fahrenheit(fahrenheit: f64) -> Self {
+        Celsius((fahrenheit - 32.) * 5. / 9.)
+    }
+
+    pub fn to_fahrenheit(self) -> f64 {
+        self.0 * 9. / 5. + 32.
+    }
+}
@@ -0,0 +1,8 @@
+use super::structs::*;
+use core::fmt;
+
+impl fmt::Display for Celsius {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
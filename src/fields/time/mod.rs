@@ -1,5 +1,7 @@
 mod parsers;
 mod structs;
+mod writers;
 
 pub(crate) use parsers::*;
 pub use structs::*;
+pub(crate) use writers::*;
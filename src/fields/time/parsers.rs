@@ -30,10 +30,13 @@ pub fn parse_time(input: &str) -> IResult<&str, Option<NaiveTime>> {
         return Err(nom::Err::Failure((input, nom::error::ErrorKind::Complete)));
     }
     let (remaining, time_str) = parse_string(input)?;
+    if time_str.is_empty() {
+        return Ok((remaining, None));
+    }
 
-    let splitted: Vec<&str> = time_str.split('.').collect();
+    let mut splitted = time_str.split('.');
 
-    let maybe_time = match (splitted.get(0), splitted.get(1)) {
+    let maybe_time = match (splitted.next(), splitted.next()) {
         (Some(hms), Some(milis)) => {
             if let Ok(raw_hms) = str::parse::<u32>(hms) {
                 let hours = raw_hms / 10_000;
@@ -51,9 +54,9 @@ pub fn parse_time(input: &str) -> IResult<&str, Option<NaiveTime>> {
         }
         (Some(hms), None) => {
             if let Ok(raw_hms) = str::parse::<u32>(hms) {
-                let hours = raw_hms / 100;
-                let minutes = (raw_hms - hours) / 100;
-                let seconds = raw_hms - hours - minutes;
+                let hours = raw_hms / 10_000;
+                let minutes = (raw_hms - hours * 10_000) / 100;
+                let seconds = raw_hms - hours * 10_000 - minutes * 100;
                 NaiveTime::from_hms_opt(hours, minutes, seconds)
             } else {
                 return Err(nom::Err::Failure((input, nom::error::ErrorKind::Digit)));
@@ -64,3 +67,25 @@ pub fn parse_time(input: &str) -> IResult<&str, Option<NaiveTime>> {
 
     Ok((remaining, maybe_time))
 }
+
+// 15D
+// The D suffix means the firmware default is used, leap seconds haven't been received yet
+pub fn parse_leap_seconds(input: &str) -> IResult<&str, (Option<u8>, bool)> {
+    if input.is_empty() {
+        return Err(nom::Err::Failure((input, nom::error::ErrorKind::Complete)));
+    }
+    let (remaining, leap_str) = parse_string(input)?;
+    let (seconds_str, default) = match leap_str.strip_suffix('D') {
+        Some(seconds_str) => (seconds_str, true),
+        None => (leap_str, false),
+    };
+    let seconds = if seconds_str.is_empty() {
+        None
+    } else if let Ok(seconds) = str::parse::<u8>(seconds_str) {
+        Some(seconds)
+    } else {
+        return Err(nom::Err::Failure((input, nom::error::ErrorKind::Digit)));
+    };
+
+    Ok((remaining, (seconds, default)))
+}
@@ -0,0 +1,47 @@
+use chrono::{Datelike, NaiveDate, NaiveTime, Timelike};
+use core::fmt;
+
+/// Writes a time in the hhmmss.ss format
+pub struct Time<'a>(pub &'a Option<NaiveTime>);
+
+impl<'a> fmt::Display for Time<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if let Some(time) = self.0 {
+            // Leap seconds are represented with more than 1_000_000_000 nanoseconds
+            let (second, nanosecond) = if time.nanosecond() >= 1_000_000_000 {
+                (time.second() + 1, time.nanosecond() - 1_000_000_000)
+            } else {
+                (time.second(), time.nanosecond())
+            };
+            write!(
+                f,
+                "{:02}{:02}{:02}.{:02}",
+                time.hour(),
+                time.minute(),
+                second,
+                nanosecond / 10_000_000
+            )
+        } else {
+            Ok(())
+        }
+    }
+}
+
+/// Writes a date in the ddmmyy format
+pub struct Date<'a>(pub &'a Option<NaiveDate>);
+
+impl<'a> fmt::Display for Date<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if let Some(date) = self.0 {
+            write!(
+                f,
+                "{:02}{:02}{:02}",
+                date.day(),
+                date.month(),
+                date.year() % 100
+            )
+        } else {
+            Ok(())
+        }
+    }
+}
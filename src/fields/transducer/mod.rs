@@ -0,0 +1,108 @@
+mod parsers;
+mod structs;
+mod writers;
+
+pub(crate) use parsers::*;
+pub use structs::*;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fields::distance::Degree;
+    use crate::fields::temperature::Celsius;
+
+    fn measurement(
+        transducer_type: TransducerType,
+        value: f64,
+        unit: char,
+    ) -> TransducerMeasurement<'static> {
+        TransducerMeasurement {
+            transducer_type,
+            value: Some(value),
+            unit: Some(unit),
+            name: "",
+        }
+    }
+
+    #[test]
+    fn test_parse_transducer_measurements() {
+        let input = "C,19.5,C,AIRTEMP,P,1.013,B,BARO,X,,,";
+        assert_eq!(Ok(("", input)), parse_transducer_measurements(input));
+
+        let (remaining, _) = parse_transducer_measurement(input).unwrap();
+        let (remaining, measurement) = parse_transducer_measurement(remaining).unwrap();
+        assert_eq!("X,,,", remaining);
+        assert_eq!(
+            TransducerMeasurement {
+                transducer_type: TransducerType::Pressure,
+                value: Some(1.013),
+                unit: Some('B'),
+                name: "BARO",
+            },
+            measurement
+        );
+        let (_, measurement) = parse_transducer_measurement(remaining).unwrap();
+        assert_eq!(TransducerType::Other('X'), measurement.transducer_type);
+        assert_eq!(None, measurement.value);
+        assert_eq!("", measurement.name);
+    }
+
+    #[test]
+    fn test_parse_invalid_transducer_type() {
+        assert!(parse_transducer_measurements("c,19.5,C,AIRTEMP").is_err());
+        assert!(parse_transducer_measurements("CC,19.5,C,AIRTEMP").is_err());
+    }
+
+    #[test]
+    fn test_parse_invalid_unit() {
+        let input = "C,19.5,C,AIRTEMP,C,19.5,CC,WATERTEMP";
+        assert_eq!(
+            Err(nom::Err::Failure((
+                "CC,WATERTEMP",
+                nom::error::ErrorKind::OneOf
+            ))),
+            parse_transducer_measurements(input)
+        );
+    }
+
+    #[test]
+    fn test_parse_long_name() {
+        let (_, measurement) =
+            parse_transducer_measurement("P,1.02481,B,ENGINE#0_OIL_PRESSURE").unwrap();
+        assert_eq!("ENGINE#0_OIL_PRESSURE", measurement.name);
+    }
+
+    #[test]
+    fn test_transducer_quantity() {
+        let quantity = measurement(TransducerType::Temperature, 19.5, 'C').quantity();
+        assert_eq!(
+            Some(TransducerQuantity::Temperature(Celsius(19.5))),
+            quantity
+        );
+        let quantity = measurement(TransducerType::Temperature, 212., 'F').quantity();
+        assert_eq!(
+            Some(TransducerQuantity::Temperature(Celsius(100.))),
+            quantity
+        );
+        let quantity = measurement(TransducerType::Pressure, 1.5, 'B').quantity();
+        assert_eq!(
+            Some(TransducerQuantity::Pressure(Pascal(150000.))),
+            quantity
+        );
+        let quantity = measurement(TransducerType::Angular, -1.5, 'D').quantity();
+        assert_eq!(Some(TransducerQuantity::Angle(Degree(-1.5))), quantity);
+        let quantity = measurement(TransducerType::Voltage, 12.6, 'V').quantity();
+        assert_eq!(Some(TransducerQuantity::Voltage(Volt(12.6))), quantity);
+        let quantity = measurement(TransducerType::Humidity, 65., 'P').quantity();
+        assert_eq!(Some(TransducerQuantity::Humidity(Percent(65.))), quantity);
+
+        assert_eq!(
+            None,
+            measurement(TransducerType::Tachometer, 1200., 'R').quantity()
+        );
+        assert_eq!(
+            None,
+            measurement(TransducerType::Pressure, 1.013, 'X').quantity()
+        );
+    }
+}
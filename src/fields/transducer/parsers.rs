@@ -0,0 +1,67 @@
+use super::structs::*;
+use crate::parser_utils::*;
+use nom::sequence::tuple;
+use nom::IResult;
+
+/// Checks every type, value, unit and name quadruplet, returns the raw measurements
+pub fn parse_transducer_measurements(input: &str) -> IResult<&str, &str> {
+    let mut remaining = input;
+    while !remaining.is_empty() {
+        remaining = parse_transducer_measurement(remaining)?.0;
+    }
+    Ok((remaining, input))
+}
+
+pub fn parse_transducer_measurement(input: &str) -> IResult<&str, TransducerMeasurement<'_>> {
+    let (remaining, (transducer_type, value, unit, name)) = tuple((
+        parse_transducer_type,
+        parse_float,
+        parse_unit,
+        parse_last_string,
+    ))(input)?;
+    Ok((
+        remaining,
+        TransducerMeasurement {
+            transducer_type,
+            value,
+            unit,
+            name,
+        },
+    ))
+}
+
+fn parse_unit(input: &str) -> IResult<&str, Option<char>> {
+    let (remaining, unit_str) = parse_string(input)?;
+    let mut chars = unit_str.chars();
+    match (chars.next(), chars.next()) {
+        (None, _) => Ok((remaining, None)),
+        (Some(unit), None) => Ok((remaining, Some(unit))),
+        _ => Err(nom::Err::Failure((input, nom::error::ErrorKind::OneOf))),
+    }
+}
+
+fn parse_transducer_type(input: &str) -> IResult<&str, TransducerType> {
+    let (remaining, type_str) = parse_string(input)?;
+    let mut chars = type_str.chars();
+    let result = match (chars.next(), chars.next()) {
+        (Some('A'), None) => TransducerType::Angular,
+        (Some('C'), None) => TransducerType::Temperature,
+        (Some('D'), None) => TransducerType::Linear,
+        (Some('F'), None) => TransducerType::Frequency,
+        (Some('G'), None) => TransducerType::Generic,
+        (Some('H'), None) => TransducerType::Humidity,
+        (Some('I'), None) => TransducerType::Current,
+        (Some('N'), None) => TransducerType::Force,
+        (Some('P'), None) => TransducerType::Pressure,
+        (Some('R'), None) => TransducerType::FlowRate,
+        (Some('S'), None) => TransducerType::Switch,
+        (Some('T'), None) => TransducerType::Tachometer,
+        (Some('U'), None) => TransducerType::Voltage,
+        (Some('V'), None) => TransducerType::Volume,
+        (Some(other), None) if other.is_ascii_uppercase() => TransducerType::Other(other),
+        _ => {
+            return Err(nom::Err::Failure((input, nom::error::ErrorKind::OneOf)));
+        }
+    };
+    Ok((remaining, result))
+}
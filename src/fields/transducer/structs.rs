@@ -0,0 +1,98 @@
+use crate::fields::distance::Degree;
+use crate::fields::temperature::Celsius;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+/// Type of a transducer
+pub enum TransducerType {
+    /// Angular displacement (`A`)
+    Angular,
+    /// Temperature (`C`)
+    Temperature,
+    /// Linear displacement (`D`)
+    Linear,
+    /// Frequency (`F`)
+    Frequency,
+    /// Generic (`G`)
+    Generic,
+    /// Humidity (`H`)
+    Humidity,
+    /// Current (`I`)
+    Current,
+    /// Force (`N`)
+    Force,
+    /// Pressure (`P`)
+    Pressure,
+    /// Flow rate (`R`)
+    FlowRate,
+    /// Switch or valve (`S`)
+    Switch,
+    /// Tachometer (`T`)
+    Tachometer,
+    /// Voltage (`U`)
+    Voltage,
+    /// Volume (`V`)
+    Volume,
+    /// Any other type
+    Other(char),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+/// Measurement of a transducer, as sent in XDR messages
+pub struct TransducerMeasurement<'a> {
+    pub transducer_type: TransducerType,
+    /// Measured value, in `unit`
+    pub value: Option<f64>,
+    /// Units of measure, such as `C` for Celsius or `B` for bars
+    pub unit: Option<char>,
+    /// Transducer identifier
+    pub name: &'a str,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+/// Pressure
+pub struct Pascal(pub f64);
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+/// Electric potential
+pub struct Volt(pub f64);
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+/// Relative quantity, such as relative humidity
+pub struct Percent(pub f64);
+
+#[derive(Debug, Clone, PartialEq)]
+/// A measurement of a common transducer type, converted to a typed quantity
+pub enum TransducerQuantity {
+    Temperature(Celsius),
+    Pressure(Pascal),
+    Angle(Degree),
+    Voltage(Volt),
+    Humidity(Percent),
+}
+
+impl<'a> TransducerMeasurement<'a> {
+    /// Interprets the measurement of a temperature, pressure, angular displacement,
+    /// voltage or humidity transducer.
+    ///
+    /// Returns `None` for other transducer types, missing values and unknown units.
+    pub fn quantity(&self) -> Option<TransducerQuantity> {
+        let value = self.value?;
+        let quantity = match (self.transducer_type, self.unit) {
+            (TransducerType::Temperature, Some('C')) => {
+                TransducerQuantity::Temperature(Celsius(value))
+            }
+            (TransducerType::Temperature, Some('F')) => {
+                TransducerQuantity::Temperature(Celsius::from_fahrenheit(value))
+            }
+            (TransducerType::Pressure, Some('P')) => TransducerQuantity::Pressure(Pascal(value)),
+            (TransducerType::Pressure, Some('B')) => {
+                TransducerQuantity::Pressure(Pascal(value * 1e5))
+            }
+            (TransducerType::Angular, Some('D')) => TransducerQuantity::Angle(Degree(value)),
+            (TransducerType::Voltage, Some('V')) => TransducerQuantity::Voltage(Volt(value)),
+            (TransducerType::Humidity, Some('P')) => TransducerQuantity::Humidity(Percent(value)),
+            _ => return None,
+        };
+        Some(quantity)
+    }
+}
@@ -0,0 +1,39 @@
+use super::structs::*;
+use crate::writer_utils::Optional;
+use core::fmt;
+
+impl fmt::Display for TransducerType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let code = match self {
+            TransducerType::Angular => 'A',
+            TransducerType::Temperature => 'C',
+            TransducerType::Linear => 'D',
+            TransducerType::Frequency => 'F',
+            TransducerType::Generic => 'G',
+            TransducerType::Humidity => 'H',
+            TransducerType::Current => 'I',
+            TransducerType::Force => 'N',
+            TransducerType::Pressure => 'P',
+            TransducerType::FlowRate => 'R',
+            TransducerType::Switch => 'S',
+            TransducerType::Tachometer => 'T',
+            TransducerType::Voltage => 'U',
+            TransducerType::Volume => 'V',
+            TransducerType::Other(code) => *code,
+        };
+        write!(f, "{}", code)
+    }
+}
+
+impl<'a> fmt::Display for TransducerMeasurement<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{},{},{},{}",
+            self.transducer_type,
+            Optional(&self.value),
+            Optional(&self.unit),
+            self.name
+        )
+    }
+}
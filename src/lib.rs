@@ -1,13 +1,33 @@
+#![cfg_attr(not(any(test, feature = "std")), no_std)]
+
 extern crate chrono;
+extern crate heapless;
 extern crate nom;
 
 pub use crate::error::Error;
 
+pub mod ais;
+#[cfg(feature = "std")]
+pub mod collision;
+pub mod decoder;
 pub mod error;
 pub mod fields;
+#[cfg(feature = "std")]
+mod math_utils;
 pub mod messages;
+#[cfg(feature = "std")]
+pub mod navigation;
 mod parser_utils;
+#[cfg(feature = "std")]
+pub mod reader;
+#[cfg(feature = "std")]
+pub mod registry;
+#[cfg(feature = "std")]
+pub mod route;
 pub mod sentence;
+#[cfg(feature = "std")]
+pub mod wind;
+mod writer_utils;
 
 pub use sentence::*;
 
@@ -18,13 +38,14 @@ pub use sentence::*;
 /// ```
 /// # use nmea_0183::parse;
 /// # use nmea_0183::Error;
-/// # fn main() -> Result<(),  Error<'static>> {
+/// # fn main() -> Result<(), Error> {
 /// // Get a sentence to parse.
 /// // According to the specification, an nmea sentence must end with CRLF
 /// let raw_nmea = "$GPGGA,092725.00,4717.11399,N,00833.91590,E,1,08,1.01,499.6,M,48.0,M,,*5B\r\n";
 /// let parsed_sentence = parse(raw_nmea)?;
 ///    /*
 ///    Sentence {
+///        tag_block: None,
 ///        sentence_type: Parametric,
 ///        talker: GPS,
 ///        message: GGA(GGAMessage {
@@ -51,6 +72,69 @@ pub fn parse(input: &str) -> Result<Sentence, Error> {
     Ok(parse_result.1)
 }
 
+/// Decodes the proprietary sentences of the manufacturers the crate doesn't know about.
+///
+/// Closures taking the manufacturer mnemonic and the raw body implement this trait.
+pub trait ProprietaryDecoder {
+    /// Message produced by the decoder
+    type Output;
+
+    /// Decodes the body of a proprietary sentence,
+    /// everything between the manufacturer mnemonic and the checksum.
+    /// Returns `Ok(None)` for the sentences the decoder doesn't handle.
+    fn decode(&self, manufacturer: &str, body: &str) -> Result<Option<Self::Output>, Error>;
+}
+
+impl<F, T> ProprietaryDecoder for F
+where
+    F: Fn(&str, &str) -> Result<Option<T>, Error>,
+{
+    type Output = T;
+
+    fn decode(&self, manufacturer: &str, body: &str) -> Result<Option<T>, Error> {
+        self(manufacturer, body)
+    }
+}
+
+/// Parse a sentence, and decode its body with a vendor decoder
+/// when it is a proprietary sentence the crate keeps raw.
+///
+/// Unlike the `registry` module, this doesn't require the `std` feature.
+///
+/// # Examples
+///
+/// ```
+/// # use nmea_0183::{parse_with, Error};
+/// // Horizontal position error from Garmin's $PGRME
+/// let garmin = |manufacturer: &str, body: &str| match (manufacturer, body.strip_prefix("E,")) {
+///     ("GRM", Some(fields)) => fields
+///         .split(',')
+///         .next()
+///         .and_then(|hpe| hpe.parse::<f64>().ok())
+///         .map(Some)
+///         .ok_or(Error::Framing),
+///     _ => Ok(None),
+/// };
+///
+/// let (_sentence, hpe) = parse_with("$PGRME,15.0,M,45.0,M,25.0,M*1C\r\n", &garmin)?;
+/// assert_eq!(Some(15.), hpe);
+/// # Ok::<(), Error>(())
+/// ```
+pub fn parse_with<'a, D: ProprietaryDecoder>(
+    input: &'a str,
+    decoder: &D,
+) -> Result<(Sentence<'a>, Option<D::Output>), Error> {
+    let (_, sentence) = parse_sentence(input)?;
+    let decoded = match sentence.message {
+        Message::Proprietary {
+            manufacturer,
+            body: ProprietaryBody::Raw(body),
+        } => decoder.decode(manufacturer, body)?,
+        _ => None,
+    };
+    Ok((sentence, decoded))
+}
+
 #[cfg(test)]
 mod talker_tests {
     use super::*;
@@ -62,6 +146,7 @@ mod talker_tests {
     fn test_parse_valid() {
         let input = "$GPVTG,77.52,T,,M,0.004,N,0.008,K,A*06\r\n";
         let expected_sentence = Sentence {
+            tag_block: None,
             sentence_type: SentenceType::Parametric,
             talker: Talker::GPS,
             message: Message::VTG(messages::VTGMessage {
@@ -83,10 +168,50 @@ mod talker_tests {
     #[test]
     fn test_parse_missing_crlf() {
         let input = "$GPVTG,77.52,T,,M,0.004,N,0.008,K,A*06";
-        let expected_error = Err(Error::ParseError(nom::Err::Error((
-            "06",
-            nom::error::ErrorKind::TakeUntil,
-        ))));
-        assert_eq!(expected_error, parse(input));
+        assert_eq!(Err(Error::MissingCrLf), parse(input));
+    }
+}
+
+#[cfg(test)]
+mod proprietary_decoder_tests {
+    use super::*;
+    use fields::identity::*;
+
+    fn garmin(manufacturer: &str, body: &str) -> Result<Option<f64>, Error> {
+        match (manufacturer, body.strip_prefix("E,")) {
+            ("GRM", Some(fields)) => fields
+                .split(',')
+                .next()
+                .and_then(|hpe| hpe.parse::<f64>().ok())
+                .map(Some)
+                .ok_or(Error::Framing),
+            _ => Ok(None),
+        }
+    }
+
+    #[test]
+    fn test_parse_with_decoder() {
+        let input = "$PGRME,15.0,M,45.0,M,25.0,M*1C\r\n";
+        let (sentence, decoded) = parse_with(input, &garmin).unwrap();
+        assert_eq!(Talker::Proprietary, sentence.talker);
+        assert_eq!(Some(15.), decoded);
+    }
+
+    #[test]
+    fn test_parse_with_decoder_not_handling() {
+        let input = "$PMTK001,604,3*32\r\n";
+        let (sentence, decoded) = parse_with(input, &garmin).unwrap();
+        assert_eq!(parse(input).unwrap(), sentence);
+        assert_eq!(None, decoded);
+
+        // Sentences decoded by the crate are left alone
+        let input = "$GPVTG,77.52,T,,M,0.004,N,0.008,K,A*06\r\n";
+        assert_eq!(None, parse_with(input, &garmin).unwrap().1);
+    }
+
+    #[test]
+    fn test_parse_with_decoder_error() {
+        let input = "$PGRME,X,M,45.0,M,25.0,M*5E\r\n";
+        assert_eq!(Err(Error::Framing), parse_with(input, &garmin));
     }
 }
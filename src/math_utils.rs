@@ -0,0 +1,34 @@
+/// Brings an angle in degrees into [0, 360)
+pub fn normalize(angle: f64) -> f64 {
+    let angle = angle.rem_euclid(360.);
+    // rem_euclid can round up to 360 for tiny negative angles
+    if angle >= 360. {
+        0.
+    } else {
+        angle
+    }
+}
+
+/// Rounds a value to a number of decimals, for writing
+pub fn round(value: f64, decimals: i32) -> f64 {
+    let factor = 10_f64.powi(decimals);
+    (value * factor).round() / factor
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_normalize() {
+        assert_eq!(90., normalize(450.));
+        assert_eq!(270., normalize(-90.));
+        assert_eq!(0., normalize(-1e-15));
+    }
+
+    #[test]
+    fn test_round() {
+        assert_eq!(12.35, round(12.345_6, 2));
+        assert_eq!(-0.1, round(-0.14, 1));
+    }
+}
@@ -0,0 +1,208 @@
+use crate::fields::cardinality::*;
+use crate::fields::distance::*;
+use crate::fields::parameter::*;
+use crate::fields::speed::*;
+use crate::parser_utils::*;
+use crate::writer_utils::*;
+use core::fmt;
+use nom::sequence::tuple;
+use nom::IResult;
+
+#[derive(Debug, PartialEq)]
+/// Heading/Track Controller (Autopilot) Sentence B
+pub struct APBMessage<'a> {
+    /// General warning status, `DataInvalid` for a LORAN-C blink or SNR warning
+    pub status: Status,
+    /// Cycle lock status, `DataInvalid` for a LORAN-C cycle lock warning
+    pub cycle_lock: Status,
+    /// Cross-track error magnitude
+    pub xte: Option<NauticalMile>,
+    /// Direction to steer to get back on track
+    pub steer: Option<LeftRight>,
+    /// Whether the arrival circle has been entered
+    pub arrival_circle_entered: bool,
+    /// Whether the perpendicular at the destination has been passed
+    pub perpendicular_passed: bool,
+    /// Bearing from the origin to the destination
+    pub bearing_origin_to_destination: Option<Degree>,
+    /// Bearing from the origin to the destination reference
+    pub bearing_origin_to_destination_unit: Option<CourseOverGroundUnit>,
+    /// Destination waypoint identifier
+    pub destination: &'a str,
+    /// Bearing from the present position to the destination
+    pub bearing_to_destination: Option<Degree>,
+    /// Bearing from the present position to the destination reference
+    pub bearing_to_destination_unit: Option<CourseOverGroundUnit>,
+    /// Heading to steer to the destination
+    pub heading_to_steer: Option<Degree>,
+    /// Heading to steer to the destination reference
+    pub heading_to_steer_unit: Option<CourseOverGroundUnit>,
+    /// Mode Indicator, since NMEA 2.3
+    pub pos_mode: Option<Fix>,
+}
+
+/// Names of the fields, in the order they appear in the sentence
+pub(crate) const FIELDS: &[&str] = &[
+    "status",
+    "cycle_lock",
+    "xte",
+    "steer",
+    "xte_unit",
+    "arrival_circle_entered",
+    "perpendicular_passed",
+    "bearing_origin_to_destination",
+    "bearing_origin_to_destination_unit",
+    "destination",
+    "bearing_to_destination",
+    "bearing_to_destination_unit",
+    "heading_to_steer",
+    "heading_to_steer_unit",
+    "pos_mode",
+];
+
+pub fn parse_apb(input: &str) -> IResult<&str, APBMessage<'_>> {
+    let (
+        remaining,
+        (status, cycle_lock, xte, steer, _, arrival_circle_entered, perpendicular_passed),
+    ) = tuple((
+        parse_status,
+        parse_status,
+        parse_nautical_mile,
+        parse_maybe_left_right_indicator,
+        ensure_unit("N"),
+        parse_flag,
+        parse_flag,
+    ))(input)?;
+    let (
+        remaining,
+        (
+            bearing_origin_to_destination,
+            bearing_origin_to_destination_unit,
+            destination,
+            bearing_to_destination,
+            bearing_to_destination_unit,
+            heading_to_steer,
+            heading_to_steer_unit,
+            pos_mode,
+        ),
+    ) = tuple((
+        parse_raw_degree,
+        parse_course_over_ground_unit,
+        parse_string,
+        parse_raw_degree,
+        parse_course_over_ground_unit,
+        parse_raw_degree,
+        parse_course_over_ground_unit,
+        parse_maybe_pos_mode,
+    ))(remaining)?;
+    Ok((
+        remaining,
+        APBMessage {
+            status,
+            cycle_lock,
+            xte,
+            steer,
+            arrival_circle_entered,
+            perpendicular_passed,
+            bearing_origin_to_destination,
+            bearing_origin_to_destination_unit,
+            destination,
+            bearing_to_destination,
+            bearing_to_destination_unit,
+            heading_to_steer,
+            heading_to_steer_unit,
+            pos_mode,
+        },
+    ))
+}
+
+impl<'a> fmt::Display for APBMessage<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{},{},{},{},N,{},{},{},{},{},{},{},{},{}",
+            self.status,
+            self.cycle_lock,
+            Optional(&self.xte),
+            Optional(&self.steer),
+            Flag(self.arrival_circle_entered),
+            Flag(self.perpendicular_passed),
+            Optional(&self.bearing_origin_to_destination),
+            Optional(&self.bearing_origin_to_destination_unit),
+            self.destination,
+            Optional(&self.bearing_to_destination),
+            Optional(&self.bearing_to_destination_unit),
+            Optional(&self.heading_to_steer),
+            Optional(&self.heading_to_steer_unit)
+        )?;
+        if let Some(pos_mode) = &self.pos_mode {
+            write!(f, ",{}", PosMode(pos_mode))?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_apb() {
+        let input = "A,A,0.10,R,N,V,V,011,M,DEST,011,M,011,M";
+        let expected = Ok((
+            "",
+            APBMessage {
+                status: Status::DataValid,
+                cycle_lock: Status::DataValid,
+                xte: Some(NauticalMile(0.1)),
+                steer: Some(LeftRight::Right),
+                arrival_circle_entered: false,
+                perpendicular_passed: false,
+                bearing_origin_to_destination: Some(Degree(11.)),
+                bearing_origin_to_destination_unit: Some(CourseOverGroundUnit::DegreesMagnetic),
+                destination: "DEST",
+                bearing_to_destination: Some(Degree(11.)),
+                bearing_to_destination_unit: Some(CourseOverGroundUnit::DegreesMagnetic),
+                heading_to_steer: Some(Degree(11.)),
+                heading_to_steer_unit: Some(CourseOverGroundUnit::DegreesMagnetic),
+                pos_mode: None,
+            },
+        ));
+
+        assert_eq!(expected, parse_apb(input));
+    }
+
+    #[test]
+    fn test_parse_apb_with_mode() {
+        let input = "A,A,0.1,L,N,A,V,45.2,T,WP2,44.8,T,43.5,T,D";
+        let (_, message) = parse_apb(input).unwrap();
+        assert!(message.arrival_circle_entered);
+        assert_eq!(Some(LeftRight::Left), message.steer);
+        assert_eq!(Some(Fix::DifferentialGNSSFix), message.pos_mode);
+    }
+
+    #[test]
+    fn test_parse_apb_without_origin() {
+        let input = "A,A,0.10,R,N,V,V,,,,011,M,011,M,A";
+        let (remaining, message) = parse_apb(input).unwrap();
+        assert_eq!("", remaining);
+        assert_eq!(None, message.bearing_origin_to_destination);
+        assert_eq!(None, message.bearing_origin_to_destination_unit);
+        assert_eq!("", message.destination);
+        assert_eq!(Some(Degree(11.)), message.bearing_to_destination);
+        assert_eq!(
+            Some(CourseOverGroundUnit::DegreesMagnetic),
+            message.bearing_to_destination_unit
+        );
+        assert_eq!(Some(Degree(11.)), message.heading_to_steer);
+        assert_eq!(Some(Fix::AutonomousGNSSFix), message.pos_mode);
+    }
+
+    #[test]
+    fn test_write_apb() {
+        let input = "A,A,0.1,L,N,A,V,45.2,T,WP2,44.8,T,43.5,T,D";
+        let (_, message) = parse_apb(input).unwrap();
+
+        assert_eq!(input, message.to_string());
+    }
+}
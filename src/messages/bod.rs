@@ -0,0 +1,99 @@
+use crate::fields::distance::*;
+use crate::parser_utils::*;
+use crate::writer_utils::*;
+use core::fmt;
+use nom::sequence::tuple;
+use nom::IResult;
+
+#[derive(Debug, PartialEq)]
+/// Bearing, Origin to Destination
+pub struct BODMessage<'a> {
+    /// Bearing from the origin to the destination, in degrees true
+    pub bearing_true: Option<Degree>,
+    /// Bearing from the origin to the destination, in degrees magnetic
+    pub bearing_magnetic: Option<Degree>,
+    /// Destination waypoint identifier
+    pub destination: &'a str,
+    /// Origin waypoint identifier
+    pub origin: &'a str,
+}
+
+/// Names of the fields, in the order they appear in the sentence
+pub(crate) const FIELDS: &[&str] = &[
+    "bearing_true",
+    "true",
+    "bearing_magnetic",
+    "magnetic",
+    "destination",
+    "origin",
+];
+
+pub fn parse_bod(input: &str) -> IResult<&str, BODMessage<'_>> {
+    let (remaining, (bearing_true, _, bearing_magnetic, _, destination, origin)) = tuple((
+        parse_raw_degree,
+        ensure_unit("T"),
+        parse_raw_degree,
+        ensure_unit("M"),
+        parse_string,
+        parse_last_string,
+    ))(input)?;
+    Ok((
+        remaining,
+        BODMessage {
+            bearing_true,
+            bearing_magnetic,
+            destination,
+            origin,
+        },
+    ))
+}
+
+impl<'a> fmt::Display for BODMessage<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{},T,{},M,{},{}",
+            Optional(&self.bearing_true),
+            Optional(&self.bearing_magnetic),
+            self.destination,
+            self.origin
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_bod() {
+        let input = "099.3,T,105.6,M,POINTB,POINTA";
+        let expected = Ok((
+            "",
+            BODMessage {
+                bearing_true: Some(Degree(99.3)),
+                bearing_magnetic: Some(Degree(105.6)),
+                destination: "POINTB",
+                origin: "POINTA",
+            },
+        ));
+
+        assert_eq!(expected, parse_bod(input));
+    }
+
+    #[test]
+    fn test_parse_bod_without_origin() {
+        let input = "97,T,103.2,M,POINTB,";
+        let (_, message) = parse_bod(input).unwrap();
+        assert_eq!("POINTB", message.destination);
+        assert_eq!("", message.origin);
+    }
+
+    #[test]
+    fn test_write_bod() {
+        let input = "99.3,T,105.6,M,POINTB,POINTA";
+        let (_, message) = parse_bod(input).unwrap();
+
+        assert_eq!(input, message.to_string());
+    }
+}
@@ -0,0 +1,171 @@
+use crate::fields::cardinality::*;
+use crate::fields::distance::*;
+use crate::fields::parameter::*;
+use crate::fields::time::*;
+use crate::parser_utils::*;
+use crate::writer_utils::*;
+use chrono::naive::NaiveTime;
+use core::fmt;
+use nom::sequence::tuple;
+use nom::IResult;
+
+#[derive(Debug, PartialEq)]
+/// Bearing and Distance to Waypoint
+///
+/// Computed along the great circle for BWC messages,
+/// and along the rhumb line for BWR messages.
+pub struct BWCMessage<'a> {
+    /// UTC time of the observation
+    pub time: Option<NaiveTime>,
+    /// Waypoint latitude
+    pub lat: Option<Degree>,
+    /// North/South indicator
+    pub ns: Option<NorthSouth>,
+    /// Waypoint longitude
+    pub lon: Option<Degree>,
+    /// East/West indicator
+    pub ew: Option<EastWest>,
+    /// Bearing to the waypoint, in degrees true
+    pub bearing_true: Option<Degree>,
+    /// Bearing to the waypoint, in degrees magnetic
+    pub bearing_magnetic: Option<Degree>,
+    /// Distance to the waypoint
+    pub distance: Option<NauticalMile>,
+    /// Waypoint identifier
+    pub waypoint: &'a str,
+    /// Mode Indicator, since NMEA 2.3
+    pub pos_mode: Option<Fix>,
+}
+
+/// Names of the fields, in the order they appear in the sentence
+pub(crate) const FIELDS: &[&str] = &[
+    "time",
+    "lat",
+    "ns",
+    "lon",
+    "ew",
+    "bearing_true",
+    "true",
+    "bearing_magnetic",
+    "magnetic",
+    "distance",
+    "distance_unit",
+    "waypoint",
+    "pos_mode",
+];
+
+pub fn parse_bwc(input: &str) -> IResult<&str, BWCMessage<'_>> {
+    let (remaining, (time, lat, ns, lon, ew)) = tuple((
+        parse_time,
+        parse_degree,
+        parse_maybe_north_south_indicator,
+        parse_degree,
+        parse_maybe_east_west_indicator,
+    ))(input)?;
+    let (remaining, (bearing_true, _, bearing_magnetic, _, distance, _, waypoint, pos_mode)) =
+        tuple((
+            parse_raw_degree,
+            ensure_unit("T"),
+            parse_raw_degree,
+            ensure_unit("M"),
+            parse_nautical_mile,
+            ensure_unit("N"),
+            parse_last_string,
+            parse_maybe_pos_mode,
+        ))(remaining)?;
+    Ok((
+        remaining,
+        BWCMessage {
+            time,
+            lat,
+            ns,
+            lon,
+            ew,
+            bearing_true,
+            bearing_magnetic,
+            distance,
+            waypoint,
+            pos_mode,
+        },
+    ))
+}
+
+impl<'a> fmt::Display for BWCMessage<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{},{},{},{},{},{},T,{},M,{},N,{}",
+            Time(&self.time),
+            DegreesMinutes(&self.lat, 10, 5),
+            Optional(&self.ns),
+            DegreesMinutes(&self.lon, 11, 5),
+            Optional(&self.ew),
+            Optional(&self.bearing_true),
+            Optional(&self.bearing_magnetic),
+            Optional(&self.distance),
+            self.waypoint
+        )?;
+        if let Some(pos_mode) = &self.pos_mode {
+            write!(f, ",{}", PosMode(pos_mode))?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::naive::NaiveTime;
+
+    #[test]
+    fn test_parse_bwc() {
+        let input = "220516,5130.02,N,00046.34,W,213.8,T,218.0,M,0004.6,N,EGLM,A";
+        let expected = Ok((
+            "",
+            BWCMessage {
+                time: Some(NaiveTime::from_hms_opt(22, 5, 16).unwrap()),
+                lat: Some(Degree(51.300200000000004)),
+                ns: Some(NorthSouth::North),
+                lon: Some(Degree(0.46340000000000003)),
+                ew: Some(EastWest::West),
+                bearing_true: Some(Degree(213.8)),
+                bearing_magnetic: Some(Degree(218.0)),
+                distance: Some(NauticalMile(4.6)),
+                waypoint: "EGLM",
+                pos_mode: Some(Fix::AutonomousGNSSFix),
+            },
+        ));
+
+        assert_eq!(expected, parse_bwc(input));
+    }
+
+    #[test]
+    fn test_parse_bwc_without_mode() {
+        let input = "220516,,,,,,T,,M,,N,EGLM";
+        let expected = Ok((
+            "",
+            BWCMessage {
+                time: Some(NaiveTime::from_hms_opt(22, 5, 16).unwrap()),
+                lat: None,
+                ns: None,
+                lon: None,
+                ew: None,
+                bearing_true: None,
+                bearing_magnetic: None,
+                distance: None,
+                waypoint: "EGLM",
+                pos_mode: None,
+            },
+        ));
+
+        assert_eq!(expected, parse_bwc(input));
+    }
+
+    #[test]
+    fn test_write_bwc() {
+        let input = "220516.00,5130.02000,N,00046.34000,W,213.8,T,218.1,M,4.6,N,EGLM,A";
+        let (_, message) = parse_bwc(input).unwrap();
+
+        assert_eq!(input, message.to_string());
+    }
+}
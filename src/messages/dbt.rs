@@ -0,0 +1,110 @@
+use crate::fields::distance::*;
+use crate::parser_utils::ensure_unit;
+use crate::writer_utils::*;
+use core::fmt;
+use nom::sequence::tuple;
+use nom::IResult;
+
+#[derive(Debug, PartialEq)]
+/// Depth Below Transducer
+///
+/// Also used by the legacy DBS (Depth Below Surface) and DBK (Depth Below Keel) messages,
+/// which only differ by the reference the depth is measured from.
+pub struct DBTMessage {
+    /// Depth, in feet
+    pub depth_feet: Option<Foot>,
+    /// Depth, in meters
+    pub depth_meters: Option<Meter>,
+    /// Depth, in fathoms
+    pub depth_fathoms: Option<Fathom>,
+}
+
+/// Names of the fields, in the order they appear in the sentence
+pub(crate) const FIELDS: &[&str] = &[
+    "depth_feet",
+    "depth_feet_unit",
+    "depth_meters",
+    "depth_meters_unit",
+    "depth_fathoms",
+    "depth_fathoms_unit",
+];
+
+pub fn parse_dbt(input: &str) -> IResult<&str, DBTMessage> {
+    let (remaining, (depth_feet, _, depth_meters, _, depth_fathoms, _)) = tuple((
+        parse_foot,
+        ensure_unit("f"),
+        parse_meter,
+        ensure_unit("M"),
+        parse_fathom,
+        ensure_unit("F"),
+    ))(input)?;
+    Ok((
+        remaining,
+        DBTMessage {
+            depth_feet,
+            depth_meters,
+            depth_fathoms,
+        },
+    ))
+}
+
+impl fmt::Display for DBTMessage {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{},f,{},M,{},F",
+            Optional(&self.depth_feet),
+            Optional(&self.depth_meters),
+            Optional(&self.depth_fathoms)
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_dbt() {
+        let input = "7.8,f,2.4,M,1.3,F";
+        let expected = Ok((
+            "",
+            DBTMessage {
+                depth_feet: Some(Foot(7.8)),
+                depth_meters: Some(Meter(2.4)),
+                depth_fathoms: Some(Fathom(1.3)),
+            },
+        ));
+
+        assert_eq!(expected, parse_dbt(input));
+    }
+
+    #[test]
+    fn test_parse_dbt_meters_only() {
+        let input = ",f,2.4,M,,F";
+        let expected = Ok((
+            "",
+            DBTMessage {
+                depth_feet: None,
+                depth_meters: Some(Meter(2.4)),
+                depth_fathoms: None,
+            },
+        ));
+
+        assert_eq!(expected, parse_dbt(input));
+    }
+
+    #[test]
+    fn test_parse_dbt_wrong_unit() {
+        let input = "7.8,M,2.4,M,1.3,F";
+        assert!(parse_dbt(input).is_err());
+    }
+
+    #[test]
+    fn test_write_dbt() {
+        let input = "7.8,f,2.4,M,1.3,F";
+        let (_, message) = parse_dbt(input).unwrap();
+
+        assert_eq!(input, message.to_string());
+    }
+}
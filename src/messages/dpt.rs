@@ -0,0 +1,95 @@
+use crate::fields::distance::*;
+use crate::writer_utils::*;
+use core::fmt;
+use nom::sequence::tuple;
+use nom::IResult;
+
+#[derive(Debug, PartialEq)]
+/// Depth of Water
+pub struct DPTMessage {
+    /// Water depth relative to the transducer
+    pub depth: Option<Meter>,
+    /// Offset from the transducer, positive for the distance to the waterline,
+    /// negative for the distance to the keel
+    pub offset: Option<Meter>,
+    /// Maximum range scale in use, only sent since NMEA 3.0
+    pub max_range: Option<Meter>,
+}
+
+/// Names of the fields, in the order they appear in the sentence
+pub(crate) const FIELDS: &[&str] = &["depth", "offset", "max_range"];
+
+pub fn parse_dpt(input: &str) -> IResult<&str, DPTMessage> {
+    let (remaining, (depth, offset, max_range)) =
+        tuple((parse_meter, parse_meter, parse_max_range))(input)?;
+    Ok((
+        remaining,
+        DPTMessage {
+            depth,
+            offset,
+            max_range,
+        },
+    ))
+}
+
+fn parse_max_range(input: &str) -> IResult<&str, Option<Meter>> {
+    parse_meter(input).or_else(|err| match err {
+        nom::Err::Failure((input, nom::error::ErrorKind::Complete)) => Ok((input, None)),
+        _ => Err(err),
+    })
+}
+
+impl fmt::Display for DPTMessage {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{},{},{}",
+            Optional(&self.depth),
+            Optional(&self.offset),
+            Optional(&self.max_range)
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_dpt() {
+        let input = "2.4,-0.5,100";
+        let expected = Ok((
+            "",
+            DPTMessage {
+                depth: Some(Meter(2.4)),
+                offset: Some(Meter(-0.5)),
+                max_range: Some(Meter(100.)),
+            },
+        ));
+
+        assert_eq!(expected, parse_dpt(input));
+    }
+
+    #[test]
+    fn test_parse_dpt_without_max_range() {
+        let input = "2.3,0.0";
+        let expected = Ok((
+            "",
+            DPTMessage {
+                depth: Some(Meter(2.3)),
+                offset: Some(Meter(0.)),
+                max_range: None,
+            },
+        ));
+
+        assert_eq!(expected, parse_dpt(input));
+    }
+
+    #[test]
+    fn test_write_dpt() {
+        let input = "2.4,-0.5,100";
+        let (_, message) = parse_dpt(input).unwrap();
+
+        assert_eq!(input, message.to_string());
+    }
+}
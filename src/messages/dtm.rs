@@ -1,6 +1,8 @@
 use crate::fields::cardinality::*;
 use crate::fields::distance::*;
 use crate::parser_utils::*;
+use crate::writer_utils::*;
+use core::fmt;
 use nom::sequence::tuple;
 use nom::IResult;
 
@@ -29,6 +31,18 @@ pub struct DTMMessage<'a> {
     pub ref_datum: &'a str,
 }
 
+/// Names of the fields, in the order they appear in the sentence
+pub(crate) const FIELDS: &[&str] = &[
+    "datum",
+    "sub_datum",
+    "lat",
+    "ns",
+    "lon",
+    "ew",
+    "alt",
+    "ref_datum",
+];
+
 pub fn parse_dtm(input: &str) -> IResult<&str, DTMMessage> {
     let (remaining, datum_ref) = tuple((
         parse_string,
@@ -55,6 +69,23 @@ pub fn parse_dtm(input: &str) -> IResult<&str, DTMMessage> {
     ))
 }
 
+impl<'a> fmt::Display for DTMMessage<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{},{},{},{},{},{},{},{}",
+            self.datum,
+            self.sub_datum,
+            Optional(&self.lat),
+            self.ns,
+            Optional(&self.lon),
+            self.ew,
+            Optional(&self.alt),
+            self.ref_datum
+        )
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -79,4 +110,13 @@ mod tests {
 
         assert_eq!(expected, parse_dtm(input));
     }
+
+    #[test]
+    fn test_write_dtm() {
+        let input = "W84,,0.0,N,0.0,E,0.0,W84";
+        let expected = "W84,,0,N,0,E,0,W84";
+        let (_, message) = parse_dtm(input).unwrap();
+
+        assert_eq!(expected, message.to_string());
+    }
 }
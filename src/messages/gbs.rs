@@ -1,7 +1,9 @@
 use crate::fields::distance::*;
 use crate::fields::time::*;
 use crate::parser_utils::*;
+use crate::writer_utils::*;
 use chrono::naive::NaiveTime;
+use core::fmt;
 use nom::sequence::tuple;
 use nom::IResult;
 
@@ -30,6 +32,20 @@ pub struct GBSMessage {
     pub signal_id: Option<u8>,
 }
 
+/// Names of the fields, in the order they appear in the sentence
+pub(crate) const FIELDS: &[&str] = &[
+    "time",
+    "lat_err",
+    "lon_err",
+    "alt_err",
+    "sat_prn",
+    "prob",
+    "res",
+    "std_dev",
+    "system_id",
+    "signal_id",
+];
+
 pub fn parse_gbs(input: &str) -> IResult<&str, GBSMessage> {
     let (
         remaining,
@@ -63,6 +79,25 @@ pub fn parse_gbs(input: &str) -> IResult<&str, GBSMessage> {
     ))
 }
 
+impl fmt::Display for GBSMessage {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{},{},{},{},{:02},{},{},{},{},{}",
+            Time(&self.time),
+            Optional(&self.lat_err),
+            Optional(&self.lon_err),
+            Optional(&self.alt_err),
+            Optional(&self.sat_prn),
+            Optional(&self.prob),
+            Optional(&self.res),
+            Optional(&self.std_dev),
+            Optional(&self.system_id),
+            Optional(&self.signal_id)
+        )
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -88,4 +123,13 @@ mod tests {
 
         assert_eq!(expected, parse_gbs(input));
     }
+
+    #[test]
+    fn test_write_gbs() {
+        let input = "235458.00,1.4,1.3,3.1,03,,-21.4,3.8,1,0";
+        let expected = "235458.00,1.4,1.3,3.1,03,,-21.4,3.8,1,0";
+        let (_, message) = parse_gbs(input).unwrap();
+
+        assert_eq!(expected, message.to_string());
+    }
 }
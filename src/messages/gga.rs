@@ -3,7 +3,9 @@ use crate::fields::distance::*;
 use crate::fields::identity::*;
 use crate::fields::parameter::*;
 use crate::fields::time::*;
+use crate::writer_utils::*;
 use chrono::naive::NaiveTime;
+use core::fmt;
 use nom::sequence::tuple;
 use nom::IResult;
 
@@ -36,6 +38,24 @@ pub struct GGAMessage {
     pub diff_station: Option<u8>,
 }
 
+/// Names of the fields, in the order they appear in the sentence
+pub(crate) const FIELDS: &[&str] = &[
+    "time",
+    "lat",
+    "ns",
+    "lon",
+    "ew",
+    "quality",
+    "num_sv",
+    "hdop",
+    "alt",
+    "alt_unit",
+    "sep",
+    "sep_unit",
+    "diff_age",
+    "diff_station",
+];
+
 pub fn parse_gga(input: &str) -> IResult<&str, GGAMessage> {
     let (
         remaining,
@@ -75,6 +95,27 @@ pub fn parse_gga(input: &str) -> IResult<&str, GGAMessage> {
     ))
 }
 
+impl fmt::Display for GGAMessage {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{},{},{},{},{},{},{:02},{},{},M,{},M,{},{}",
+            Time(&self.time),
+            DegreesMinutes(&self.lat, 10, 5),
+            self.ns,
+            DegreesMinutes(&self.lon, 11, 5),
+            self.ew,
+            Quality(&self.quality),
+            Optional(&self.num_sv),
+            Optional(&self.hdop),
+            Optional(&self.alt),
+            Optional(&self.sep),
+            Optional(&self.diff_age),
+            Optional(&self.diff_station)
+        )
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -102,4 +143,13 @@ mod tests {
 
         assert_eq!(expected, parse_gga(input));
     }
+
+    #[test]
+    fn test_write_gga() {
+        let input = "092725.00,4717.11399,N,00833.91590,E,1,08,1.01,499.6,M,48.0,M,,";
+        let expected = "092725.00,4717.11399,N,00833.91590,E,1,08,1.01,499.6,M,48,M,,";
+        let (_, message) = parse_gga(input).unwrap();
+
+        assert_eq!(expected, message.to_string());
+    }
 }
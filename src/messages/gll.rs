@@ -3,6 +3,7 @@ use crate::fields::distance::*;
 use crate::fields::parameter::*;
 use crate::fields::time::*;
 use chrono::naive::NaiveTime;
+use core::fmt;
 use nom::sequence::tuple;
 use nom::IResult;
 
@@ -25,6 +26,9 @@ pub struct GLLMessage {
     pub pos_mode: Fix,
 }
 
+/// Names of the fields, in the order they appear in the sentence
+pub(crate) const FIELDS: &[&str] = &["lat", "ns", "lon", "ew", "time", "status", "pos_mode"];
+
 pub fn parse_gll(input: &str) -> IResult<&str, GLLMessage> {
     let (remaining, (lat, ns, lon, ew, time, status, pos_mode)) = tuple((
         parse_degree,
@@ -49,6 +53,22 @@ pub fn parse_gll(input: &str) -> IResult<&str, GLLMessage> {
     ))
 }
 
+impl fmt::Display for GLLMessage {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{},{},{},{},{},{},{}",
+            DegreesMinutes(&self.lat, 10, 5),
+            self.ns,
+            DegreesMinutes(&self.lon, 11, 5),
+            self.ew,
+            Time(&self.time),
+            self.status,
+            PosMode(&self.pos_mode)
+        )
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -71,4 +91,13 @@ mod tests {
 
         assert_eq!(expected, parse_gll(input));
     }
+
+    #[test]
+    fn test_write_gll() {
+        let input = "4717.11364,N,00833.91565,E,092321.00,A,A";
+        let expected = "4717.11364,N,00833.91565,E,092321.00,A,A";
+        let (_, message) = parse_gll(input).unwrap();
+
+        assert_eq!(expected, message.to_string());
+    }
 }
@@ -1,4 +1,5 @@
 use crate::parser_utils::parse_string;
+use core::fmt;
 use nom::IResult;
 
 #[derive(Debug, PartialEq)]
@@ -8,11 +9,20 @@ pub struct GLQMessage<'a> {
     pub msg_id: &'a str,
 }
 
+/// Names of the fields, in the order they appear in the sentence
+pub(crate) const FIELDS: &[&str] = &["msg_id"];
+
 pub fn parse_glq(input: &str) -> IResult<&str, GLQMessage> {
     let (remaining, msg_id) = parse_string(input)?;
     Ok((remaining, GLQMessage { msg_id }))
 }
 
+impl<'a> fmt::Display for GLQMessage<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.msg_id)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -24,4 +34,13 @@ mod tests {
 
         assert_eq!(expected, parse_glq(input));
     }
+
+    #[test]
+    fn test_write_glq() {
+        let input = "RMC";
+        let expected = "RMC";
+        let (_, message) = parse_glq(input).unwrap();
+
+        assert_eq!(expected, message.to_string());
+    }
 }
@@ -1,4 +1,5 @@
 use crate::parser_utils::parse_string;
+use core::fmt;
 use nom::IResult;
 
 #[derive(Debug, PartialEq)]
@@ -8,11 +9,20 @@ pub struct GNQMessage<'a> {
     pub msg_id: &'a str,
 }
 
+/// Names of the fields, in the order they appear in the sentence
+pub(crate) const FIELDS: &[&str] = &["msg_id"];
+
 pub fn parse_gnq(input: &str) -> IResult<&str, GNQMessage> {
     let (remaining, msg_id) = parse_string(input)?;
     Ok((remaining, GNQMessage { msg_id }))
 }
 
+impl<'a> fmt::Display for GNQMessage<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.msg_id)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -24,4 +34,13 @@ mod tests {
 
         assert_eq!(expected, parse_gnq(input));
     }
+
+    #[test]
+    fn test_write_gnq() {
+        let input = "RMC";
+        let expected = "RMC";
+        let (_, message) = parse_gnq(input).unwrap();
+
+        assert_eq!(expected, message.to_string());
+    }
 }
@@ -3,7 +3,10 @@ use crate::fields::distance::*;
 use crate::fields::parameter::*;
 use crate::fields::time::*;
 use crate::parser_utils::*;
+use crate::writer_utils::*;
 use chrono::naive::NaiveTime;
+use core::fmt;
+use heapless::Vec;
 use nom::sequence::tuple;
 use nom::IResult;
 
@@ -20,8 +23,8 @@ pub struct GNSMessage {
     pub lon: Option<Degree>,
     /// East/West indicator
     pub ew: Option<EastWest>,
-    /// Positioning mode,
-    pub pos_mode: Vec<Fix>,
+    /// Positioning mode, one per constellation
+    pub pos_mode: Vec<Fix, MAX_POS_MODES>,
     /// Number of satellites used
     pub num_sv: Option<u8>,
     /// Horizontal Dilution of Precision
@@ -38,6 +41,23 @@ pub struct GNSMessage {
     pub nav_status: Status,
 }
 
+/// Names of the fields, in the order they appear in the sentence
+pub(crate) const FIELDS: &[&str] = &[
+    "time",
+    "lat",
+    "ns",
+    "lon",
+    "ew",
+    "pos_mode",
+    "num_sv",
+    "hdop",
+    "alt",
+    "sep",
+    "diff_age",
+    "diff_station",
+    "nav_status",
+];
+
 pub fn parse_gns(input: &str) -> IResult<&str, GNSMessage> {
     let (
         remaining,
@@ -91,6 +111,34 @@ pub fn parse_gns(input: &str) -> IResult<&str, GNSMessage> {
     ))
 }
 
+impl fmt::Display for GNSMessage {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{},{},{},{},{},",
+            Time(&self.time),
+            DegreesMinutes(&self.lat, 10, 5),
+            Optional(&self.ns),
+            DegreesMinutes(&self.lon, 11, 5),
+            Optional(&self.ew)
+        )?;
+        for fix in &self.pos_mode {
+            write!(f, "{}", PosMode(fix))?;
+        }
+        write!(
+            f,
+            ",{:02},{},{},{},{},{},{}",
+            Optional(&self.num_sv),
+            Optional(&self.hdop),
+            Optional(&self.alt),
+            Optional(&self.sep),
+            Optional(&self.diff_age),
+            Optional(&self.diff_station),
+            self.nav_status
+        )
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -106,7 +154,13 @@ mod tests {
                 ns: Some(NorthSouth::North),
                 lon: Some(Degree(0.12293799999999999)), // floats ¯\_(ツ)_/¯
                 ew: Some(EastWest::West),
-                pos_mode: vec![Fix::AutonomousGNSSFix, Fix::NoFix, Fix::NoFix, Fix::NoFix],
+                pos_mode: Vec::from_slice(&[
+                    Fix::AutonomousGNSSFix,
+                    Fix::NoFix,
+                    Fix::NoFix,
+                    Fix::NoFix,
+                ])
+                .unwrap(),
                 num_sv: Some(7),
                 hdop: Some(1.18),
                 alt: Some(Meter(111.5)),
@@ -119,4 +173,12 @@ mod tests {
 
         assert_eq!(expected, parse_gns(input));
     }
+
+    #[test]
+    fn test_write_gns() {
+        let input = "103600.01,5114.51176,N,00012.29380,W,ANNN,07,1.18,111.5,45.6,,,V";
+        let (_, message) = parse_gns(input).unwrap();
+
+        assert_eq!(input, message.to_string());
+    }
 }
@@ -1,4 +1,5 @@
 use crate::parser_utils::parse_string;
+use core::fmt;
 use nom::IResult;
 
 #[derive(Debug, PartialEq)]
@@ -8,11 +9,20 @@ pub struct GPQMessage<'a> {
     pub msg_id: &'a str,
 }
 
+/// Names of the fields, in the order they appear in the sentence
+pub(crate) const FIELDS: &[&str] = &["msg_id"];
+
 pub fn parse_gpq(input: &str) -> IResult<&str, GPQMessage> {
     let (remaining, msg_id) = parse_string(input)?;
     Ok((remaining, GPQMessage { msg_id }))
 }
 
+impl<'a> fmt::Display for GPQMessage<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.msg_id)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -24,4 +34,13 @@ mod tests {
 
         assert_eq!(expected, parse_gpq(input));
     }
+
+    #[test]
+    fn test_write_gpq() {
+        let input = "RMC";
+        let expected = "RMC";
+        let (_, message) = parse_gpq(input).unwrap();
+
+        assert_eq!(expected, message.to_string());
+    }
 }
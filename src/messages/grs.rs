@@ -2,7 +2,9 @@ use crate::fields::distance::*;
 use crate::fields::identity::*;
 use crate::fields::parameter::*;
 use crate::fields::time::*;
+use crate::writer_utils::*;
 use chrono::naive::NaiveTime;
+use core::fmt;
 use nom::sequence::tuple;
 use nom::IResult;
 
@@ -21,6 +23,26 @@ pub struct GRSMessage {
     pub signal_id: Option<u8>,
 }
 
+/// Names of the fields, in the order they appear in the sentence
+pub(crate) const FIELDS: &[&str] = &[
+    "time",
+    "mode",
+    "residuals",
+    "residuals",
+    "residuals",
+    "residuals",
+    "residuals",
+    "residuals",
+    "residuals",
+    "residuals",
+    "residuals",
+    "residuals",
+    "residuals",
+    "residuals",
+    "system_id",
+    "signal_id",
+];
+
 pub fn parse_grs(input: &str) -> IResult<&str, GRSMessage> {
     let (remaining, (time, mode, residuals, system_id, signal_id)) = tuple((
         parse_time,
@@ -41,6 +63,20 @@ pub fn parse_grs(input: &str) -> IResult<&str, GRSMessage> {
     ))
 }
 
+impl fmt::Display for GRSMessage {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{},{},{},{},{}",
+            Time(&self.time),
+            Optional(&self.mode),
+            Separated(&self.residuals),
+            Optional(&self.system_id),
+            Optional(&self.signal_id)
+        )
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -74,4 +110,13 @@ mod tests {
 
         assert_eq!(expected, parse_grs(input));
     }
+
+    #[test]
+    fn test_write_grs() {
+        let input = "104148.00,1,2.6,2.2,-1.6,-1.1,-1.7,-1.5,5.8,1.7,,,,,1,1";
+        let expected = "104148.00,1,2.6,2.2,-1.6,-1.1,-1.7,-1.5,5.8,1.7,,,,,1,1";
+        let (_, message) = parse_grs(input).unwrap();
+
+        assert_eq!(expected, message.to_string());
+    }
 }
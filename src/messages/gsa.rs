@@ -1,6 +1,8 @@
 use crate::fields::distance::*;
 use crate::fields::identity::*;
 use crate::fields::parameter::*;
+use crate::writer_utils::*;
+use core::fmt;
 use nom::sequence::tuple;
 use nom::IResult;
 
@@ -21,6 +23,27 @@ pub struct GSAMessage {
     pub vdop: Option<Meter>,
 }
 
+/// Names of the fields, in the order they appear in the sentence
+pub(crate) const FIELDS: &[&str] = &[
+    "op_mode",
+    "nav_mode",
+    "sattelite_ids",
+    "sattelite_ids",
+    "sattelite_ids",
+    "sattelite_ids",
+    "sattelite_ids",
+    "sattelite_ids",
+    "sattelite_ids",
+    "sattelite_ids",
+    "sattelite_ids",
+    "sattelite_ids",
+    "sattelite_ids",
+    "sattelite_ids",
+    "pdop",
+    "hdop",
+    "vdop",
+];
+
 pub fn parse_gsa(input: &str) -> IResult<&str, GSAMessage> {
     let (remaining, (op_mode, nav_mode, sattelite_ids, pdop, hdop, vdop)) = tuple((
         parse_operation_mode,
@@ -43,6 +66,21 @@ pub fn parse_gsa(input: &str) -> IResult<&str, GSAMessage> {
     ))
 }
 
+impl fmt::Display for GSAMessage {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{},{},{:02},{},{},{}",
+            self.op_mode,
+            self.nav_mode,
+            Separated(&self.sattelite_ids),
+            Optional(&self.pdop),
+            Optional(&self.hdop),
+            Optional(&self.vdop)
+        )
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -77,4 +115,13 @@ mod tests {
 
         assert_eq!(expected, parse_gsa(input));
     }
+
+    #[test]
+    fn test_write_gsa() {
+        let input = "A,3,80,71,73,79,69,,,,,,,,1.83,1.09,1.47";
+        let expected = "A,3,80,71,73,79,69,,,,,,,,1.83,1.09,1.47";
+        let (_, message) = parse_gsa(input).unwrap();
+
+        assert_eq!(expected, message.to_string());
+    }
 }
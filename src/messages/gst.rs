@@ -1,6 +1,8 @@
 use crate::fields::distance::*;
 use crate::fields::time::*;
+use crate::writer_utils::*;
 use chrono::naive::NaiveTime;
+use core::fmt;
 use nom::sequence::tuple;
 use nom::IResult;
 
@@ -25,6 +27,18 @@ pub struct GSTMessage {
     pub std_alt: Option<Meter>,
 }
 
+/// Names of the fields, in the order they appear in the sentence
+pub(crate) const FIELDS: &[&str] = &[
+    "time",
+    "range_rms",
+    "std_major",
+    "std_minor",
+    "orient",
+    "std_lat",
+    "std_lon",
+    "std_alt",
+];
+
 pub fn parse_gst(input: &str) -> IResult<&str, GSTMessage> {
     let (remaining, (time, range_rms, std_major, std_minor, orient, std_lat, std_lon, std_alt)) =
         tuple((
@@ -52,6 +66,23 @@ pub fn parse_gst(input: &str) -> IResult<&str, GSTMessage> {
     ))
 }
 
+impl fmt::Display for GSTMessage {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{},{},{},{},{},{},{},{}",
+            Time(&self.time),
+            Optional(&self.range_rms),
+            Optional(&self.std_major),
+            Optional(&self.std_minor),
+            DegreesMinutes(&self.orient, 0, 5),
+            Optional(&self.std_lat),
+            Optional(&self.std_lon),
+            Optional(&self.std_alt)
+        )
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -75,4 +106,13 @@ mod tests {
 
         assert_eq!(expected, parse_gst(input));
     }
+
+    #[test]
+    fn test_write_gst() {
+        let input = "082356.00,1.8,,,,1.7,1.3,2.2";
+        let expected = "082356.00,1.8,,,,1.7,1.3,2.2";
+        let (_, message) = parse_gst(input).unwrap();
+
+        assert_eq!(expected, message.to_string());
+    }
 }
@@ -1,5 +1,7 @@
 use crate::fields::identity::*;
 use crate::parser_utils::*;
+use core::fmt;
+use heapless::Vec;
 use nom::sequence::tuple;
 use nom::IResult;
 
@@ -14,9 +16,32 @@ pub struct GSVMessage {
     /// both the talker ID and the signalId
     pub satellite_num: u8,
     /// Satellites in view
-    pub satellites: Vec<SatelliteInView>,
+    pub satellites: Vec<SatelliteInView, MAX_SATELLITES_IN_VIEW>,
 }
 
+/// Names of the fields, in the order they appear in the sentence
+pub(crate) const FIELDS: &[&str] = &[
+    "total_msgs",
+    "msg_num",
+    "satellite_num",
+    "satellites.id",
+    "satellites.elv",
+    "satellites.az",
+    "satellites.cno",
+    "satellites.id",
+    "satellites.elv",
+    "satellites.az",
+    "satellites.cno",
+    "satellites.id",
+    "satellites.elv",
+    "satellites.az",
+    "satellites.cno",
+    "satellites.id",
+    "satellites.elv",
+    "satellites.az",
+    "satellites.cno",
+];
+
 pub fn parse_gsv(input: &str) -> IResult<&str, GSVMessage> {
     let (remaining, (maybe_total_msgs, maybe_msg_num, maybe_satellite_num, satellites)) =
         tuple((parse_u8, parse_u8, parse_u8, parse_satellites_in_view))(input)?;
@@ -34,6 +59,20 @@ pub fn parse_gsv(input: &str) -> IResult<&str, GSVMessage> {
     }
 }
 
+impl fmt::Display for GSVMessage {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{},{},{}",
+            self.total_msgs, self.msg_num, self.satellite_num
+        )?;
+        for satellite in &self.satellites {
+            write!(f, ",{}", satellite)?;
+        }
+        Ok(())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -49,7 +88,7 @@ mod tests {
                 total_msgs: 3,
                 msg_num: 1,
                 satellite_num: 11,
-                satellites: vec![
+                satellites: Vec::from_slice(&[
                     SatelliteInView {
                         id: Some(3),
                         elv: Some(Degree(3.)),
@@ -74,10 +113,20 @@ mod tests {
                         az: Some(Degree(292.)),
                         cno: Some(DBHZ(0.)),
                     },
-                ],
+                ])
+                .unwrap(),
             },
         ));
 
         assert_eq!(expected, parse_gsv(input));
     }
+
+    #[test]
+    fn test_write_gsv() {
+        let input = "3,1,11,03,03,111,00,04,15,270,00,06,01,010,00,13,06,292,00";
+        let expected = "3,1,11,03,3,111,0,04,15,270,0,06,1,10,0,13,6,292,0";
+        let (_, message) = parse_gsv(input).unwrap();
+
+        assert_eq!(expected, message.to_string());
+    }
 }
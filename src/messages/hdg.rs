@@ -0,0 +1,115 @@
+use crate::fields::cardinality::*;
+use crate::fields::distance::*;
+use crate::writer_utils::*;
+use core::fmt;
+use nom::sequence::tuple;
+use nom::IResult;
+
+#[derive(Debug, PartialEq)]
+/// Heading, Deviation and Variation
+///
+/// Magnetic sensor heading, which becomes the magnetic heading once corrected by the deviation,
+/// and the true heading once corrected by the variation.
+pub struct HDGMessage {
+    /// Magnetic sensor heading, in degrees
+    pub heading: Option<Degree>,
+    /// Magnetic deviation, in degrees
+    pub deviation: Option<Degree>,
+    /// Magnetic deviation direction
+    pub deviation_ew: Option<EastWest>,
+    /// Magnetic variation, in degrees
+    pub variation: Option<Degree>,
+    /// Magnetic variation direction
+    pub variation_ew: Option<EastWest>,
+}
+
+/// Names of the fields, in the order they appear in the sentence
+pub(crate) const FIELDS: &[&str] = &[
+    "heading",
+    "deviation",
+    "deviation_ew",
+    "variation",
+    "variation_ew",
+];
+
+pub fn parse_hdg(input: &str) -> IResult<&str, HDGMessage> {
+    let (remaining, (heading, deviation, deviation_ew, variation, variation_ew)) = tuple((
+        parse_raw_degree,
+        parse_raw_degree,
+        parse_maybe_east_west_indicator,
+        parse_raw_degree,
+        parse_maybe_east_west_indicator,
+    ))(input)?;
+    Ok((
+        remaining,
+        HDGMessage {
+            heading,
+            deviation,
+            deviation_ew,
+            variation,
+            variation_ew,
+        },
+    ))
+}
+
+impl fmt::Display for HDGMessage {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{},{},{},{},{}",
+            Optional(&self.heading),
+            Optional(&self.deviation),
+            Optional(&self.deviation_ew),
+            Optional(&self.variation),
+            Optional(&self.variation_ew)
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_hdg() {
+        let input = "98.3,0.0,E,12.6,W";
+        let expected = Ok((
+            "",
+            HDGMessage {
+                heading: Some(Degree(98.3)),
+                deviation: Some(Degree(0.)),
+                deviation_ew: Some(EastWest::East),
+                variation: Some(Degree(12.6)),
+                variation_ew: Some(EastWest::West),
+            },
+        ));
+
+        assert_eq!(expected, parse_hdg(input));
+    }
+
+    #[test]
+    fn test_parse_hdg_without_deviation() {
+        let input = "101.1,,,7.1,W";
+        let expected = Ok((
+            "",
+            HDGMessage {
+                heading: Some(Degree(101.1)),
+                deviation: None,
+                deviation_ew: None,
+                variation: Some(Degree(7.1)),
+                variation_ew: Some(EastWest::West),
+            },
+        ));
+
+        assert_eq!(expected, parse_hdg(input));
+    }
+
+    #[test]
+    fn test_write_hdg() {
+        let input = "98.3,0.0,E,12.6,W";
+        let expected = "98.3,0,E,12.6,W";
+        let (_, message) = parse_hdg(input).unwrap();
+
+        assert_eq!(expected, message.to_string());
+    }
+}
@@ -0,0 +1,55 @@
+use crate::fields::distance::*;
+use crate::parser_utils::*;
+use crate::writer_utils::*;
+use core::fmt;
+use nom::sequence::tuple;
+use nom::IResult;
+
+#[derive(Debug, PartialEq)]
+/// Heading, Magnetic
+///
+/// Heading relative to magnetic north, deviation already applied.
+pub struct HDMMessage {
+    /// Heading, in degrees magnetic
+    pub heading: Option<Degree>,
+}
+
+/// Names of the fields, in the order they appear in the sentence
+pub(crate) const FIELDS: &[&str] = &["heading", "magnetic"];
+
+pub fn parse_hdm(input: &str) -> IResult<&str, HDMMessage> {
+    let (remaining, (heading, _)) = tuple((parse_raw_degree, parse_string))(input)?;
+    Ok((remaining, HDMMessage { heading }))
+}
+
+impl fmt::Display for HDMMessage {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{},M", Optional(&self.heading))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_hdm() {
+        let input = "172.5,M";
+        let expected = Ok((
+            "",
+            HDMMessage {
+                heading: Some(Degree(172.5)),
+            },
+        ));
+
+        assert_eq!(expected, parse_hdm(input));
+    }
+
+    #[test]
+    fn test_write_hdm() {
+        let input = "172.5,M";
+        let (_, message) = parse_hdm(input).unwrap();
+
+        assert_eq!(input, message.to_string());
+    }
+}
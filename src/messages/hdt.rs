@@ -0,0 +1,55 @@
+use crate::fields::distance::*;
+use crate::parser_utils::*;
+use crate::writer_utils::*;
+use core::fmt;
+use nom::sequence::tuple;
+use nom::IResult;
+
+#[derive(Debug, PartialEq)]
+/// Heading, True
+///
+/// Heading relative to true north, usually sent by a gyro compass.
+pub struct HDTMessage {
+    /// Heading, in degrees true
+    pub heading: Option<Degree>,
+}
+
+/// Names of the fields, in the order they appear in the sentence
+pub(crate) const FIELDS: &[&str] = &["heading", "true"];
+
+pub fn parse_hdt(input: &str) -> IResult<&str, HDTMessage> {
+    let (remaining, (heading, _)) = tuple((parse_raw_degree, parse_string))(input)?;
+    Ok((remaining, HDTMessage { heading }))
+}
+
+impl fmt::Display for HDTMessage {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{},T", Optional(&self.heading))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_hdt() {
+        let input = "274.07,T";
+        let expected = Ok((
+            "",
+            HDTMessage {
+                heading: Some(Degree(274.07)),
+            },
+        ));
+
+        assert_eq!(expected, parse_hdt(input));
+    }
+
+    #[test]
+    fn test_write_hdt() {
+        let input = "274.07,T";
+        let (_, message) = parse_hdt(input).unwrap();
+
+        assert_eq!(input, message.to_string());
+    }
+}
@@ -1,3 +1,8 @@
+pub(crate) mod apb;
+pub(crate) mod bod;
+pub(crate) mod bwc;
+pub(crate) mod dbt;
+pub(crate) mod dpt;
 pub(crate) mod dtm;
 pub(crate) mod gbq;
 pub(crate) mod gbs;
@@ -11,12 +16,41 @@ pub(crate) mod grs;
 pub(crate) mod gsa;
 pub(crate) mod gst;
 pub(crate) mod gsv;
+pub(crate) mod hdg;
+pub(crate) mod hdm;
+pub(crate) mod hdt;
+pub(crate) mod mtw;
+pub(crate) mod mwd;
+pub(crate) mod mwv;
+pub(crate) mod osd;
+pub(crate) mod pubx00;
+pub(crate) mod pubx03;
+pub(crate) mod pubx04;
+pub(crate) mod rmb;
 pub(crate) mod rmc;
+pub(crate) mod rot;
+pub(crate) mod rsa;
+pub(crate) mod rte;
+pub(crate) mod ths;
+pub(crate) mod tll;
+pub(crate) mod ttm;
 pub(crate) mod txt;
+pub(crate) mod vbw;
+pub(crate) mod vdm;
+pub(crate) mod vhw;
 pub(crate) mod vlw;
 pub(crate) mod vtg;
+pub(crate) mod vwr;
+pub(crate) mod wpl;
+pub(crate) mod xdr;
+pub(crate) mod xte;
 pub(crate) mod zda;
 
+pub(crate) use apb::parse_apb;
+pub(crate) use bod::parse_bod;
+pub(crate) use bwc::parse_bwc;
+pub(crate) use dbt::parse_dbt;
+pub(crate) use dpt::parse_dpt;
 pub(crate) use dtm::parse_dtm;
 pub(crate) use gbq::parse_gbq;
 pub(crate) use gbs::parse_gbs;
@@ -30,12 +64,41 @@ pub(crate) use grs::parse_grs;
 pub(crate) use gsa::parse_gsa;
 pub(crate) use gst::parse_gst;
 pub(crate) use gsv::parse_gsv;
+pub(crate) use hdg::parse_hdg;
+pub(crate) use hdm::parse_hdm;
+pub(crate) use hdt::parse_hdt;
+pub(crate) use mtw::parse_mtw;
+pub(crate) use mwd::parse_mwd;
+pub(crate) use mwv::parse_mwv;
+pub(crate) use osd::parse_osd;
+pub(crate) use pubx00::parse_pubx00;
+pub(crate) use pubx03::parse_pubx03;
+pub(crate) use pubx04::parse_pubx04;
+pub(crate) use rmb::parse_rmb;
 pub(crate) use rmc::parse_rmc;
+pub(crate) use rot::parse_rot;
+pub(crate) use rsa::parse_rsa;
+pub(crate) use rte::parse_rte;
+pub(crate) use ths::parse_ths;
+pub(crate) use tll::parse_tll;
+pub(crate) use ttm::parse_ttm;
 pub(crate) use txt::parse_txt;
+pub(crate) use vbw::parse_vbw;
+pub(crate) use vdm::parse_vdm;
+pub(crate) use vhw::parse_vhw;
 pub(crate) use vlw::parse_vlw;
 pub(crate) use vtg::parse_vtg;
+pub(crate) use vwr::parse_vwr;
+pub(crate) use wpl::parse_wpl;
+pub(crate) use xdr::parse_xdr;
+pub(crate) use xte::parse_xte;
 pub(crate) use zda::parse_zda;
 
+pub use apb::APBMessage;
+pub use bod::BODMessage;
+pub use bwc::BWCMessage;
+pub use dbt::DBTMessage;
+pub use dpt::DPTMessage;
 pub use dtm::DTMMessage;
 pub use gbq::GBQMessage;
 pub use gbs::GBSMessage;
@@ -49,8 +112,32 @@ pub use grs::GRSMessage;
 pub use gsa::GSAMessage;
 pub use gst::GSTMessage;
 pub use gsv::GSVMessage;
+pub use hdg::HDGMessage;
+pub use hdm::HDMMessage;
+pub use hdt::HDTMessage;
+pub use mtw::MTWMessage;
+pub use mwd::MWDMessage;
+pub use mwv::MWVMessage;
+pub use osd::OSDMessage;
+pub use pubx00::PUBX00Message;
+pub use pubx03::PUBX03Message;
+pub use pubx04::PUBX04Message;
+pub use rmb::RMBMessage;
 pub use rmc::RMCMessage;
+pub use rot::ROTMessage;
+pub use rsa::RSAMessage;
+pub use rte::RTEMessage;
+pub use ths::THSMessage;
+pub use tll::TLLMessage;
+pub use ttm::TTMMessage;
 pub use txt::TXTMessage;
+pub use vbw::VBWMessage;
+pub use vdm::VDMMessage;
+pub use vhw::VHWMessage;
 pub use vlw::VLWMessage;
 pub use vtg::VTGMessage;
+pub use vwr::VWRMessage;
+pub use wpl::WPLMessage;
+pub use xdr::XDRMessage;
+pub use xte::XTEMessage;
 pub use zda::ZDAMessage;
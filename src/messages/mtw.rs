@@ -0,0 +1,59 @@
+use crate::fields::temperature::*;
+use crate::parser_utils::*;
+use crate::writer_utils::*;
+use core::fmt;
+use nom::sequence::tuple;
+use nom::IResult;
+
+#[derive(Debug, PartialEq)]
+/// Mean Temperature of Water
+pub struct MTWMessage {
+    /// Water temperature
+    pub temperature: Option<Celsius>,
+}
+
+/// Names of the fields, in the order they appear in the sentence
+pub(crate) const FIELDS: &[&str] = &["temperature", "temperature_unit"];
+
+pub fn parse_mtw(input: &str) -> IResult<&str, MTWMessage> {
+    let (remaining, (temperature, _)) = tuple((parse_celsius, ensure_unit("C")))(input)?;
+    Ok((remaining, MTWMessage { temperature }))
+}
+
+impl fmt::Display for MTWMessage {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{},C", Optional(&self.temperature))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_mtw() {
+        let input = "17.9,C";
+        let expected = Ok((
+            "",
+            MTWMessage {
+                temperature: Some(Celsius(17.9)),
+            },
+        ));
+
+        assert_eq!(expected, parse_mtw(input));
+    }
+
+    #[test]
+    fn test_parse_mtw_wrong_unit() {
+        let input = "64.2,F";
+        assert!(parse_mtw(input).is_err());
+    }
+
+    #[test]
+    fn test_write_mtw() {
+        let input = "17.9,C";
+        let (_, message) = parse_mtw(input).unwrap();
+
+        assert_eq!(input, message.to_string());
+    }
+}
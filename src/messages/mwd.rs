@@ -0,0 +1,115 @@
+use crate::fields::distance::*;
+use crate::fields::speed::*;
+use crate::parser_utils::*;
+use crate::writer_utils::*;
+use core::fmt;
+use nom::sequence::tuple;
+use nom::IResult;
+
+#[derive(Debug, PartialEq)]
+/// Wind Direction and Speed
+///
+/// Direction the true wind comes from, relative to the north.
+pub struct MWDMessage {
+    /// Wind direction, in degrees true
+    pub direction_true: Option<Degree>,
+    /// Wind direction, in degrees magnetic
+    pub direction_magnetic: Option<Degree>,
+    /// Wind speed, in knots
+    pub speed: Option<Knot>,
+    /// Wind speed, in meters per second
+    pub speed_ms: Option<MeterPerSecond>,
+}
+
+/// Names of the fields, in the order they appear in the sentence
+pub(crate) const FIELDS: &[&str] = &[
+    "direction_true",
+    "direction_true_unit",
+    "direction_magnetic",
+    "direction_magnetic_unit",
+    "speed",
+    "speed_unit",
+    "speed_ms",
+    "speed_ms_unit",
+];
+
+pub fn parse_mwd(input: &str) -> IResult<&str, MWDMessage> {
+    let (remaining, (direction_true, _, direction_magnetic, _, speed, _, speed_ms, _)) =
+        tuple((
+            parse_raw_degree,
+            ensure_unit("T"),
+            parse_raw_degree,
+            ensure_unit("M"),
+            parse_knot,
+            ensure_unit("N"),
+            parse_meter_per_second,
+            ensure_unit("M"),
+        ))(input)?;
+    Ok((
+        remaining,
+        MWDMessage {
+            direction_true,
+            direction_magnetic,
+            speed,
+            speed_ms,
+        },
+    ))
+}
+
+impl fmt::Display for MWDMessage {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{},T,{},M,{},N,{},M",
+            Optional(&self.direction_true),
+            Optional(&self.direction_magnetic),
+            Optional(&self.speed),
+            Optional(&self.speed_ms)
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_mwd() {
+        let input = "46.4,T,47.5,M,12.3,N,6.3,M";
+        let expected = Ok((
+            "",
+            MWDMessage {
+                direction_true: Some(Degree(46.4)),
+                direction_magnetic: Some(Degree(47.5)),
+                speed: Some(Knot(12.3)),
+                speed_ms: Some(MeterPerSecond(6.3)),
+            },
+        ));
+
+        assert_eq!(expected, parse_mwd(input));
+    }
+
+    #[test]
+    fn test_parse_mwd_true_direction_only() {
+        let input = "46.4,T,,M,12.3,N,,M";
+        let expected = Ok((
+            "",
+            MWDMessage {
+                direction_true: Some(Degree(46.4)),
+                direction_magnetic: None,
+                speed: Some(Knot(12.3)),
+                speed_ms: None,
+            },
+        ));
+
+        assert_eq!(expected, parse_mwd(input));
+    }
+
+    #[test]
+    fn test_write_mwd() {
+        let input = "46.4,T,47.5,M,12.3,N,6.3,M";
+        let (_, message) = parse_mwd(input).unwrap();
+
+        assert_eq!(input, message.to_string());
+    }
+}
@@ -0,0 +1,110 @@
+use crate::fields::distance::*;
+use crate::fields::parameter::*;
+use crate::fields::speed::*;
+use crate::parser_utils::*;
+use crate::writer_utils::*;
+use core::fmt;
+use nom::sequence::tuple;
+use nom::IResult;
+
+#[derive(Debug, PartialEq)]
+/// Wind Speed and Angle
+pub struct MWVMessage {
+    /// Wind angle, 0 to 359 degrees clockwise from the bow
+    pub angle: Option<Degree>,
+    /// Reference of the wind angle and speed
+    pub reference: WindReference,
+    /// Wind speed
+    pub speed: Option<f64>,
+    /// Wind speed units
+    pub speed_unit: Option<SpeedOverGroundUnit>,
+    /// Data validity
+    pub status: Status,
+}
+
+impl MWVMessage {
+    /// Wind speed, converted to knots
+    pub fn speed_knots(&self) -> Option<Knot> {
+        match (self.speed, &self.speed_unit) {
+            (Some(speed), Some(unit)) => Some(unit.to_knots(speed)),
+            _ => None,
+        }
+    }
+}
+
+/// Names of the fields, in the order they appear in the sentence
+pub(crate) const FIELDS: &[&str] = &["angle", "reference", "speed", "speed_unit", "status"];
+
+pub fn parse_mwv(input: &str) -> IResult<&str, MWVMessage> {
+    let (remaining, (angle, reference, speed, speed_unit, status)) = tuple((
+        parse_raw_degree,
+        parse_wind_reference,
+        parse_float,
+        parse_speed_over_ground_unit,
+        parse_status,
+    ))(input)?;
+    Ok((
+        remaining,
+        MWVMessage {
+            angle,
+            reference,
+            speed,
+            speed_unit,
+            status,
+        },
+    ))
+}
+
+impl fmt::Display for MWVMessage {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{},{},{},{},{}",
+            Optional(&self.angle),
+            self.reference,
+            Optional(&self.speed),
+            Optional(&self.speed_unit),
+            self.status
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_mwv() {
+        let input = "214.8,R,0.1,K,A";
+        let expected = Ok((
+            "",
+            MWVMessage {
+                angle: Some(Degree(214.8)),
+                reference: WindReference::Relative,
+                speed: Some(0.1),
+                speed_unit: Some(SpeedOverGroundUnit::KilometersPerHour),
+                status: Status::DataValid,
+            },
+        ));
+
+        assert_eq!(expected, parse_mwv(input));
+    }
+
+    #[test]
+    fn test_speed_knots() {
+        let (_, message) = parse_mwv("45.5,T,10.4,M,A").unwrap();
+        let knots = message.speed_knots().unwrap();
+        assert!((knots.0 - 20.216).abs() < 1e-3);
+
+        let (_, message) = parse_mwv(",T,,,V").unwrap();
+        assert_eq!(None, message.speed_knots());
+    }
+
+    #[test]
+    fn test_write_mwv() {
+        let input = "214.8,R,0.1,K,A";
+        let (_, message) = parse_mwv(input).unwrap();
+
+        assert_eq!(input, message.to_string());
+    }
+}
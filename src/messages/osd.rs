@@ -0,0 +1,159 @@
+use crate::fields::distance::*;
+use crate::fields::parameter::*;
+use crate::fields::speed::*;
+use crate::parser_utils::*;
+use crate::writer_utils::*;
+use core::fmt;
+use nom::sequence::tuple;
+use nom::IResult;
+
+#[derive(Debug, PartialEq)]
+/// Own Ship Data
+///
+/// Heading, course and speed used by a radar to compute the target motion.
+pub struct OSDMessage {
+    /// Heading, in degrees true
+    pub heading: Option<Degree>,
+    pub heading_status: Status,
+    /// Course, in degrees true
+    pub course: Option<Degree>,
+    pub course_reference: Option<SpeedReference>,
+    /// Speed, in `unit`
+    pub speed: Option<f64>,
+    pub speed_reference: Option<SpeedReference>,
+    /// Set (direction of the current), in degrees true
+    pub set: Option<Degree>,
+    /// Drift (speed of the current), in `unit`
+    pub drift: Option<f64>,
+    /// Unit of the speed and drift
+    pub unit: Option<SpeedOverGroundUnit>,
+}
+
+impl OSDMessage {
+    /// Speed, converted to knots
+    pub fn speed_knots(&self) -> Option<Knot> {
+        match (self.speed, &self.unit) {
+            (Some(speed), Some(unit)) => Some(unit.to_knots(speed)),
+            _ => None,
+        }
+    }
+}
+
+/// Names of the fields, in the order they appear in the sentence
+pub(crate) const FIELDS: &[&str] = &[
+    "heading",
+    "heading_status",
+    "course",
+    "course_reference",
+    "speed",
+    "speed_reference",
+    "set",
+    "drift",
+    "unit",
+];
+
+pub fn parse_osd(input: &str) -> IResult<&str, OSDMessage> {
+    let (
+        remaining,
+        (
+            heading,
+            heading_status,
+            course,
+            course_reference,
+            speed,
+            speed_reference,
+            set,
+            drift,
+            unit,
+        ),
+    ) = tuple((
+        parse_raw_degree,
+        parse_status,
+        parse_raw_degree,
+        parse_maybe_speed_reference,
+        parse_float,
+        parse_maybe_speed_reference,
+        parse_raw_degree,
+        parse_float,
+        parse_speed_over_ground_unit,
+    ))(input)?;
+    Ok((
+        remaining,
+        OSDMessage {
+            heading,
+            heading_status,
+            course,
+            course_reference,
+            speed,
+            speed_reference,
+            set,
+            drift,
+            unit,
+        },
+    ))
+}
+
+impl fmt::Display for OSDMessage {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{},{},{},{},{},{},{},{},{}",
+            Optional(&self.heading),
+            self.heading_status,
+            Optional(&self.course),
+            Optional(&self.course_reference),
+            Optional(&self.speed),
+            Optional(&self.speed_reference),
+            Optional(&self.set),
+            Optional(&self.drift),
+            Optional(&self.unit)
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_osd() {
+        let input = "035.5,A,036.0,P,12.3,W,181.2,0.5,N";
+        let expected = Ok((
+            "",
+            OSDMessage {
+                heading: Some(Degree(35.5)),
+                heading_status: Status::DataValid,
+                course: Some(Degree(36.)),
+                course_reference: Some(SpeedReference::PositioningSystem),
+                speed: Some(12.3),
+                speed_reference: Some(SpeedReference::Water),
+                set: Some(Degree(181.2)),
+                drift: Some(0.5),
+                unit: Some(SpeedOverGroundUnit::Knots),
+            },
+        ));
+
+        assert_eq!(expected, parse_osd(input));
+
+        let (_, message) = parse_osd(input).unwrap();
+        assert_eq!(Some(Knot(12.3)), message.speed_knots());
+    }
+
+    #[test]
+    fn test_parse_osd_without_current() {
+        let input = "35.5,V,,B,12.3,B,,,N";
+        let (_, message) = parse_osd(input).unwrap();
+        assert_eq!(Status::DataInvalid, message.heading_status);
+        assert_eq!(None, message.course);
+        assert_eq!(Some(SpeedReference::BottomTrack), message.course_reference);
+        assert_eq!(None, message.set);
+    }
+
+    #[test]
+    fn test_write_osd() {
+        let input = "35.5,A,36,P,12.3,W,181.2,0.5,N";
+        let (_, message) = parse_osd(input).unwrap();
+
+        assert_eq!(input, message.to_string());
+    }
+}
@@ -0,0 +1,201 @@
+use crate::fields::cardinality::*;
+use crate::fields::distance::*;
+use crate::fields::parameter::*;
+use crate::fields::time::*;
+use crate::parser_utils::*;
+use crate::writer_utils::*;
+use chrono::naive::NaiveTime;
+use core::fmt;
+use nom::sequence::tuple;
+use nom::IResult;
+
+#[derive(Debug, PartialEq)]
+/// U-BLOX Lat/Long position data
+pub struct PUBX00Message {
+    /// UTC time
+    pub time: Option<NaiveTime>,
+    /// Latitude
+    pub lat: Option<Degree>,
+    /// North/South indicator
+    pub ns: Option<NorthSouth>,
+    /// Longitude
+    pub lon: Option<Degree>,
+    /// East/West indicator
+    pub ew: Option<EastWest>,
+    /// Altitude above user datum ellipsoid
+    pub alt_ref: Option<Meter>,
+    /// Navigation status
+    pub nav_stat: FixStatus,
+    /// Horizontal accuracy estimate
+    pub h_acc: Option<Meter>,
+    /// Vertical accuracy estimate
+    pub v_acc: Option<Meter>,
+    /// Speed over ground, in km/h
+    pub sog: Option<f64>,
+    /// Course over ground, in degrees
+    pub cog: Option<f64>,
+    /// Vertical velocity in m/s, positive downwards
+    pub v_vel: Option<f64>,
+    /// Age of differential corrections
+    pub diff_age: Option<Second>,
+    /// Horizontal Dilution of Precision
+    pub hdop: Option<f64>,
+    /// Vertical Dilution of Precision
+    pub vdop: Option<f64>,
+    /// Time Dilution of Precision
+    pub tdop: Option<f64>,
+    /// Number of satellites used in the navigation solution
+    pub num_svs: Option<u8>,
+    /// Dead reckoning used
+    pub dr: Option<u8>,
+}
+
+/// Names of the fields, in the order they appear in the sentence
+pub(crate) const FIELDS: &[&str] = &[
+    "time", "lat", "ns", "lon", "ew", "alt_ref", "nav_stat", "h_acc", "v_acc", "sog", "cog",
+    "v_vel", "diff_age", "hdop", "vdop", "tdop", "num_svs", "reserved", "dr",
+];
+
+pub fn parse_pubx00(input: &str) -> IResult<&str, PUBX00Message> {
+    let (
+        remaining,
+        (
+            time,
+            lat,
+            ns,
+            lon,
+            ew,
+            alt_ref,
+            nav_stat,
+            h_acc,
+            v_acc,
+            sog,
+            cog,
+            v_vel,
+            diff_age,
+            hdop,
+            vdop,
+            tdop,
+            num_svs,
+            _reserved,
+            dr,
+        ),
+    ) = tuple((
+        parse_time,
+        parse_degree,
+        parse_maybe_north_south_indicator,
+        parse_degree,
+        parse_maybe_east_west_indicator,
+        parse_meter,
+        parse_fix_status,
+        parse_meter,
+        parse_meter,
+        parse_float,
+        parse_float,
+        parse_float,
+        parse_second,
+        parse_dilution_of_precision,
+        parse_dilution_of_precision,
+        parse_dilution_of_precision,
+        parse_u8,
+        parse_u8,
+        parse_u8,
+    ))(input)?;
+    Ok((
+        remaining,
+        PUBX00Message {
+            time,
+            lat,
+            ns,
+            lon,
+            ew,
+            alt_ref,
+            nav_stat,
+            h_acc,
+            v_acc,
+            sog,
+            cog,
+            v_vel,
+            diff_age,
+            hdop,
+            vdop,
+            tdop,
+            num_svs,
+            dr,
+        },
+    ))
+}
+
+impl fmt::Display for PUBX00Message {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{},{},{},{},{},{},{},{},{},",
+            Time(&self.time),
+            DegreesMinutes(&self.lat, 11, 6),
+            Optional(&self.ns),
+            DegreesMinutes(&self.lon, 12, 6),
+            Optional(&self.ew),
+            Optional(&self.alt_ref),
+            self.nav_stat,
+            Optional(&self.h_acc),
+            Optional(&self.v_acc)
+        )?;
+        write!(
+            f,
+            "{},{},{},{},{},{},{},{},0,{}",
+            Optional(&self.sog),
+            Optional(&self.cog),
+            Optional(&self.v_vel),
+            Optional(&self.diff_age),
+            Optional(&self.hdop),
+            Optional(&self.vdop),
+            Optional(&self.tdop),
+            Optional(&self.num_svs),
+            Optional(&self.dr)
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_pubx00() {
+        let input = "081350.00,4717.113210,N,00833.915187,E,546.589,G3,2.1,2.0,0.007,77.52,0.007,,0.92,1.19,0.77,9,0,0";
+        let expected = Ok((
+            "",
+            PUBX00Message {
+                time: Some(NaiveTime::from_hms_opt(8, 13, 50).unwrap()),
+                lat: Some(Degree(47.1711321)),
+                ns: Some(NorthSouth::North),
+                lon: Some(Degree(8.33915187)),
+                ew: Some(EastWest::East),
+                alt_ref: Some(Meter(546.589)),
+                nav_stat: FixStatus::StandAlone3D,
+                h_acc: Some(Meter(2.1)),
+                v_acc: Some(Meter(2.0)),
+                sog: Some(0.007),
+                cog: Some(77.52),
+                v_vel: Some(0.007),
+                diff_age: None,
+                hdop: Some(0.92),
+                vdop: Some(1.19),
+                tdop: Some(0.77),
+                num_svs: Some(9),
+                dr: Some(0),
+            },
+        ));
+
+        assert_eq!(expected, parse_pubx00(input));
+    }
+
+    #[test]
+    fn test_write_pubx00() {
+        let input = "081350.00,4717.113210,N,00833.915187,E,546.589,G3,2.1,2.3,0.007,77.52,0.007,,0.92,1.19,0.77,9,0,0";
+        let (_, message) = parse_pubx00(input).unwrap();
+
+        assert_eq!(input, message.to_string());
+    }
+}
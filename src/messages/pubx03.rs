@@ -0,0 +1,100 @@
+use crate::fields::identity::*;
+use crate::parser_utils::*;
+use crate::writer_utils::*;
+use core::fmt;
+use heapless::Vec;
+use nom::sequence::tuple;
+use nom::IResult;
+
+#[derive(Debug, PartialEq)]
+/// U-BLOX Satellite status
+pub struct PUBX03Message {
+    /// Number of GNSS satellites tracked
+    pub num_sv: Option<u8>,
+    /// Status of each tracked satellite
+    pub satellites: Vec<SatelliteStatus, MAX_SATELLITES_STATUS>,
+}
+
+/// Names of the fields, in the order they appear in the sentence
+pub(crate) const FIELDS: &[&str] = &[
+    "num_sv",
+    "satellites.id",
+    "satellites.usage",
+    "satellites.az",
+    "satellites.elv",
+    "satellites.cno",
+    "satellites.lck",
+];
+
+pub fn parse_pubx03(input: &str) -> IResult<&str, PUBX03Message> {
+    let (remaining, (num_sv, satellites)) = tuple((parse_u8, parse_satellites_status))(input)?;
+    Ok((remaining, PUBX03Message { num_sv, satellites }))
+}
+
+impl fmt::Display for PUBX03Message {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{:02}", Optional(&self.num_sv))?;
+        for sv in &self.satellites {
+            write!(f, ",{}", sv)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_pubx03() {
+        let input = "2,23,-,,,45,010,08,U,067,31,42,025";
+        let expected = Ok((
+            "",
+            PUBX03Message {
+                num_sv: Some(2),
+                satellites: Vec::from_slice(&[
+                    SatelliteStatus {
+                        id: Some(23),
+                        usage: SatelliteUsage::NotUsed,
+                        az: None,
+                        elv: None,
+                        cno: Some(45),
+                        lck: Some(10),
+                    },
+                    SatelliteStatus {
+                        id: Some(8),
+                        usage: SatelliteUsage::Used,
+                        az: Some(67),
+                        elv: Some(31),
+                        cno: Some(42),
+                        lck: Some(25),
+                    },
+                ])
+                .unwrap(),
+            },
+        ));
+
+        assert_eq!(expected, parse_pubx03(input));
+    }
+
+    #[test]
+    fn test_parse_pubx03_more_than_24_satellites() {
+        let mut input = String::from("25");
+        for id in 1..=25 {
+            input.push_str(&format!(",{:02},U,100,45,40,020", id));
+        }
+        let (remaining, message) = parse_pubx03(&input).unwrap();
+        assert_eq!("", remaining);
+        assert_eq!(Some(25), message.num_sv);
+        assert_eq!(25, message.satellites.len());
+        assert_eq!(Some(25), message.satellites[24].id);
+    }
+
+    #[test]
+    fn test_write_pubx03() {
+        let input = "11,23,-,,,45,010,29,-,,,46,013,07,-,,,42,015,08,U,067,31,42,025,10,U,195,33,46,026,18,U,326,08,39,026,17,-,,,32,015,26,U,306,66,48,025,27,U,073,10,36,026,28,U,089,61,46,024,15,-,,,39,014";
+        let (_, message) = parse_pubx03(input).unwrap();
+
+        assert_eq!(input, message.to_string());
+    }
+}
@@ -0,0 +1,125 @@
+use crate::fields::distance::*;
+use crate::fields::time::*;
+use crate::parser_utils::*;
+use crate::writer_utils::*;
+use chrono::naive::{NaiveDate, NaiveTime};
+use core::fmt;
+use nom::sequence::tuple;
+use nom::IResult;
+
+#[derive(Debug, PartialEq)]
+/// U-BLOX Time of day and clock information
+pub struct PUBX04Message {
+    /// UTC time
+    pub time: Option<NaiveTime>,
+    /// UTC date
+    pub date: Option<NaiveDate>,
+    /// UTC time of week
+    pub utc_tow: Option<Second>,
+    /// UTC week number, continues beyond 1023
+    pub utc_wk: Option<u16>,
+    /// Leap seconds
+    pub leap_sec: Option<u8>,
+    /// The leap seconds are the firmware default, they haven't been received from the satellites yet
+    pub leap_sec_default: bool,
+    /// Receiver clock bias, in nanoseconds
+    pub clk_bias: Option<f64>,
+    /// Receiver clock drift, in nanoseconds per second
+    pub clk_drift: Option<f64>,
+    /// Time pulse granularity, in nanoseconds
+    pub tp_gran: Option<f64>,
+}
+
+/// Names of the fields, in the order they appear in the sentence
+pub(crate) const FIELDS: &[&str] = &[
+    "time",
+    "date",
+    "utc_tow",
+    "utc_wk",
+    "leap_sec",
+    "clk_bias",
+    "clk_drift",
+    "tp_gran",
+];
+
+pub fn parse_pubx04(input: &str) -> IResult<&str, PUBX04Message> {
+    let (
+        remaining,
+        (time, date, utc_tow, utc_wk, (leap_sec, leap_sec_default), clk_bias, clk_drift, tp_gran),
+    ) = tuple((
+        parse_time,
+        parse_date,
+        parse_second,
+        parse_u16,
+        parse_leap_seconds,
+        parse_float,
+        parse_float,
+        parse_float,
+    ))(input)?;
+    Ok((
+        remaining,
+        PUBX04Message {
+            time,
+            date,
+            utc_tow,
+            utc_wk,
+            leap_sec,
+            leap_sec_default,
+            clk_bias,
+            clk_drift,
+            tp_gran,
+        },
+    ))
+}
+
+impl fmt::Display for PUBX04Message {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{},{},{:.2},{},{}{},{},{},{},",
+            Time(&self.time),
+            Date(&self.date),
+            Optional(&self.utc_tow),
+            Optional(&self.utc_wk),
+            Optional(&self.leap_sec),
+            if self.leap_sec_default { "D" } else { "" },
+            Optional(&self.clk_bias),
+            Optional(&self.clk_drift),
+            Optional(&self.tp_gran)
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_pubx04() {
+        let input = "073731.00,091202,113851.00,1196,15D,1930035,-2660.664,43,";
+        let expected = Ok((
+            "",
+            PUBX04Message {
+                time: Some(NaiveTime::from_hms_opt(7, 37, 31).unwrap()),
+                date: Some(NaiveDate::from_ymd_opt(2002, 12, 9).unwrap()),
+                utc_tow: Some(Second(113851.)),
+                utc_wk: Some(1196),
+                leap_sec: Some(15),
+                leap_sec_default: true,
+                clk_bias: Some(1930035.),
+                clk_drift: Some(-2660.664),
+                tp_gran: Some(43.),
+            },
+        ));
+
+        assert_eq!(expected, parse_pubx04(input));
+    }
+
+    #[test]
+    fn test_write_pubx04() {
+        let input = "073731.00,091202,113851.00,1196,15D,1930035,-2660.664,43,";
+        let (_, message) = parse_pubx04(input).unwrap();
+
+        assert_eq!(input, message.to_string());
+    }
+}
@@ -0,0 +1,177 @@
+use crate::fields::cardinality::*;
+use crate::fields::distance::*;
+use crate::fields::parameter::*;
+use crate::fields::speed::*;
+use crate::parser_utils::*;
+use crate::writer_utils::*;
+use core::fmt;
+use nom::sequence::tuple;
+use nom::IResult;
+
+#[derive(Debug, PartialEq)]
+/// Recommended Minimum Navigation Information
+///
+/// Sent by a navigation receiver when a destination waypoint is active.
+pub struct RMBMessage<'a> {
+    /// Data validity status
+    pub status: Status,
+    /// Cross-track error magnitude
+    pub xte: Option<NauticalMile>,
+    /// Direction to steer to get back on track
+    pub steer: Option<LeftRight>,
+    /// Origin waypoint identifier
+    pub origin: &'a str,
+    /// Destination waypoint identifier
+    pub destination: &'a str,
+    /// Destination waypoint latitude
+    pub lat: Option<Degree>,
+    /// North/South indicator
+    pub ns: Option<NorthSouth>,
+    /// Destination waypoint longitude
+    pub lon: Option<Degree>,
+    /// East/West indicator
+    pub ew: Option<EastWest>,
+    /// Range to the destination
+    pub range: Option<NauticalMile>,
+    /// Bearing to the destination, in degrees true
+    pub bearing: Option<Degree>,
+    /// Velocity towards the destination
+    pub closing_velocity: Option<Knot>,
+    /// Whether the arrival circle has been entered,
+    /// or the perpendicular at the destination passed
+    pub arrived: bool,
+    /// Mode Indicator, since NMEA 2.3
+    pub pos_mode: Option<Fix>,
+}
+
+/// Names of the fields, in the order they appear in the sentence
+pub(crate) const FIELDS: &[&str] = &[
+    "status",
+    "xte",
+    "steer",
+    "origin",
+    "destination",
+    "lat",
+    "ns",
+    "lon",
+    "ew",
+    "range",
+    "bearing",
+    "closing_velocity",
+    "arrived",
+    "pos_mode",
+];
+
+pub fn parse_rmb(input: &str) -> IResult<&str, RMBMessage<'_>> {
+    let (remaining, (status, xte, steer, origin, destination, lat, ns, lon, ew)) = tuple((
+        parse_status,
+        parse_nautical_mile,
+        parse_maybe_left_right_indicator,
+        parse_string,
+        parse_string,
+        parse_degree,
+        parse_maybe_north_south_indicator,
+        parse_degree,
+        parse_maybe_east_west_indicator,
+    ))(input)?;
+    let (remaining, (range, bearing, closing_velocity, arrived, pos_mode)) = tuple((
+        parse_nautical_mile,
+        parse_raw_degree,
+        parse_knot,
+        parse_flag,
+        parse_maybe_pos_mode,
+    ))(remaining)?;
+    Ok((
+        remaining,
+        RMBMessage {
+            status,
+            xte,
+            steer,
+            origin,
+            destination,
+            lat,
+            ns,
+            lon,
+            ew,
+            range,
+            bearing,
+            closing_velocity,
+            arrived,
+            pos_mode,
+        },
+    ))
+}
+
+impl<'a> fmt::Display for RMBMessage<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{},{},{},{},{},{},{},{},{},{},{},{},{}",
+            self.status,
+            Optional(&self.xte),
+            Optional(&self.steer),
+            self.origin,
+            self.destination,
+            DegreesMinutes(&self.lat, 10, 5),
+            Optional(&self.ns),
+            DegreesMinutes(&self.lon, 11, 5),
+            Optional(&self.ew),
+            Optional(&self.range),
+            Optional(&self.bearing),
+            Optional(&self.closing_velocity),
+            Flag(self.arrived)
+        )?;
+        if let Some(pos_mode) = &self.pos_mode {
+            write!(f, ",{}", PosMode(pos_mode))?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_rmb() {
+        let input = "A,0.66,L,003,004,4917.24,N,12309.57,W,001.3,052.5,000.5,V";
+        let expected = Ok((
+            "",
+            RMBMessage {
+                status: Status::DataValid,
+                xte: Some(NauticalMile(0.66)),
+                steer: Some(LeftRight::Left),
+                origin: "003",
+                destination: "004",
+                lat: Some(Degree(49.172399999999996)),
+                ns: Some(NorthSouth::North),
+                lon: Some(Degree(123.0957)),
+                ew: Some(EastWest::West),
+                range: Some(NauticalMile(1.3)),
+                bearing: Some(Degree(52.5)),
+                closing_velocity: Some(Knot(0.5)),
+                arrived: false,
+                pos_mode: None,
+            },
+        ));
+
+        assert_eq!(expected, parse_rmb(input));
+    }
+
+    #[test]
+    fn test_parse_rmb_arrived() {
+        let input = "A,,,003,004,4917.24,N,12309.57,W,0,052.5,,A,D";
+        let (_, message) = parse_rmb(input).unwrap();
+        assert!(message.arrived);
+        assert_eq!(None, message.steer);
+        assert_eq!(Some(Fix::DifferentialGNSSFix), message.pos_mode);
+    }
+
+    #[test]
+    fn test_write_rmb() {
+        let input = "A,0.66,L,003,004,4917.24000,N,12309.57000,W,1.3,52.5,0.5,V,A";
+        let (_, message) = parse_rmb(input).unwrap();
+
+        assert_eq!(input, message.to_string());
+    }
+}
@@ -3,7 +3,9 @@ use crate::fields::distance::*;
 use crate::fields::parameter::*;
 use crate::fields::speed::*;
 use crate::fields::time::*;
+use crate::writer_utils::*;
 use chrono::naive::{NaiveDate, NaiveTime};
+use core::fmt;
 use nom::sequence::tuple;
 use nom::IResult;
 
@@ -37,6 +39,23 @@ pub struct RMCMessage {
     pub nav_status: NavigationalStatus,
 }
 
+/// Names of the fields, in the order they appear in the sentence
+pub(crate) const FIELDS: &[&str] = &[
+    "time",
+    "status",
+    "lat",
+    "ns",
+    "lon",
+    "ew",
+    "spd",
+    "cog",
+    "date",
+    "mv",
+    "mv_ew",
+    "pos_mode",
+    "nav_status",
+];
+
 pub fn parse_rmc(input: &str) -> IResult<&str, RMCMessage> {
     let (
         remaining,
@@ -76,6 +95,28 @@ pub fn parse_rmc(input: &str) -> IResult<&str, RMCMessage> {
     ))
 }
 
+impl fmt::Display for RMCMessage {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{},{},{},{},{},{},{},{},{},{},{},{},{}",
+            Time(&self.time),
+            self.status,
+            DegreesMinutes(&self.lat, 10, 5),
+            self.ns,
+            DegreesMinutes(&self.lon, 11, 5),
+            self.ew,
+            Optional(&self.spd),
+            Optional(&self.cog),
+            Date(&self.date),
+            DegreesMinutes(&self.mv, 0, 5),
+            Optional(&self.mv_ew),
+            PosMode(&self.pos_mode),
+            self.nav_status
+        )
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -104,4 +145,13 @@ mod tests {
 
         assert_eq!(expected, parse_rmc(input));
     }
+
+    #[test]
+    fn test_write_rmc() {
+        let input = "083559.00,A,4717.11437,N,00833.91522,E,0.004,77.52,091202,,,A,V";
+        let expected = "083559.00,A,4717.11437,N,00833.91522,E,0.004,77.52,091202,,,A,V";
+        let (_, message) = parse_rmc(input).unwrap();
+
+        assert_eq!(expected, message.to_string());
+    }
 }
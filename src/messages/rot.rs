@@ -0,0 +1,69 @@
+use crate::fields::parameter::*;
+use crate::fields::speed::*;
+use crate::writer_utils::*;
+use core::fmt;
+use nom::sequence::tuple;
+use nom::IResult;
+
+#[derive(Debug, PartialEq)]
+/// Rate Of Turn
+pub struct ROTMessage {
+    /// Rate of turn, negative when the bow turns to port
+    pub rate: Option<DegreePerMinute>,
+    pub status: Status,
+}
+
+/// Names of the fields, in the order they appear in the sentence
+pub(crate) const FIELDS: &[&str] = &["rate", "status"];
+
+pub fn parse_rot(input: &str) -> IResult<&str, ROTMessage> {
+    let (remaining, (rate, status)) = tuple((parse_degree_per_minute, parse_status))(input)?;
+    Ok((remaining, ROTMessage { rate, status }))
+}
+
+impl fmt::Display for ROTMessage {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{},{}", Optional(&self.rate), self.status)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_rot() {
+        let input = "-12.5,A";
+        let expected = Ok((
+            "",
+            ROTMessage {
+                rate: Some(DegreePerMinute(-12.5)),
+                status: Status::DataValid,
+            },
+        ));
+
+        assert_eq!(expected, parse_rot(input));
+    }
+
+    #[test]
+    fn test_parse_rot_invalid() {
+        let input = ",V";
+        let expected = Ok((
+            "",
+            ROTMessage {
+                rate: None,
+                status: Status::DataInvalid,
+            },
+        ));
+
+        assert_eq!(expected, parse_rot(input));
+    }
+
+    #[test]
+    fn test_write_rot() {
+        let input = "-12.5,A";
+        let (_, message) = parse_rot(input).unwrap();
+
+        assert_eq!(input, message.to_string());
+    }
+}
@@ -0,0 +1,104 @@
+use crate::fields::distance::*;
+use crate::fields::parameter::*;
+use crate::writer_utils::*;
+use core::fmt;
+use nom::sequence::tuple;
+use nom::IResult;
+
+#[derive(Debug, PartialEq)]
+/// Rudder Sensor Angle
+///
+/// Angles are negative when the rudder turns the bow to port.
+/// Single rudder vessels only report the starboard sensor.
+pub struct RSAMessage {
+    /// Starboard, or single, rudder sensor angle
+    pub starboard: Option<Degree>,
+    pub starboard_status: Status,
+    /// Port rudder sensor angle
+    pub port: Option<Degree>,
+    pub port_status: Option<Status>,
+}
+
+/// Names of the fields, in the order they appear in the sentence
+pub(crate) const FIELDS: &[&str] = &["starboard", "starboard_status", "port", "port_status"];
+
+pub fn parse_rsa(input: &str) -> IResult<&str, RSAMessage> {
+    let (remaining, (starboard, starboard_status, port, port_status)) = tuple((
+        parse_raw_degree,
+        parse_status,
+        parse_raw_degree,
+        parse_maybe_status,
+    ))(input)?;
+    Ok((
+        remaining,
+        RSAMessage {
+            starboard,
+            starboard_status,
+            port,
+            port_status,
+        },
+    ))
+}
+
+impl fmt::Display for RSAMessage {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{},{},{},{}",
+            Optional(&self.starboard),
+            self.starboard_status,
+            Optional(&self.port),
+            Optional(&self.port_status)
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_rsa_single_rudder() {
+        let input = "-4.5,A,,V";
+        let expected = Ok((
+            "",
+            RSAMessage {
+                starboard: Some(Degree(-4.5)),
+                starboard_status: Status::DataValid,
+                port: None,
+                port_status: Some(Status::DataInvalid),
+            },
+        ));
+
+        assert_eq!(expected, parse_rsa(input));
+    }
+
+    #[test]
+    fn test_parse_rsa_twin_rudders() {
+        let input = "3.2,A,3.5,A";
+        let expected = Ok((
+            "",
+            RSAMessage {
+                starboard: Some(Degree(3.2)),
+                starboard_status: Status::DataValid,
+                port: Some(Degree(3.5)),
+                port_status: Some(Status::DataValid),
+            },
+        ));
+
+        assert_eq!(expected, parse_rsa(input));
+    }
+
+    #[test]
+    fn test_write_rsa() {
+        let input = "-4.5,A,,V";
+        let (_, message) = parse_rsa(input).unwrap();
+
+        assert_eq!(input, message.to_string());
+
+        let input = "-4.5,A,,";
+        let (_, message) = parse_rsa(input).unwrap();
+
+        assert_eq!(input, message.to_string());
+    }
+}
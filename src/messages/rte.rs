@@ -0,0 +1,114 @@
+use crate::fields::parameter::*;
+use crate::parser_utils::*;
+use core::fmt;
+use nom::sequence::tuple;
+use nom::IResult;
+
+#[derive(Debug, PartialEq)]
+/// Routes
+///
+/// Long routes are split into several sentences, listing the waypoints in order.
+pub struct RTEMessage<'a> {
+    /// Number of messages, total number of RTE messages being output
+    pub total_msgs: u8,
+    /// Number of this message
+    pub msg_num: u8,
+    pub mode: RouteMode,
+    /// Route identifier
+    pub route: &'a str,
+    /// Raw comma separated waypoint identifiers, see `waypoints`
+    pub waypoint_ids: &'a str,
+}
+
+/// Names of the fields, in the order they appear in the sentence
+pub(crate) const FIELDS: &[&str] = &["total_msgs", "msg_num", "mode", "route", "waypoint_ids"];
+
+pub fn parse_rte(input: &str) -> IResult<&str, RTEMessage<'_>> {
+    let (waypoint_ids, (maybe_total_msgs, maybe_msg_num, mode, route)) =
+        tuple((parse_u8, parse_u8, parse_route_mode, parse_last_string))(input)?;
+    match (maybe_total_msgs, maybe_msg_num) {
+        (Some(total_msgs), Some(msg_num)) => Ok((
+            "",
+            RTEMessage {
+                total_msgs,
+                msg_num,
+                mode,
+                route,
+                waypoint_ids,
+            },
+        )),
+        _ => Err(nom::Err::Failure((input, nom::error::ErrorKind::Not))),
+    }
+}
+
+impl<'a> RTEMessage<'a> {
+    /// Identifiers of the waypoints listed in this message, in route order
+    pub fn waypoints(&self) -> impl Iterator<Item = &'a str> {
+        self.waypoint_ids.split(',').filter(|id| !id.is_empty())
+    }
+}
+
+impl<'a> fmt::Display for RTEMessage<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{},{},{},{}",
+            self.total_msgs, self.msg_num, self.mode, self.route
+        )?;
+        if !self.waypoint_ids.is_empty() {
+            write!(f, ",{}", self.waypoint_ids)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_rte() {
+        let input = "2,1,c,0,PBRCPK,PBRTO,PTELGR,PPLAND";
+        let expected = Ok((
+            "",
+            RTEMessage {
+                total_msgs: 2,
+                msg_num: 1,
+                mode: RouteMode::Complete,
+                route: "0",
+                waypoint_ids: "PBRCPK,PBRTO,PTELGR,PPLAND",
+            },
+        ));
+
+        assert_eq!(expected, parse_rte(input));
+    }
+
+    #[test]
+    fn test_rte_waypoints() {
+        let (_, message) = parse_rte("1,1,w,HOME,WP1,,WP2").unwrap();
+        assert_eq!(RouteMode::Working, message.mode);
+        assert_eq!(vec!["WP1", "WP2"], message.waypoints().collect::<Vec<_>>());
+
+        let (_, message) = parse_rte("1,1,c,HOME").unwrap();
+        assert_eq!(0, message.waypoints().count());
+    }
+
+    #[test]
+    fn test_parse_rte_invalid() {
+        assert!(parse_rte(",1,c,0,WP1").is_err());
+        assert!(parse_rte("1,1,x,0,WP1").is_err());
+    }
+
+    #[test]
+    fn test_write_rte() {
+        let input = "2,1,c,0,PBRCPK,PBRTO,PTELGR,PPLAND";
+        let (_, message) = parse_rte(input).unwrap();
+
+        assert_eq!(input, message.to_string());
+
+        let input = "1,1,c,HOME";
+        let (_, message) = parse_rte(input).unwrap();
+
+        assert_eq!(input, message.to_string());
+    }
+}
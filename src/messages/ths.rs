@@ -0,0 +1,70 @@
+use crate::fields::distance::*;
+use crate::fields::parameter::*;
+use crate::writer_utils::*;
+use core::fmt;
+use nom::sequence::tuple;
+use nom::IResult;
+
+#[derive(Debug, PartialEq)]
+/// True Heading and Status
+pub struct THSMessage {
+    /// Heading, in degrees true
+    pub heading: Option<Degree>,
+    /// Mode indicator
+    pub mode: HeadingMode,
+}
+
+/// Names of the fields, in the order they appear in the sentence
+pub(crate) const FIELDS: &[&str] = &["heading", "mode"];
+
+pub fn parse_ths(input: &str) -> IResult<&str, THSMessage> {
+    let (remaining, (heading, mode)) = tuple((parse_raw_degree, parse_heading_mode))(input)?;
+    Ok((remaining, THSMessage { heading, mode }))
+}
+
+impl fmt::Display for THSMessage {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{},{}", Optional(&self.heading), self.mode)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_ths() {
+        let input = "77.52,E";
+        let expected = Ok((
+            "",
+            THSMessage {
+                heading: Some(Degree(77.52)),
+                mode: HeadingMode::Estimated,
+            },
+        ));
+
+        assert_eq!(expected, parse_ths(input));
+    }
+
+    #[test]
+    fn test_parse_ths_invalid() {
+        let input = ",V";
+        let expected = Ok((
+            "",
+            THSMessage {
+                heading: None,
+                mode: HeadingMode::DataNotValid,
+            },
+        ));
+
+        assert_eq!(expected, parse_ths(input));
+    }
+
+    #[test]
+    fn test_write_ths() {
+        let input = "77.52,E";
+        let (_, message) = parse_ths(input).unwrap();
+
+        assert_eq!(input, message.to_string());
+    }
+}
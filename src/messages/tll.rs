@@ -0,0 +1,136 @@
+use crate::fields::cardinality::*;
+use crate::fields::distance::*;
+use crate::fields::parameter::*;
+use crate::fields::time::*;
+use crate::parser_utils::*;
+use crate::writer_utils::*;
+use chrono::naive::NaiveTime;
+use core::fmt;
+use nom::sequence::tuple;
+use nom::IResult;
+
+#[derive(Debug, PartialEq)]
+/// Target Latitude and Longitude
+pub struct TLLMessage<'a> {
+    /// Target number, from 00 to 99
+    pub target: Option<u8>,
+    /// Latitude
+    pub lat: Option<Degree>,
+    /// North/South indicator
+    pub ns: Option<NorthSouth>,
+    /// Longitude
+    pub lon: Option<Degree>,
+    /// East/West indicator
+    pub ew: Option<EastWest>,
+    /// Target name
+    pub name: &'a str,
+    /// UTC time of the data
+    pub time: Option<NaiveTime>,
+    pub status: Option<TargetStatus>,
+    /// Whether the target is used as a reference to compute the own ship speed over ground
+    pub reference_target: bool,
+}
+
+/// Names of the fields, in the order they appear in the sentence
+pub(crate) const FIELDS: &[&str] = &[
+    "target",
+    "lat",
+    "ns",
+    "lon",
+    "ew",
+    "name",
+    "time",
+    "status",
+    "reference_target",
+];
+
+pub fn parse_tll(input: &str) -> IResult<&str, TLLMessage<'_>> {
+    let (remaining, (target, lat, ns, lon, ew, name, time, status, reference_target)) =
+        tuple((
+            parse_u8,
+            parse_degree,
+            parse_maybe_north_south_indicator,
+            parse_degree,
+            parse_maybe_east_west_indicator,
+            parse_string,
+            parse_time,
+            parse_maybe_target_status,
+            parse_reference_target,
+        ))(input)?;
+    Ok((
+        remaining,
+        TLLMessage {
+            target,
+            lat,
+            ns,
+            lon,
+            ew,
+            name,
+            time,
+            status,
+            reference_target,
+        },
+    ))
+}
+
+impl<'a> fmt::Display for TLLMessage<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{},{},{},{},{},{},{},{},{}",
+            TargetNumber(&self.target),
+            DegreesMinutes(&self.lat, 10, 5),
+            Optional(&self.ns),
+            DegreesMinutes(&self.lon, 11, 5),
+            Optional(&self.ew),
+            self.name,
+            Time(&self.time),
+            Optional(&self.status),
+            if self.reference_target { "R" } else { "" }
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_tll() {
+        let input = "01,4917.16,N,12310.64,W,TGT01,100032.00,T,";
+        let expected = Ok((
+            "",
+            TLLMessage {
+                target: Some(1),
+                lat: Some(Degree(49.1716)),
+                ns: Some(NorthSouth::North),
+                lon: Some(Degree(123.1064)),
+                ew: Some(EastWest::West),
+                name: "TGT01",
+                time: Some(NaiveTime::from_hms_opt(10, 0, 32).unwrap()),
+                status: Some(TargetStatus::Tracking),
+                reference_target: false,
+            },
+        ));
+
+        assert_eq!(expected, parse_tll(input));
+    }
+
+    #[test]
+    fn test_parse_tll_lost_target() {
+        let input = "07,,,,,,,L,";
+        let (_, message) = parse_tll(input).unwrap();
+        assert_eq!(Some(7), message.target);
+        assert_eq!(None, message.lat);
+        assert_eq!(None, message.time);
+        assert_eq!(Some(TargetStatus::Lost), message.status);
+    }
+
+    #[test]
+    fn test_write_tll() {
+        let input = "01,4917.16000,N,12310.64000,W,TGT01,100032.00,T,";
+        let (_, message) = parse_tll(input).unwrap();
+
+        assert_eq!(input, message.to_string());
+    }
+}
@@ -0,0 +1,251 @@
+use crate::fields::distance::*;
+use crate::fields::parameter::*;
+use crate::fields::speed::*;
+use crate::fields::time::*;
+use crate::parser_utils::*;
+use crate::writer_utils::*;
+use chrono::naive::NaiveTime;
+use core::fmt;
+use nom::sequence::tuple;
+use nom::IResult;
+
+#[derive(Debug, PartialEq)]
+/// Tracked Target Message
+///
+/// Target data from a radar or an ARPA, relative to the own ship.
+pub struct TTMMessage<'a> {
+    /// Target number, from 00 to 99
+    pub target: Option<u8>,
+    /// Distance from the own ship, in `unit`
+    pub distance: Option<f64>,
+    /// Bearing from the own ship
+    pub bearing: Option<Degree>,
+    pub bearing_reference: Option<BearingReference>,
+    /// Target speed, in `unit`
+    pub speed: Option<f64>,
+    /// Target course
+    pub course: Option<Degree>,
+    pub course_reference: Option<BearingReference>,
+    /// Distance of the closest point of approach, in `unit`
+    pub cpa: Option<f64>,
+    /// Time to the closest point of approach, in minutes, negative once passed
+    pub tcpa: Option<f64>,
+    /// Unit of the speed, and matching unit of the distances
+    pub unit: Option<SpeedOverGroundUnit>,
+    /// Target name
+    pub name: &'a str,
+    pub status: Option<TargetStatus>,
+    /// Whether the target is used as a reference to compute the own ship speed over ground
+    pub reference_target: bool,
+    /// UTC time of the data, since NMEA 3.0
+    pub time: Option<NaiveTime>,
+    /// Type of acquisition, since NMEA 3.0
+    pub acquisition: Option<TargetAcquisition>,
+}
+
+impl<'a> TTMMessage<'a> {
+    /// Distance from the own ship, converted to nautical miles
+    pub fn distance_nautical_miles(&self) -> Option<NauticalMile> {
+        match (self.distance, &self.unit) {
+            (Some(distance), Some(unit)) => Some(unit.to_nautical_miles(distance)),
+            _ => None,
+        }
+    }
+
+    /// Target speed, converted to knots
+    pub fn speed_knots(&self) -> Option<Knot> {
+        match (self.speed, &self.unit) {
+            (Some(speed), Some(unit)) => Some(unit.to_knots(speed)),
+            _ => None,
+        }
+    }
+
+    /// Distance of the closest point of approach, converted to nautical miles
+    pub fn cpa_nautical_miles(&self) -> Option<NauticalMile> {
+        match (self.cpa, &self.unit) {
+            (Some(cpa), Some(unit)) => Some(unit.to_nautical_miles(cpa)),
+            _ => None,
+        }
+    }
+}
+
+/// Names of the fields, in the order they appear in the sentence
+pub(crate) const FIELDS: &[&str] = &[
+    "target",
+    "distance",
+    "bearing",
+    "bearing_reference",
+    "speed",
+    "course",
+    "course_reference",
+    "cpa",
+    "tcpa",
+    "unit",
+    "name",
+    "status",
+    "reference_target",
+    "time",
+    "acquisition",
+];
+
+pub fn parse_ttm(input: &str) -> IResult<&str, TTMMessage<'_>> {
+    let (
+        remaining,
+        (target, distance, bearing, bearing_reference, speed, course, course_reference, cpa, tcpa),
+    ) = tuple((
+        parse_u8,
+        parse_float,
+        parse_raw_degree,
+        parse_maybe_bearing_reference,
+        parse_float,
+        parse_raw_degree,
+        parse_maybe_bearing_reference,
+        parse_float,
+        parse_float,
+    ))(input)?;
+    let (remaining, (unit, name, status, reference_target)) = tuple((
+        parse_speed_over_ground_unit,
+        parse_string,
+        parse_maybe_target_status,
+        parse_reference_target,
+    ))(remaining)?;
+    // Missing before NMEA 3.0
+    let (remaining, (time, acquisition)) = if remaining.is_empty() {
+        (remaining, (None, None))
+    } else {
+        tuple((parse_time, parse_maybe_target_acquisition))(remaining)?
+    };
+    Ok((
+        remaining,
+        TTMMessage {
+            target,
+            distance,
+            bearing,
+            bearing_reference,
+            speed,
+            course,
+            course_reference,
+            cpa,
+            tcpa,
+            unit,
+            name,
+            status,
+            reference_target,
+            time,
+            acquisition,
+        },
+    ))
+}
+
+impl<'a> fmt::Display for TTMMessage<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{},{},{},{},{},{},{},{},{},{},{},{},{}",
+            TargetNumber(&self.target),
+            Optional(&self.distance),
+            Optional(&self.bearing),
+            Optional(&self.bearing_reference),
+            Optional(&self.speed),
+            Optional(&self.course),
+            Optional(&self.course_reference),
+            Optional(&self.cpa),
+            Optional(&self.tcpa),
+            Optional(&self.unit),
+            self.name,
+            Optional(&self.status),
+            if self.reference_target { "R" } else { "" }
+        )?;
+        if self.time.is_some() || self.acquisition.is_some() {
+            write!(f, ",{},{}", Time(&self.time), Optional(&self.acquisition))?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_ttm() {
+        let input = "01,2.6,215.3,T,11.2,45.8,T,0.9,12.5,N,TGT01,T,,100032.00,A";
+        let expected = Ok((
+            "",
+            TTMMessage {
+                target: Some(1),
+                distance: Some(2.6),
+                bearing: Some(Degree(215.3)),
+                bearing_reference: Some(BearingReference::True),
+                speed: Some(11.2),
+                course: Some(Degree(45.8)),
+                course_reference: Some(BearingReference::True),
+                cpa: Some(0.9),
+                tcpa: Some(12.5),
+                unit: Some(SpeedOverGroundUnit::Knots),
+                name: "TGT01",
+                status: Some(TargetStatus::Tracking),
+                reference_target: false,
+                time: Some(NaiveTime::from_hms_opt(10, 0, 32).unwrap()),
+                acquisition: Some(TargetAcquisition::Automatic),
+            },
+        ));
+
+        assert_eq!(expected, parse_ttm(input));
+    }
+
+    #[test]
+    fn test_parse_ttm_before_nmea_3() {
+        let input = "12,5.0,30.0,R,0.0,,T,5.0,-3.5,K,,Q,R";
+        let (_, message) = parse_ttm(input).unwrap();
+        assert_eq!(Some(12), message.target);
+        assert_eq!(Some(BearingReference::Relative), message.bearing_reference);
+        assert_eq!(None, message.course);
+        assert_eq!(Some(-3.5), message.tcpa);
+        assert_eq!(Some(TargetStatus::Query), message.status);
+        assert!(message.reference_target);
+        assert_eq!(None, message.time);
+        assert_eq!(None, message.acquisition);
+    }
+
+    #[test]
+    fn test_parse_lost_ttm() {
+        let input = "02,,,,,,,,,,,L,,,M";
+        let (remaining, message) = parse_ttm(input).unwrap();
+        assert_eq!("", remaining);
+        assert_eq!(Some(2), message.target);
+        assert_eq!(None, message.distance);
+        assert_eq!(None, message.speed);
+        assert_eq!(None, message.unit);
+        assert_eq!(None, message.distance_nautical_miles());
+        assert_eq!("", message.name);
+        assert_eq!(Some(TargetStatus::Lost), message.status);
+        assert!(!message.reference_target);
+        assert_eq!(None, message.time);
+        assert_eq!(Some(TargetAcquisition::Manual), message.acquisition);
+
+        assert_eq!(input, message.to_string());
+    }
+
+    #[test]
+    fn test_ttm_conversions() {
+        let input = "12,3.704,30.0,R,18.52,,T,1.852,-3.5,K,,Q,R";
+        let (_, message) = parse_ttm(input).unwrap();
+        assert_eq!(Some(NauticalMile(2.)), message.distance_nautical_miles());
+        assert_eq!(Some(Knot(10.)), message.speed_knots());
+        assert_eq!(Some(NauticalMile(1.)), message.cpa_nautical_miles());
+    }
+
+    #[test]
+    fn test_write_ttm() {
+        let input = "01,2.6,215.3,T,11.2,45.8,T,0.9,12.5,N,TGT01,T,,100032.00,A";
+        let (_, message) = parse_ttm(input).unwrap();
+
+        assert_eq!(input, message.to_string());
+
+        let input = "12,5,30,R,0,,T,5,-3.5,K,,Q,R";
+        let (_, message) = parse_ttm(input).unwrap();
+
+        assert_eq!(input, message.to_string());
+    }
+}
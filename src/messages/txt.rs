@@ -1,5 +1,7 @@
 use crate::fields::parameter::*;
 use crate::parser_utils::*;
+use crate::writer_utils::*;
+use core::fmt;
 use nom::sequence::tuple;
 use nom::IResult;
 
@@ -16,6 +18,9 @@ pub struct TXTMessage<'a> {
     pub text: &'a str,
 }
 
+/// Names of the fields, in the order they appear in the sentence
+pub(crate) const FIELDS: &[&str] = &["num_msg", "msg_num", "msg_type", "text"];
+
 pub fn parse_txt(input: &str) -> IResult<&str, TXTMessage> {
     let (remaining, (num_msg, msg_num, msg_type, text)) =
         tuple((parse_u8, parse_u8, parse_message_type, parse_string))(input)?;
@@ -30,6 +35,19 @@ pub fn parse_txt(input: &str) -> IResult<&str, TXTMessage> {
     ))
 }
 
+impl<'a> fmt::Display for TXTMessage<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{:02},{:02},{},{}",
+            Optional(&self.num_msg),
+            Optional(&self.msg_num),
+            self.msg_type,
+            self.text
+        )
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -49,4 +67,13 @@ mod tests {
 
         assert_eq!(expected, parse_txt(input));
     }
+
+    #[test]
+    fn test_write_txt() {
+        let input = "01,01,02,ANTARIS ATR0620 HW 00000040";
+        let expected = "01,01,02,ANTARIS ATR0620 HW 00000040";
+        let (_, message) = parse_txt(input).unwrap();
+
+        assert_eq!(expected, message.to_string());
+    }
 }
@@ -0,0 +1,183 @@
+use crate::fields::parameter::*;
+use crate::fields::speed::*;
+use crate::writer_utils::*;
+use core::fmt;
+use nom::sequence::tuple;
+use nom::IResult;
+
+#[derive(Debug, PartialEq)]
+/// Dual Ground/Water Speed
+///
+/// Longitudinal speeds are negative astern, transverse speeds are negative to port.
+pub struct VBWMessage {
+    /// Longitudinal water speed
+    pub water_longitudinal: Option<Knot>,
+    /// Transverse water speed
+    pub water_transverse: Option<Knot>,
+    /// Water speed validity
+    pub water_status: Status,
+    /// Longitudinal ground speed
+    pub ground_longitudinal: Option<Knot>,
+    /// Transverse ground speed
+    pub ground_transverse: Option<Knot>,
+    /// Ground speed validity
+    pub ground_status: Status,
+    /// Stern transverse water speed, only sent since NMEA 3.0
+    pub stern_water_transverse: Option<Knot>,
+    /// Stern transverse water speed validity
+    pub stern_water_status: Option<Status>,
+    /// Stern transverse ground speed, only sent since NMEA 3.0
+    pub stern_ground_transverse: Option<Knot>,
+    /// Stern transverse ground speed validity
+    pub stern_ground_status: Option<Status>,
+}
+
+/// Names of the fields, in the order they appear in the sentence
+pub(crate) const FIELDS: &[&str] = &[
+    "water_longitudinal",
+    "water_transverse",
+    "water_status",
+    "ground_longitudinal",
+    "ground_transverse",
+    "ground_status",
+    "stern_water_transverse",
+    "stern_water_status",
+    "stern_ground_transverse",
+    "stern_ground_status",
+];
+
+pub fn parse_vbw(input: &str) -> IResult<&str, VBWMessage> {
+    let (
+        remaining,
+        (
+            water_longitudinal,
+            water_transverse,
+            water_status,
+            ground_longitudinal,
+            ground_transverse,
+            ground_status,
+        ),
+    ) = tuple((
+        parse_knot,
+        parse_knot,
+        parse_status,
+        parse_knot,
+        parse_knot,
+        parse_status,
+    ))(input)?;
+    let (
+        remaining,
+        (stern_water_transverse, stern_water_status, stern_ground_transverse, stern_ground_status),
+    ) = if remaining.is_empty() {
+        (remaining, (None, None, None, None))
+    } else {
+        tuple((
+            parse_knot,
+            parse_maybe_status,
+            parse_knot,
+            parse_maybe_status,
+        ))(remaining)?
+    };
+    Ok((
+        remaining,
+        VBWMessage {
+            water_longitudinal,
+            water_transverse,
+            water_status,
+            ground_longitudinal,
+            ground_transverse,
+            ground_status,
+            stern_water_transverse,
+            stern_water_status,
+            stern_ground_transverse,
+            stern_ground_status,
+        },
+    ))
+}
+
+impl fmt::Display for VBWMessage {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{},{},{},{},{},{}",
+            Optional(&self.water_longitudinal),
+            Optional(&self.water_transverse),
+            self.water_status,
+            Optional(&self.ground_longitudinal),
+            Optional(&self.ground_transverse),
+            self.ground_status
+        )?;
+        if self.stern_water_transverse.is_some()
+            || self.stern_water_status.is_some()
+            || self.stern_ground_transverse.is_some()
+            || self.stern_ground_status.is_some()
+        {
+            write!(
+                f,
+                ",{},{},{},{}",
+                Optional(&self.stern_water_transverse),
+                Optional(&self.stern_water_status),
+                Optional(&self.stern_ground_transverse),
+                Optional(&self.stern_ground_status)
+            )?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_vbw() {
+        let input = "12.3,-0.07,A,12.44,0.12,A";
+        let expected = Ok((
+            "",
+            VBWMessage {
+                water_longitudinal: Some(Knot(12.3)),
+                water_transverse: Some(Knot(-0.07)),
+                water_status: Status::DataValid,
+                ground_longitudinal: Some(Knot(12.44)),
+                ground_transverse: Some(Knot(0.12)),
+                ground_status: Status::DataValid,
+                stern_water_transverse: None,
+                stern_water_status: None,
+                stern_ground_transverse: None,
+                stern_ground_status: None,
+            },
+        ));
+
+        assert_eq!(expected, parse_vbw(input));
+    }
+
+    #[test]
+    fn test_parse_vbw_stern_speeds() {
+        let input = "12.3,-0.07,A,,,V,0.03,A,,V";
+        let expected = Ok((
+            "",
+            VBWMessage {
+                water_longitudinal: Some(Knot(12.3)),
+                water_transverse: Some(Knot(-0.07)),
+                water_status: Status::DataValid,
+                ground_longitudinal: None,
+                ground_transverse: None,
+                ground_status: Status::DataInvalid,
+                stern_water_transverse: Some(Knot(0.03)),
+                stern_water_status: Some(Status::DataValid),
+                stern_ground_transverse: None,
+                stern_ground_status: Some(Status::DataInvalid),
+            },
+        ));
+
+        assert_eq!(expected, parse_vbw(input));
+    }
+
+    #[test]
+    fn test_write_vbw() {
+        for input in ["12.3,-0.07,A,12.44,0.12,A", "12.3,-0.07,A,,,V,0.03,A,,V"].iter() {
+            let (_, message) = parse_vbw(input).unwrap();
+            assert_eq!(*input, message.to_string());
+        }
+    }
+}
@@ -0,0 +1,133 @@
+use crate::ais::sixbit;
+use crate::fields::identity::*;
+use crate::parser_utils::*;
+use crate::writer_utils::*;
+use core::fmt;
+use nom::sequence::tuple;
+use nom::IResult;
+
+#[derive(Debug, PartialEq)]
+/// AIS VHF data-link message, received from another station (VDM)
+/// or reported by the own station (VDO)
+pub struct VDMMessage<'a> {
+    /// Number of sentences the message is split into
+    pub fragment_count: u8,
+    /// Number of this sentence, starting at 1
+    pub fragment_number: u8,
+    /// Sequential message identifier, linking the fragments of a message
+    pub message_id: Option<u8>,
+    /// Radio channel
+    pub channel: Option<AISChannel>,
+    /// Six-bit armoured payload
+    pub payload: &'a str,
+    /// Number of bits padding the last character of the payload
+    pub fill_bits: u8,
+}
+
+/// Names of the fields, in the order they appear in the sentence
+pub(crate) const FIELDS: &[&str] = &[
+    "fragment_count",
+    "fragment_number",
+    "message_id",
+    "channel",
+    "payload",
+    "fill_bits",
+];
+
+pub fn parse_vdm(input: &str) -> IResult<&str, VDMMessage<'_>> {
+    let (remaining, (fragment_count, fragment_number, message_id, channel, payload, fill_bits)) =
+        tuple((
+            parse_fragment,
+            parse_fragment,
+            parse_u8,
+            parse_maybe_ais_channel,
+            parse_payload,
+            parse_fill_bits,
+        ))(input)?;
+    Ok((
+        remaining,
+        VDMMessage {
+            fragment_count,
+            fragment_number,
+            message_id,
+            channel,
+            payload,
+            fill_bits,
+        },
+    ))
+}
+
+fn parse_fragment(input: &str) -> IResult<&str, u8> {
+    match parse_u8(input)? {
+        (remaining, Some(fragment)) if fragment > 0 => Ok((remaining, fragment)),
+        _ => Err(nom::Err::Failure((input, nom::error::ErrorKind::Verify))),
+    }
+}
+
+fn parse_payload(input: &str) -> IResult<&str, &str> {
+    let (remaining, payload) = parse_string(input)?;
+    if payload.chars().all(|c| sixbit(c).is_some()) {
+        Ok((remaining, payload))
+    } else {
+        Err(nom::Err::Failure((input, nom::error::ErrorKind::Verify)))
+    }
+}
+
+fn parse_fill_bits(input: &str) -> IResult<&str, u8> {
+    match parse_u8(input)? {
+        (remaining, Some(fill_bits)) if fill_bits <= 5 => Ok((remaining, fill_bits)),
+        _ => Err(nom::Err::Failure((input, nom::error::ErrorKind::Verify))),
+    }
+}
+
+impl<'a> fmt::Display for VDMMessage<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{},{},{},{},{},{}",
+            self.fragment_count,
+            self.fragment_number,
+            Optional(&self.message_id),
+            Optional(&self.channel),
+            self.payload,
+            self.fill_bits
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_vdm() {
+        let input = "2,2,3,B,1@0000000000000,2";
+        let expected = Ok((
+            "",
+            VDMMessage {
+                fragment_count: 2,
+                fragment_number: 2,
+                message_id: Some(3),
+                channel: Some(AISChannel::B),
+                payload: "1@0000000000000",
+                fill_bits: 2,
+            },
+        ));
+
+        assert_eq!(expected, parse_vdm(input));
+    }
+
+    #[test]
+    fn test_parse_vdm_invalid_payload() {
+        let input = "1,1,,A,1@X,0";
+        assert!(parse_vdm(input).is_err());
+    }
+
+    #[test]
+    fn test_write_vdm() {
+        let input = "1,1,,B,177KQJ5000G?tO`K>RA1wUbN0TKH,0";
+        let (_, message) = parse_vdm(input).unwrap();
+
+        assert_eq!(input, message.to_string());
+    }
+}
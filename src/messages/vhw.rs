@@ -0,0 +1,112 @@
+use crate::fields::distance::*;
+use crate::fields::speed::*;
+use crate::parser_utils::*;
+use crate::writer_utils::*;
+use core::fmt;
+use nom::sequence::tuple;
+use nom::IResult;
+
+#[derive(Debug, PartialEq)]
+/// Water Speed and Heading
+pub struct VHWMessage {
+    /// Heading, in degrees true
+    pub heading_true: Option<Degree>,
+    /// Heading, in degrees magnetic
+    pub heading_magnetic: Option<Degree>,
+    /// Speed through water, in knots
+    pub speed: Option<Knot>,
+    /// Speed through water, in kilometers per hour
+    pub speed_kmh: Option<f64>,
+}
+
+/// Names of the fields, in the order they appear in the sentence
+pub(crate) const FIELDS: &[&str] = &[
+    "heading_true",
+    "heading_true_unit",
+    "heading_magnetic",
+    "heading_magnetic_unit",
+    "speed",
+    "speed_unit",
+    "speed_kmh",
+    "speed_kmh_unit",
+];
+
+pub fn parse_vhw(input: &str) -> IResult<&str, VHWMessage> {
+    let (remaining, (heading_true, _, heading_magnetic, _, speed, _, speed_kmh, _)) = tuple((
+        parse_raw_degree,
+        ensure_unit("T"),
+        parse_raw_degree,
+        ensure_unit("M"),
+        parse_knot,
+        ensure_unit("N"),
+        parse_float,
+        ensure_unit("K"),
+    ))(input)?;
+    Ok((
+        remaining,
+        VHWMessage {
+            heading_true,
+            heading_magnetic,
+            speed,
+            speed_kmh,
+        },
+    ))
+}
+
+impl fmt::Display for VHWMessage {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{},T,{},M,{},N,{},K",
+            Optional(&self.heading_true),
+            Optional(&self.heading_magnetic),
+            Optional(&self.speed),
+            Optional(&self.speed_kmh)
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_vhw() {
+        let input = "245.1,T,245.1,M,6.3,N,11.7,K";
+        let expected = Ok((
+            "",
+            VHWMessage {
+                heading_true: Some(Degree(245.1)),
+                heading_magnetic: Some(Degree(245.1)),
+                speed: Some(Knot(6.3)),
+                speed_kmh: Some(11.7),
+            },
+        ));
+
+        assert_eq!(expected, parse_vhw(input));
+    }
+
+    #[test]
+    fn test_parse_vhw_speed_only() {
+        let input = ",T,,M,6.3,N,11.7,K";
+        let expected = Ok((
+            "",
+            VHWMessage {
+                heading_true: None,
+                heading_magnetic: None,
+                speed: Some(Knot(6.3)),
+                speed_kmh: Some(11.7),
+            },
+        ));
+
+        assert_eq!(expected, parse_vhw(input));
+    }
+
+    #[test]
+    fn test_write_vhw() {
+        let input = "245.1,T,245.1,M,6.3,N,11.7,K";
+        let (_, message) = parse_vhw(input).unwrap();
+
+        assert_eq!(input, message.to_string());
+    }
+}
@@ -1,5 +1,7 @@
 use crate::fields::speed::*;
 use crate::parser_utils::*;
+use crate::writer_utils::*;
+use core::fmt;
 use nom::sequence::tuple;
 use nom::IResult;
 
@@ -24,6 +26,11 @@ pub struct VLWMessage {
     pub gd_unit: Option<WaterDistanceUnit>,
 }
 
+/// Names of the fields, in the order they appear in the sentence
+pub(crate) const FIELDS: &[&str] = &[
+    "twd", "twd_unit", "wd", "wd_unit", "tgd", "tgd_unit", "gd", "gd_unit",
+];
+
 pub fn parse_vlw(input: &str) -> IResult<&str, VLWMessage> {
     let (remaining, (twd, twd_unit, wd, wd_unit, tgd, tgd_unit, gd, gd_unit)) = tuple((
         parse_float,
@@ -50,6 +57,23 @@ pub fn parse_vlw(input: &str) -> IResult<&str, VLWMessage> {
     ))
 }
 
+impl fmt::Display for VLWMessage {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{},{},{},{},{},{},{},{}",
+            Optional(&self.twd),
+            Optional(&self.twd_unit),
+            Optional(&self.wd),
+            Optional(&self.wd_unit),
+            Optional(&self.tgd),
+            Optional(&self.tgd_unit),
+            Optional(&self.gd),
+            Optional(&self.gd_unit)
+        )
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -73,4 +97,13 @@ mod tests {
 
         assert_eq!(expected, parse_vlw(input));
     }
+
+    #[test]
+    fn test_write_vlw() {
+        let input = ",N,,N,15.8,N,1.2,N";
+        let expected = ",N,,N,15.8,N,1.2,N";
+        let (_, message) = parse_vlw(input).unwrap();
+
+        assert_eq!(expected, message.to_string());
+    }
 }
@@ -1,6 +1,8 @@
 use crate::fields::parameter::*;
 use crate::fields::speed::*;
 use crate::parser_utils::*;
+use crate::writer_utils::*;
+use core::fmt;
 use nom::sequence::tuple;
 use nom::IResult;
 
@@ -27,6 +29,19 @@ pub struct VTGMessage {
     pub pos_mode: Fix,
 }
 
+/// Names of the fields, in the order they appear in the sentence
+pub(crate) const FIELDS: &[&str] = &[
+    "cogt",
+    "cogt_unit",
+    "cogm",
+    "cogm_unit",
+    "sogn",
+    "sogn_unit",
+    "sogk",
+    "sogk_unit",
+    "pos_mode",
+];
+
 pub fn parse_vtg(input: &str) -> IResult<&str, VTGMessage> {
     let (remaining, (cogt, cogt_unit, cogm, cogm_unit, sogn, sogn_unit, sogk, sogk_unit, pos_mode)) =
         tuple((
@@ -56,6 +71,24 @@ pub fn parse_vtg(input: &str) -> IResult<&str, VTGMessage> {
     ))
 }
 
+impl fmt::Display for VTGMessage {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{},{},{},{},{},{},{},{},{}",
+            Optional(&self.cogt),
+            Optional(&self.cogt_unit),
+            Optional(&self.cogm),
+            Optional(&self.cogm_unit),
+            Optional(&self.sogn),
+            Optional(&self.sogn_unit),
+            Optional(&self.sogk),
+            Optional(&self.sogk_unit),
+            PosMode(&self.pos_mode)
+        )
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -80,4 +113,13 @@ mod tests {
 
         assert_eq!(expected, parse_vtg(input));
     }
+
+    #[test]
+    fn test_write_vtg() {
+        let input = "77.52,T,,M,0.004,N,0.008,K,A";
+        let expected = "77.52,T,,M,0.004,N,0.008,K,A";
+        let (_, message) = parse_vtg(input).unwrap();
+
+        assert_eq!(expected, message.to_string());
+    }
 }
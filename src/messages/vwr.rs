@@ -0,0 +1,106 @@
+use crate::fields::cardinality::*;
+use crate::fields::distance::*;
+use crate::fields::speed::*;
+use crate::parser_utils::*;
+use crate::writer_utils::*;
+use core::fmt;
+use nom::sequence::tuple;
+use nom::IResult;
+
+#[derive(Debug, PartialEq)]
+/// Relative Wind Speed and Angle
+///
+/// Also used by the legacy VWT (True Wind Speed and Angle) message,
+/// which gives the true wind relative to the bow instead.
+pub struct VWRMessage {
+    /// Wind angle, 0 to 180 degrees from the bow
+    pub angle: Option<Degree>,
+    /// Side of the bow the wind comes from
+    pub side: Option<LeftRight>,
+    /// Wind speed, in knots
+    pub speed: Option<Knot>,
+    /// Wind speed, in meters per second
+    pub speed_ms: Option<MeterPerSecond>,
+    /// Wind speed, in kilometers per hour
+    pub speed_kmh: Option<f64>,
+}
+
+/// Names of the fields, in the order they appear in the sentence
+pub(crate) const FIELDS: &[&str] = &[
+    "angle",
+    "side",
+    "speed",
+    "speed_unit",
+    "speed_ms",
+    "speed_ms_unit",
+    "speed_kmh",
+    "speed_kmh_unit",
+];
+
+pub fn parse_vwr(input: &str) -> IResult<&str, VWRMessage> {
+    let (remaining, (angle, side, speed, _, speed_ms, _, speed_kmh, _)) = tuple((
+        parse_raw_degree,
+        parse_maybe_left_right_indicator,
+        parse_knot,
+        ensure_unit("N"),
+        parse_meter_per_second,
+        ensure_unit("M"),
+        parse_float,
+        ensure_unit("K"),
+    ))(input)?;
+    Ok((
+        remaining,
+        VWRMessage {
+            angle,
+            side,
+            speed,
+            speed_ms,
+            speed_kmh,
+        },
+    ))
+}
+
+impl fmt::Display for VWRMessage {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{},{},{},N,{},M,{},K",
+            Optional(&self.angle),
+            Optional(&self.side),
+            Optional(&self.speed),
+            Optional(&self.speed_ms),
+            Optional(&self.speed_kmh)
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_vwr() {
+        let input = "148.,L,02.4,N,01.2,M,04.4,K";
+        let expected = Ok((
+            "",
+            VWRMessage {
+                angle: Some(Degree(148.)),
+                side: Some(LeftRight::Left),
+                speed: Some(Knot(2.4)),
+                speed_ms: Some(MeterPerSecond(1.2)),
+                speed_kmh: Some(4.4),
+            },
+        ));
+
+        assert_eq!(expected, parse_vwr(input));
+    }
+
+    #[test]
+    fn test_write_vwr() {
+        let input = "148.,L,02.4,N,01.2,M,04.4,K";
+        let expected = "148,L,2.4,N,1.2,M,4.4,K";
+        let (_, message) = parse_vwr(input).unwrap();
+
+        assert_eq!(expected, message.to_string());
+    }
+}
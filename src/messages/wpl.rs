@@ -0,0 +1,118 @@
+use crate::fields::cardinality::*;
+use crate::fields::distance::*;
+use crate::parser_utils::*;
+use crate::writer_utils::*;
+use core::fmt;
+use nom::sequence::tuple;
+use nom::IResult;
+
+#[derive(Debug, PartialEq)]
+/// Waypoint Location
+pub struct WPLMessage<'a> {
+    /// Latitude
+    pub lat: Option<Degree>,
+    /// North/South indicator
+    pub ns: Option<NorthSouth>,
+    /// Longitude
+    pub lon: Option<Degree>,
+    /// East/West indicator
+    pub ew: Option<EastWest>,
+    /// Waypoint identifier
+    pub waypoint: &'a str,
+}
+
+/// Names of the fields, in the order they appear in the sentence
+pub(crate) const FIELDS: &[&str] = &["lat", "ns", "lon", "ew", "waypoint"];
+
+pub fn parse_wpl(input: &str) -> IResult<&str, WPLMessage<'_>> {
+    let (remaining, (lat, ns, lon, ew, waypoint)) = tuple((
+        parse_degree,
+        parse_maybe_north_south_indicator,
+        parse_degree,
+        parse_maybe_east_west_indicator,
+        parse_last_string,
+    ))(input)?;
+    Ok((
+        remaining,
+        WPLMessage {
+            lat,
+            ns,
+            lon,
+            ew,
+            waypoint,
+        },
+    ))
+}
+
+impl<'a> WPLMessage<'a> {
+    /// Latitude and longitude of the waypoint, in decimal degrees,
+    /// negative in the southern and western hemispheres
+    pub fn position(&self) -> Option<(Degree, Degree)> {
+        let lat = match (&self.lat, &self.ns) {
+            (Some(lat), Some(NorthSouth::North)) => lat.to_decimal().0,
+            (Some(lat), Some(NorthSouth::South)) => -lat.to_decimal().0,
+            _ => return None,
+        };
+        let lon = match (&self.lon, &self.ew) {
+            (Some(lon), Some(EastWest::East)) => lon.to_decimal().0,
+            (Some(lon), Some(EastWest::West)) => -lon.to_decimal().0,
+            _ => return None,
+        };
+        Some((Degree(lat), Degree(lon)))
+    }
+}
+
+impl<'a> fmt::Display for WPLMessage<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{},{},{},{},{}",
+            DegreesMinutes(&self.lat, 10, 5),
+            Optional(&self.ns),
+            DegreesMinutes(&self.lon, 11, 5),
+            Optional(&self.ew),
+            self.waypoint
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_wpl() {
+        let input = "4917.16,N,12310.64,W,003";
+        let expected = Ok((
+            "",
+            WPLMessage {
+                lat: Some(Degree(49.1716)),
+                ns: Some(NorthSouth::North),
+                lon: Some(Degree(123.1064)),
+                ew: Some(EastWest::West),
+                waypoint: "003",
+            },
+        ));
+
+        assert_eq!(expected, parse_wpl(input));
+    }
+
+    #[test]
+    fn test_wpl_position() {
+        let (_, message) = parse_wpl("4930.00,S,12315.00,W,003").unwrap();
+        let (lat, lon) = message.position().unwrap();
+        assert!((lat.0 + 49.5).abs() < 1e-9);
+        assert!((lon.0 + 123.25).abs() < 1e-9);
+
+        let (_, message) = parse_wpl(",,,,003").unwrap();
+        assert_eq!(None, message.position());
+    }
+
+    #[test]
+    fn test_write_wpl() {
+        let input = "4917.16000,N,12310.64000,W,003";
+        let (_, message) = parse_wpl(input).unwrap();
+
+        assert_eq!(input, message.to_string());
+    }
+}
@@ -0,0 +1,112 @@
+use crate::fields::transducer::*;
+use core::fmt;
+use nom::IResult;
+
+#[derive(Debug, PartialEq)]
+/// Transducer Measurements
+pub struct XDRMessage<'a> {
+    /// Raw type, value, unit and name quadruplets, see `measurements`
+    pub raw_measurements: &'a str,
+}
+
+/// Names of the fields of a measurement, the quadruplets repeat in the sentence
+pub(crate) const FIELDS: &[&str] = &[
+    "measurements.transducer_type",
+    "measurements.value",
+    "measurements.unit",
+    "measurements.name",
+];
+
+pub fn parse_xdr(input: &str) -> IResult<&str, XDRMessage<'_>> {
+    let (remaining, raw_measurements) = parse_transducer_measurements(input)?;
+    Ok((remaining, XDRMessage { raw_measurements }))
+}
+
+impl<'a> XDRMessage<'a> {
+    /// Measurements, in the order they appear in the sentence
+    pub fn measurements(&self) -> impl Iterator<Item = TransducerMeasurement<'a>> {
+        let mut remaining = self.raw_measurements;
+        core::iter::from_fn(move || {
+            if remaining.is_empty() {
+                return None;
+            }
+            // The quadruplets have been checked by parse_xdr
+            let (next, measurement) = parse_transducer_measurement(remaining).ok()?;
+            remaining = next;
+            Some(measurement)
+        })
+    }
+}
+
+impl<'a> fmt::Display for XDRMessage<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let mut separator = "";
+        for measurement in self.measurements() {
+            write!(f, "{}{}", separator, measurement)?;
+            separator = ",";
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fields::temperature::Celsius;
+
+    #[test]
+    fn test_parse_xdr() {
+        let input = "C,19.52,C,TempAir,P,1.02481,B,Barometer";
+        let expected = Ok((
+            "",
+            XDRMessage {
+                raw_measurements: input,
+            },
+        ));
+        assert_eq!(expected, parse_xdr(input));
+
+        let (_, message) = parse_xdr(input).unwrap();
+        let measurements: Vec<_> = message.measurements().collect();
+        assert_eq!(
+            vec![
+                TransducerMeasurement {
+                    transducer_type: TransducerType::Temperature,
+                    value: Some(19.52),
+                    unit: Some('C'),
+                    name: "TempAir",
+                },
+                TransducerMeasurement {
+                    transducer_type: TransducerType::Pressure,
+                    value: Some(1.02481),
+                    unit: Some('B'),
+                    name: "Barometer",
+                },
+            ],
+            measurements
+        );
+    }
+
+    #[test]
+    fn test_xdr_quantities() {
+        let (_, message) = parse_xdr("C,19.52,C,TempAir,U,12.6,V,BATT").unwrap();
+        let quantities: Vec<_> = message
+            .measurements()
+            .filter_map(|measurement| measurement.quantity())
+            .collect();
+        assert_eq!(
+            vec![
+                TransducerQuantity::Temperature(Celsius(19.52)),
+                TransducerQuantity::Voltage(Volt(12.6)),
+            ],
+            quantities
+        );
+    }
+
+    #[test]
+    fn test_write_xdr() {
+        let input = "C,19.52,C,TempAir,P,1.02481,B,Barometer,H,,P,";
+        let (_, message) = parse_xdr(input).unwrap();
+
+        assert_eq!(input, message.to_string());
+    }
+}
@@ -0,0 +1,123 @@
+use crate::fields::cardinality::*;
+use crate::fields::distance::*;
+use crate::fields::parameter::*;
+use crate::parser_utils::*;
+use crate::writer_utils::*;
+use core::fmt;
+use nom::sequence::tuple;
+use nom::IResult;
+
+#[derive(Debug, PartialEq)]
+/// Cross-Track Error, measured
+pub struct XTEMessage {
+    /// General warning status, `DataInvalid` for a LORAN-C blink or SNR warning
+    pub status: Status,
+    /// Cycle lock status, `DataInvalid` for a LORAN-C cycle lock warning
+    pub cycle_lock: Status,
+    /// Cross-track error magnitude
+    pub xte: Option<NauticalMile>,
+    /// Direction to steer to get back on track
+    pub steer: Option<LeftRight>,
+    /// Mode Indicator, since NMEA 2.3
+    pub pos_mode: Option<Fix>,
+}
+
+/// Names of the fields, in the order they appear in the sentence
+pub(crate) const FIELDS: &[&str] = &[
+    "status",
+    "cycle_lock",
+    "xte",
+    "steer",
+    "xte_unit",
+    "pos_mode",
+];
+
+pub fn parse_xte(input: &str) -> IResult<&str, XTEMessage> {
+    let (remaining, (status, cycle_lock, xte, steer, _, pos_mode)) = tuple((
+        parse_status,
+        parse_status,
+        parse_nautical_mile,
+        parse_maybe_left_right_indicator,
+        ensure_unit("N"),
+        parse_maybe_pos_mode,
+    ))(input)?;
+    Ok((
+        remaining,
+        XTEMessage {
+            status,
+            cycle_lock,
+            xte,
+            steer,
+            pos_mode,
+        },
+    ))
+}
+
+impl fmt::Display for XTEMessage {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{},{},{},{},N",
+            self.status,
+            self.cycle_lock,
+            Optional(&self.xte),
+            Optional(&self.steer)
+        )?;
+        if let Some(pos_mode) = &self.pos_mode {
+            write!(f, ",{}", PosMode(pos_mode))?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_xte() {
+        let input = "A,A,0.67,L,N,A";
+        let expected = Ok((
+            "",
+            XTEMessage {
+                status: Status::DataValid,
+                cycle_lock: Status::DataValid,
+                xte: Some(NauticalMile(0.67)),
+                steer: Some(LeftRight::Left),
+                pos_mode: Some(Fix::AutonomousGNSSFix),
+            },
+        ));
+
+        assert_eq!(expected, parse_xte(input));
+    }
+
+    #[test]
+    fn test_parse_xte_without_mode() {
+        let input = "V,V,,,N";
+        let expected = Ok((
+            "",
+            XTEMessage {
+                status: Status::DataInvalid,
+                cycle_lock: Status::DataInvalid,
+                xte: None,
+                steer: None,
+                pos_mode: None,
+            },
+        ));
+
+        assert_eq!(expected, parse_xte(input));
+    }
+
+    #[test]
+    fn test_write_xte() {
+        let input = "A,A,0.67,L,N,A";
+        let (_, message) = parse_xte(input).unwrap();
+
+        assert_eq!(input, message.to_string());
+
+        let input = "V,V,,,N";
+        let (_, message) = parse_xte(input).unwrap();
+
+        assert_eq!(input, message.to_string());
+    }
+}
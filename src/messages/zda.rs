@@ -1,6 +1,8 @@
 use crate::fields::time::*;
 use crate::parser_utils::*;
+use crate::writer_utils::*;
 use chrono::naive::NaiveTime;
+use core::fmt;
 use nom::sequence::tuple;
 use nom::IResult;
 
@@ -21,6 +23,9 @@ pub struct ZDAMessage {
     pub ltzn: Option<u8>,
 }
 
+/// Names of the fields, in the order they appear in the sentence
+pub(crate) const FIELDS: &[&str] = &["time", "day", "month", "year", "ltzh", "ltzn"];
+
 pub fn parse_zda(input: &str) -> IResult<&str, ZDAMessage> {
     let (remaining, (time, day, month, year, ltzh, ltzn)) = tuple((
         parse_time, parse_u8, parse_u8, parse_u16, parse_u8, parse_u8,
@@ -38,6 +43,21 @@ pub fn parse_zda(input: &str) -> IResult<&str, ZDAMessage> {
     ))
 }
 
+impl fmt::Display for ZDAMessage {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{},{:02},{:02},{},{:02},{:02}",
+            Time(&self.time),
+            Optional(&self.day),
+            Optional(&self.month),
+            Optional(&self.year),
+            Optional(&self.ltzh),
+            Optional(&self.ltzn)
+        )
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -59,4 +79,13 @@ mod tests {
 
         assert_eq!(expected, parse_zda(input));
     }
+
+    #[test]
+    fn test_write_zda() {
+        let input = "082710.00,16,09,2002,00,00";
+        let expected = "082710.00,16,09,2002,00,00";
+        let (_, message) = parse_zda(input).unwrap();
+
+        assert_eq!(expected, message.to_string());
+    }
 }
@@ -0,0 +1,506 @@
+use crate::fields::cardinality::{EastWest, LeftRight, NorthSouth};
+use crate::fields::distance::{Degree, NauticalMile};
+use crate::fields::identity::Talker;
+use crate::fields::parameter::{Fix, SentenceType, Status};
+use crate::fields::speed::{CourseOverGroundUnit, Knot};
+use crate::math_utils::{normalize, round};
+use crate::messages::{APBMessage, GGAMessage, RMBMessage, RMCMessage, XTEMessage};
+use crate::route::{Route, RouteWaypoint, WaypointPosition};
+use crate::sentence::{Message, Sentence};
+use core::fmt;
+
+/// Mean radius of the Earth, in nautical miles
+const EARTH_RADIUS: f64 = 3440.065;
+
+/// Position of a valid RMC message
+pub fn position_from_rmc(message: &RMCMessage) -> Option<WaypointPosition> {
+    if message.status != Status::DataValid {
+        return None;
+    }
+    position(&message.lat, &message.ns, &message.lon, &message.ew)
+}
+
+/// Position of a GGA message with a fix
+pub fn position_from_gga(message: &GGAMessage) -> Option<WaypointPosition> {
+    if message.quality == Fix::NoFix {
+        return None;
+    }
+    position(&message.lat, &message.ns, &message.lon, &message.ew)
+}
+
+pub(crate) fn position(
+    lat: &Option<Degree>,
+    ns: &NorthSouth,
+    lon: &Option<Degree>,
+    ew: &EastWest,
+) -> Option<WaypointPosition> {
+    let lat = lat.as_ref()?.to_decimal().0;
+    let lon = lon.as_ref()?.to_decimal().0;
+    Some(WaypointPosition {
+        lat: Degree(match ns {
+            NorthSouth::North => lat,
+            NorthSouth::South => -lat,
+        }),
+        lon: Degree(match ew {
+            EastWest::East => lon,
+            EastWest::West => -lon,
+        }),
+    })
+}
+
+/// Great circle distance between two positions
+pub fn distance(from: &WaypointPosition, to: &WaypointPosition) -> NauticalMile {
+    let (lat1, lat2) = (from.lat.0.to_radians(), to.lat.0.to_radians());
+    let half_dlat = (lat2 - lat1) / 2.;
+    let half_dlon = (to.lon.0 - from.lon.0).to_radians() / 2.;
+    let a = half_dlat.sin().powi(2) + lat1.cos() * lat2.cos() * half_dlon.sin().powi(2);
+    NauticalMile(2. * EARTH_RADIUS * a.sqrt().min(1.).asin())
+}
+
+/// Initial bearing of the great circle between two positions, in degrees true
+pub fn bearing(from: &WaypointPosition, to: &WaypointPosition) -> Degree {
+    let (lat1, lat2) = (from.lat.0.to_radians(), to.lat.0.to_radians());
+    let dlon = (to.lon.0 - from.lon.0).to_radians();
+    let y = dlon.sin() * lat2.cos();
+    let x = lat1.cos() * lat2.sin() - lat1.sin() * lat2.cos() * dlon.cos();
+    Degree(normalize(y.atan2(x).to_degrees()))
+}
+
+#[derive(Debug, Clone, PartialEq)]
+/// Steering information towards the destination waypoint of the active leg
+pub struct Steering {
+    /// Origin waypoint identifier, empty when the leg starts at the first fix
+    pub origin: String,
+    /// Destination waypoint identifier
+    pub destination: String,
+    pub destination_position: WaypointPosition,
+    /// Cross-track error, distance to the great circle from the origin to the destination
+    pub xte: NauticalMile,
+    /// Direction to steer to get back on track
+    pub steer: LeftRight,
+    /// Initial bearing from the origin to the destination, in degrees true
+    pub bearing_origin_to_destination: Degree,
+    /// Bearing from the present position to the destination, in degrees true
+    pub bearing_to_destination: Degree,
+    /// Range to the destination
+    pub distance: NauticalMile,
+    /// Velocity towards the destination, known when the speed and course over ground are
+    pub closing_velocity: Option<Knot>,
+    pub arrival_circle_entered: bool,
+    /// Whether the perpendicular to the leg at the destination has been passed
+    pub perpendicular_passed: bool,
+    /// Mode indicator of the fix
+    pub pos_mode: Fix,
+}
+
+impl Steering {
+    /// XTE message, cross-track error rounded to a hundredth
+    pub fn to_xte(&self) -> XTEMessage {
+        XTEMessage {
+            status: Status::DataValid,
+            cycle_lock: Status::DataValid,
+            xte: Some(NauticalMile(round(self.xte.0, 2))),
+            steer: Some(self.steer),
+            pos_mode: Some(self.pos_mode.clone()),
+        }
+    }
+
+    /// APB message, steering to the bearing to the destination,
+    /// cross-track error rounded to a hundredth and bearings to a tenth
+    pub fn to_apb(&self) -> APBMessage<'_> {
+        let bearing_to_destination = Degree(round(self.bearing_to_destination.0, 1));
+        APBMessage {
+            status: Status::DataValid,
+            cycle_lock: Status::DataValid,
+            xte: Some(NauticalMile(round(self.xte.0, 2))),
+            steer: Some(self.steer),
+            arrival_circle_entered: self.arrival_circle_entered,
+            perpendicular_passed: self.perpendicular_passed,
+            bearing_origin_to_destination: Some(Degree(round(
+                self.bearing_origin_to_destination.0,
+                1,
+            ))),
+            bearing_origin_to_destination_unit: Some(CourseOverGroundUnit::DegreesTrue),
+            destination: &self.destination,
+            bearing_to_destination: Some(bearing_to_destination.clone()),
+            bearing_to_destination_unit: Some(CourseOverGroundUnit::DegreesTrue),
+            heading_to_steer: Some(bearing_to_destination),
+            heading_to_steer_unit: Some(CourseOverGroundUnit::DegreesTrue),
+            pos_mode: Some(self.pos_mode.clone()),
+        }
+    }
+
+    /// RMB message, distances rounded to a hundredth, bearing and velocity to a tenth
+    pub fn to_rmb(&self) -> RMBMessage<'_> {
+        let lat = self.destination_position.lat.0;
+        let lon = self.destination_position.lon.0;
+        RMBMessage {
+            status: Status::DataValid,
+            xte: Some(NauticalMile(round(self.xte.0, 2))),
+            steer: Some(self.steer),
+            origin: &self.origin,
+            destination: &self.destination,
+            lat: Some(Degree(lat.abs()).to_degrees_minutes()),
+            ns: Some(if lat < 0. {
+                NorthSouth::South
+            } else {
+                NorthSouth::North
+            }),
+            lon: Some(Degree(lon.abs()).to_degrees_minutes()),
+            ew: Some(if lon < 0. {
+                EastWest::West
+            } else {
+                EastWest::East
+            }),
+            range: Some(NauticalMile(round(self.distance.0, 2))),
+            bearing: Some(Degree(round(self.bearing_to_destination.0, 1))),
+            closing_velocity: self
+                .closing_velocity
+                .map(|velocity| Knot(round(velocity.0, 1))),
+            arrived: self.arrival_circle_entered || self.perpendicular_passed,
+            pos_mode: Some(self.pos_mode.clone()),
+        }
+    }
+
+    /// Writes the XTE, APB and RMB sentences driving an autopilot, in that order.
+    pub fn write_sentences<W: fmt::Write>(&self, talker: &Talker, w: &mut W) -> fmt::Result {
+        for message in [
+            Message::XTE(self.to_xte()),
+            Message::APB(self.to_apb()),
+            Message::RMB(self.to_rmb()),
+        ] {
+            Sentence {
+                tag_block: None,
+                sentence_type: SentenceType::Parametric,
+                talker: talker.clone(),
+                message,
+            }
+            .write_to(w)?;
+        }
+        Ok(())
+    }
+}
+
+/// Follows a route from position fixes.
+///
+/// Each leg goes from a waypoint to the next one, the first leg of a single waypoint route
+/// starts at the first fix. The computer moves on to the next leg once the arrival circle
+/// of the destination has been entered, or the perpendicular at the destination passed.
+///
+/// # Examples
+///
+/// ```
+/// # use nmea_0183::fields::distance::NauticalMile;
+/// # use nmea_0183::fields::identity::Talker;
+/// # use nmea_0183::navigation::NavigationComputer;
+/// # use nmea_0183::route::RouteAssembler;
+/// # use nmea_0183::{parse, Message};
+/// let mut assembler = RouteAssembler::new();
+/// let mut computer = None;
+/// for line in &[
+///     "$GPWPL,4700.00000,N,00800.00000,E,WP1*76\r\n",
+///     "$GPWPL,4710.00000,N,00800.00000,E,WP2*74\r\n",
+///     "$GPRTE,1,1,c,0,WP1,WP2*04\r\n",
+///     "$GPRMC,083559.00,A,4705.00000,N,00800.50000,E,6.0,350.0,091202,,,A,V*25\r\n",
+/// ] {
+///     match parse(line).unwrap().message {
+///         Message::WPL(wpl) => assembler.push_wpl(&wpl),
+///         Message::RTE(rte) => {
+///             let route = assembler.push_rte(&rte).unwrap().unwrap();
+///             computer = Some(NavigationComputer::new(route, NauticalMile(0.1)));
+///         }
+///         Message::RMC(rmc) => {
+///             let steering = computer.as_mut().unwrap().update_rmc(&rmc).unwrap();
+///             let mut output = String::new();
+///             steering
+///                 .write_sentences(&Talker::IntegratedNavigation, &mut output)
+///                 .unwrap();
+///             assert!(output.starts_with("$INXTE,A,A,0.34,L,N,A*"));
+///         }
+///         _ => {}
+///     }
+/// }
+/// ```
+#[derive(Debug)]
+pub struct NavigationComputer {
+    route: Route,
+    arrival_radius: NauticalMile,
+    /// Index of the destination waypoint of the active leg
+    destination: usize,
+    /// First fix, origin of the first leg of a single waypoint route
+    start: Option<WaypointPosition>,
+}
+
+impl NavigationComputer {
+    /// Starts following the route from its first leg,
+    /// waypoints are reached when the vessel comes within `arrival_radius`.
+    pub fn new(route: Route, arrival_radius: NauticalMile) -> Self {
+        let destination = if route.waypoints.len() > 1 { 1 } else { 0 };
+        NavigationComputer {
+            route,
+            arrival_radius,
+            destination,
+            start: None,
+        }
+    }
+
+    pub fn route(&self) -> &Route {
+        &self.route
+    }
+
+    /// Destination waypoint of the active leg, `None` for an empty route
+    pub fn destination(&self) -> Option<&RouteWaypoint> {
+        self.route.waypoints.get(self.destination)
+    }
+
+    /// Updates the steering from a valid RMC message, with its speed and course over ground.
+    pub fn update_rmc(&mut self, message: &RMCMessage) -> Option<Steering> {
+        let position = position_from_rmc(message)?;
+        self.update(
+            &position,
+            message.spd,
+            message.cog.clone(),
+            message.pos_mode.clone(),
+        )
+    }
+
+    /// Updates the steering from a GGA message with a fix.
+    pub fn update_gga(&mut self, message: &GGAMessage) -> Option<Steering> {
+        let position = position_from_gga(message)?;
+        self.update(&position, None, None, message.quality.clone())
+    }
+
+    /// Computes the steering towards the destination of the active leg,
+    /// and moves on to the next leg once the destination has been reached.
+    ///
+    /// Returns `None` when the position of the leg waypoints isn't known.
+    pub fn update(
+        &mut self,
+        position: &WaypointPosition,
+        speed: Option<Knot>,
+        course: Option<Degree>,
+        pos_mode: Fix,
+    ) -> Option<Steering> {
+        if self.start.is_none() {
+            self.start = Some(position.clone());
+        }
+        let destination = self.route.waypoints.get(self.destination)?;
+        let destination_position = destination.position.clone()?;
+        let (origin, origin_position) = if self.destination > 0 {
+            let origin = &self.route.waypoints[self.destination - 1];
+            (origin.id.clone(), origin.position.clone()?)
+        } else {
+            (String::new(), self.start.clone()?)
+        };
+
+        let leg_bearing = bearing(&origin_position, &destination_position);
+        let bearing_to_destination = bearing(position, &destination_position);
+        let range = distance(position, &destination_position);
+
+        // Angular distance to the great circle of the leg, positive on its starboard side
+        let from_origin = distance(&origin_position, position).0 / EARTH_RADIUS;
+        let off_track = bearing(&origin_position, position).0 - leg_bearing.0;
+        let xte = (from_origin.sin() * off_track.to_radians().sin()).asin() * EARTH_RADIUS;
+
+        // The origin and the present position are on both sides of the perpendicular
+        let from_destination = bearing(&destination_position, position).0
+            - bearing(&destination_position, &origin_position).0;
+        let perpendicular_passed = from_destination.to_radians().cos() < 0.;
+        let arrival_circle_entered = range.0 <= self.arrival_radius.0;
+
+        let closing_velocity = match (speed, course) {
+            (Some(speed), Some(course)) => Some(Knot(
+                speed.0 * (course.0 - bearing_to_destination.0).to_radians().cos(),
+            )),
+            _ => None,
+        };
+
+        let steering = Steering {
+            origin,
+            destination: destination.id.clone(),
+            destination_position,
+            xte: NauticalMile(xte.abs()),
+            steer: if xte > 0. {
+                LeftRight::Left
+            } else {
+                LeftRight::Right
+            },
+            bearing_origin_to_destination: leg_bearing,
+            bearing_to_destination,
+            distance: range,
+            closing_velocity,
+            arrival_circle_entered,
+            perpendicular_passed,
+            pos_mode,
+        };
+        if (arrival_circle_entered || perpendicular_passed)
+            && self.destination + 1 < self.route.waypoints.len()
+        {
+            self.destination += 1;
+        }
+        Some(steering)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fields::parameter::RouteMode;
+
+    fn assert_close(expected: f64, actual: f64) {
+        assert!(
+            (expected - actual).abs() < 1e-3,
+            "expected {}, got {}",
+            expected,
+            actual
+        );
+    }
+
+    fn at(lat: f64, lon: f64) -> WaypointPosition {
+        WaypointPosition {
+            lat: Degree(lat),
+            lon: Degree(lon),
+        }
+    }
+
+    fn waypoint(id: &str, position: Option<WaypointPosition>) -> RouteWaypoint {
+        RouteWaypoint {
+            id: id.to_string(),
+            position,
+        }
+    }
+
+    fn route(waypoints: Vec<RouteWaypoint>) -> Route {
+        Route {
+            id: "0".to_string(),
+            mode: RouteMode::Complete,
+            waypoints,
+        }
+    }
+
+    fn equator_route() -> Route {
+        route(vec![
+            waypoint("A", Some(at(0., 0.))),
+            waypoint("B", Some(at(0., 1.))),
+            waypoint("C", Some(at(1., 1.))),
+        ])
+    }
+
+    #[test]
+    fn test_distance_and_bearing() {
+        // A minute of latitude is close to a nautical mile
+        assert_close(60.04, distance(&at(0., 0.), &at(1., 0.)).0);
+        assert_close(0., bearing(&at(0., 0.), &at(1., 0.)).0);
+        assert_close(90., bearing(&at(0., 0.), &at(0., 1.)).0);
+        assert_close(270., bearing(&at(0., 1.), &at(0., 0.)).0);
+    }
+
+    #[test]
+    fn test_cross_track_error() {
+        let mut computer = NavigationComputer::new(equator_route(), NauticalMile(0.1));
+
+        // North of an eastbound leg
+        let steering = computer
+            .update(
+                &at(0.01, 0.5),
+                Some(Knot(5.)),
+                Some(Degree(90.)),
+                Fix::AutonomousGNSSFix,
+            )
+            .unwrap();
+        assert_eq!("A", steering.origin);
+        assert_eq!("B", steering.destination);
+        assert_close(0.6004, steering.xte.0);
+        assert_eq!(LeftRight::Right, steering.steer);
+        assert_close(90., steering.bearing_origin_to_destination.0);
+        assert_close(30.026, steering.distance.0);
+        assert_close(5., steering.closing_velocity.unwrap().0);
+        assert!(!steering.arrival_circle_entered);
+        assert!(!steering.perpendicular_passed);
+
+        // South of it
+        let steering = computer
+            .update(&at(-0.01, 0.5), None, None, Fix::AutonomousGNSSFix)
+            .unwrap();
+        assert_eq!(LeftRight::Left, steering.steer);
+        assert_eq!(None, steering.closing_velocity);
+    }
+
+    #[test]
+    fn test_waypoint_advancement() {
+        let mut computer = NavigationComputer::new(equator_route(), NauticalMile(0.1));
+
+        let steering = computer
+            .update(&at(0., 0.999), None, None, Fix::AutonomousGNSSFix)
+            .unwrap();
+        assert!(steering.arrival_circle_entered);
+        assert_eq!("B", steering.destination);
+        assert_eq!("C", computer.destination().unwrap().id);
+
+        // Passing abeam of C, outside of its arrival circle
+        let steering = computer
+            .update(&at(1.01, 1.2), None, None, Fix::AutonomousGNSSFix)
+            .unwrap();
+        assert_eq!("C", steering.destination);
+        assert!(!steering.arrival_circle_entered);
+        assert!(steering.perpendicular_passed);
+        // Last waypoint
+        assert_eq!("C", computer.destination().unwrap().id);
+    }
+
+    #[test]
+    fn test_single_waypoint_route() {
+        let mut computer = NavigationComputer::new(
+            route(vec![waypoint("A", Some(at(1., 0.)))]),
+            NauticalMile(0.1),
+        );
+        let steering = computer
+            .update(&at(0., 0.), None, None, Fix::AutonomousGNSSFix)
+            .unwrap();
+        assert_eq!("", steering.origin);
+        assert_close(0., steering.xte.0);
+        assert_close(0., steering.bearing_to_destination.0);
+    }
+
+    #[test]
+    fn test_unknown_waypoint_position() {
+        let mut computer = NavigationComputer::new(
+            route(vec![waypoint("A", Some(at(0., 0.))), waypoint("B", None)]),
+            NauticalMile(0.1),
+        );
+        assert_eq!(
+            None,
+            computer.update(&at(0., 0.5), None, None, Fix::AutonomousGNSSFix)
+        );
+
+        let mut computer = NavigationComputer::new(route(Vec::new()), NauticalMile(0.1));
+        assert_eq!(None, computer.destination());
+        assert_eq!(
+            None,
+            computer.update(&at(0., 0.5), None, None, Fix::AutonomousGNSSFix)
+        );
+    }
+
+    #[test]
+    fn test_write_sentences() {
+        let mut computer = NavigationComputer::new(equator_route(), NauticalMile(0.1));
+        let steering = computer
+            .update(
+                &at(0.01, 0.5),
+                Some(Knot(5.)),
+                Some(Degree(90.)),
+                Fix::DifferentialGNSSFix,
+            )
+            .unwrap();
+        let mut output = String::new();
+        steering
+            .write_sentences(&Talker::IntegratedNavigation, &mut output)
+            .unwrap();
+        assert_eq!(
+            "$INXTE,A,A,0.6,R,N,D*3E\r\n\
+             $INAPB,A,A,0.6,R,N,V,V,90,T,B,91.1,T,91.1,T,D*17\r\n\
+             $INRMB,A,0.6,R,A,B,0000.00000,N,00100.00000,E,30.03,91.1,5,V,D*46\r\n",
+            output
+        );
+    }
+}
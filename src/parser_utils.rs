@@ -99,6 +99,30 @@ pub fn parse_u16(input: &str) -> IResult<&str, Option<u16>> {
     remove_separator_if_next(',', remaining, result)
 }
 
+/// Parses a string field which may be empty when it ends the sentence
+pub fn parse_last_string(input: &str) -> IResult<&str, &str> {
+    if input.is_empty() {
+        return Ok((input, input));
+    }
+    parse_string(input)
+}
+
+/// Checks a unit field holds the given unit, or is empty
+pub fn ensure_unit(unit: &'static str) -> impl Fn(&str) -> IResult<&str, ()> {
+    move |input: &str| {
+        // An empty last field
+        if input.is_empty() {
+            return Ok((input, ()));
+        }
+        let (remaining, field) = parse_string(input)?;
+        if field.is_empty() || field == unit {
+            Ok((remaining, ()))
+        } else {
+            Err(nom::Err::Failure((input, nom::error::ErrorKind::OneOf)))
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -180,4 +204,12 @@ mod tests {
         assert_eq!(expected_four, parse_string(expected_three.unwrap().0));
         assert_eq!(expected_five, parse_string(expected_four.unwrap().0));
     }
+
+    #[test]
+    fn test_ensure_unit() {
+        assert_eq!(Ok(("1", ())), ensure_unit("f")("f,1"));
+        assert_eq!(Ok(("1", ())), ensure_unit("f")(",1"));
+        assert_eq!(Ok(("", ())), ensure_unit("F")("F"));
+        assert!(ensure_unit("f")("M,1").is_err());
+    }
 }
@@ -0,0 +1,253 @@
+use crate::error::Error;
+use crate::sentence::{parse_sentence, Sentence};
+use std::io::{self, BufRead, BufReader, Read};
+
+/// Number of characters after which a sentence is considered garbage.
+///
+/// IEC 61162-1 limits a sentence to 82 characters,
+/// but some receivers emit longer proprietary sentences so we are a bit more forgiving.
+const MAX_LINE_LENGTH: usize = 512;
+
+/// What happened to the sentence being buffered.
+enum Event {
+    /// A CR LF (or a lone LF) terminated the sentence
+    Complete,
+    /// The stream ended, or a new start delimiter was found, before the end of the sentence
+    Truncated(Option<u8>),
+    /// A non printable character was found in the middle of the sentence
+    Invalid,
+    /// The sentence is longer than MAX_LINE_LENGTH
+    Overflow,
+}
+
+/// Reads NMEA sentences from a byte stream, such as a serial port or a log file.
+///
+/// The reader looks for a `$` or `!` start delimiter, or the `\` starting a tag block,
+/// and buffers everything up to the next `\n`. Anything in between two sentences is skipped, and a sentence that is
+/// interrupted by a new start delimiter, binary noise or that grows too long is reported
+/// as an error before the reader resynchronises on the next start delimiter.
+///
+/// Sentences borrow from the reader's internal buffer,
+/// which is why `NmeaReader` doesn't implement `Iterator`.
+///
+/// # Examples
+///
+/// ```
+/// # use nmea_0183::reader::NmeaReader;
+/// # fn main() -> std::io::Result<()> {
+/// let log = "garbage$GPGBQ,RMC*27\r\n$UPGBQ,RMC*21\r\n".as_bytes();
+/// let mut reader = NmeaReader::new(log);
+///
+/// let mut parsed = 0;
+/// while let Some(result) = reader.read_sentence()? {
+///     if result.is_ok() {
+///         parsed += 1;
+///     }
+/// }
+/// assert_eq!(1, parsed);
+/// # Ok(())
+/// # }
+/// ```
+pub struct NmeaReader<R> {
+    inner: R,
+    line: String,
+    /// Start delimiter that interrupted the previous sentence
+    pending_start: Option<u8>,
+}
+
+impl<R: Read> NmeaReader<BufReader<R>> {
+    /// Creates a reader over any `Read` implementor, wrapping it in a `BufReader`.
+    pub fn new(inner: R) -> Self {
+        NmeaReader::from_buf_read(BufReader::new(inner))
+    }
+}
+
+impl<R: BufRead> NmeaReader<R> {
+    /// Creates a reader over an already buffered stream.
+    pub fn from_buf_read(inner: R) -> Self {
+        NmeaReader {
+            inner,
+            line: String::with_capacity(MAX_LINE_LENGTH),
+            pending_start: None,
+        }
+    }
+
+    /// Unwraps this `NmeaReader`, returning the underlying stream.
+    pub fn into_inner(self) -> R {
+        self.inner
+    }
+
+    /// Reads the next sentence from the stream.
+    ///
+    /// Returns `Ok(None)` once the end of the stream has been reached,
+    /// and `Ok(Some(Err(_)))` if a sentence could not be read or parsed.
+    /// The stream can still be read after such an error.
+    pub fn read_sentence(&mut self) -> io::Result<Option<Result<Sentence<'_>, Error>>> {
+        self.line.clear();
+        if let Some(start) = self.pending_start.take() {
+            self.line.push(start as char);
+        }
+
+        let event = loop {
+            let available = match self.inner.fill_buf() {
+                Ok(available) => available,
+                Err(ref err) if err.kind() == io::ErrorKind::Interrupted => continue,
+                Err(err) => return Err(err),
+            };
+            if available.is_empty() {
+                // The stream ended in the middle of a sentence
+                if !self.line.is_empty() {
+                    break Event::Truncated(None);
+                }
+                return Ok(None);
+            }
+
+            let mut consumed = 0;
+            let mut event = None;
+            for &byte in available {
+                consumed += 1;
+                match byte {
+                    b'$' | b'!' | b'\\' if self.line.is_empty() => self.line.push(byte as char),
+                    // Start of the sentence following a tag block
+                    b'$' | b'!'
+                        if self.line.len() > 1
+                            && self.line.starts_with('\\')
+                            && self.line.ends_with('\\') =>
+                    {
+                        self.line.push(byte as char)
+                    }
+                    b'$' | b'!' => {
+                        event = Some(Event::Truncated(Some(byte)));
+                        break;
+                    }
+                    // Skip whatever is between two sentences
+                    _ if self.line.is_empty() => {}
+                    b'\n' => {
+                        self.line.push('\n');
+                        event = Some(Event::Complete);
+                        break;
+                    }
+                    b'\r' | b' '..=b'~' => self.line.push(byte as char),
+                    _ => {
+                        event = Some(Event::Invalid);
+                        break;
+                    }
+                }
+                if self.line.len() > MAX_LINE_LENGTH {
+                    event = Some(Event::Overflow);
+                    break;
+                }
+            }
+            self.inner.consume(consumed);
+
+            if let Some(event) = event {
+                break event;
+            }
+        };
+
+        let result = match event {
+            Event::Complete => parse_sentence(&self.line).map(|(_, sentence)| sentence),
+            Event::Truncated(start) => {
+                self.pending_start = start;
+                Err(Error::Framing)
+            }
+            Event::Invalid => Err(Error::Framing),
+            Event::Overflow => Err(Error::BufferOverflow),
+        };
+        Ok(Some(result))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::messages::GBQMessage;
+    use crate::Message;
+
+    fn read_all(input: &[u8]) -> Vec<Result<String, Error>> {
+        let mut reader = NmeaReader::new(input);
+        let mut results = Vec::new();
+        while let Some(result) = reader.read_sentence().unwrap() {
+            results.push(result.map(|sentence| format!("{:?}", sentence.message)));
+        }
+        results
+    }
+
+    #[test]
+    fn test_read_sentences() {
+        let input = "$UPGBQ,RMC*21\r\n$GPVTG,77.52,T,,M,0.004,N,0.008,K,A*06\r\n".as_bytes();
+        let mut reader = NmeaReader::new(input);
+
+        let first = reader.read_sentence().unwrap().unwrap().unwrap();
+        assert_eq!(Message::GBQ(GBQMessage { msg_id: "RMC" }), first.message);
+        assert!(reader.read_sentence().unwrap().unwrap().is_ok());
+        assert!(reader.read_sentence().unwrap().is_none());
+    }
+
+    #[test]
+    fn test_read_tag_block() {
+        let input = "noise\\s:GP0001,c:1577096133*20\\$UPGBQ,RMC*21\r\n".as_bytes();
+        let mut reader = NmeaReader::new(input);
+
+        let sentence = reader.read_sentence().unwrap().unwrap().unwrap();
+        assert_eq!(Some("GP0001"), sentence.tag_block.unwrap().source);
+        assert!(reader.read_sentence().unwrap().is_none());
+    }
+
+    #[test]
+    fn test_read_skips_garbage_between_sentences() {
+        let input = b"\x00\xffnoise\r\n$UPGBQ,RMC*21\r\n\xfe,,,$UPGBQ,RMC*21\r\n";
+        let results = read_all(input);
+        assert_eq!(2, results.len());
+        assert!(results.iter().all(Result::is_ok));
+    }
+
+    #[test]
+    fn test_read_resynchronises_after_truncated_sentence() {
+        let input = "$GPVTG,77.52,T,,M$UPGBQ,RMC*21\r\n$UPGBQ,RM".as_bytes();
+        let results = read_all(input);
+        assert_eq!(
+            vec![
+                Err(Error::Framing),
+                Ok(format!("{:?}", Message::GBQ(GBQMessage { msg_id: "RMC" }))),
+                Err(Error::Framing),
+            ],
+            results
+        );
+    }
+
+    #[test]
+    fn test_read_resynchronises_after_binary_noise() {
+        let input = b"$GPVTG,77.52,\x00\x01T,,M\r\n$UPGBQ,RMC*21\r\n";
+        let results = read_all(input);
+        assert_eq!(2, results.len());
+        assert_eq!(Err(Error::Framing), results[0]);
+        assert!(results[1].is_ok());
+    }
+
+    #[test]
+    fn test_read_resynchronises_after_overflow() {
+        let mut input = b"$GPTXT,".to_vec();
+        input.extend(vec![b'A'; MAX_LINE_LENGTH]);
+        input.extend(b"\r\n$UPGBQ,RMC*21\r\n");
+        let results = read_all(&input);
+        assert_eq!(2, results.len());
+        assert_eq!(Err(Error::BufferOverflow), results[0]);
+        assert!(results[1].is_ok());
+    }
+
+    #[test]
+    fn test_read_reports_invalid_sentences() {
+        let input = "$UPGBQ,RMC*22\r\n$UPGBQ,RMC*21\r\n".as_bytes();
+        let results = read_all(input);
+        assert_eq!(2, results.len());
+        assert_eq!(
+            Err(Error::ChecksumMismatch {
+                expected: 0x22,
+                computed: 0x21
+            }),
+            results[0]
+        );
+        assert!(results[1].is_ok());
+    }
+}
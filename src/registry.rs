@@ -0,0 +1,251 @@
+use crate::error::Error;
+use crate::fields::identity::Talker;
+use crate::sentence::{parse_raw_sentence, parse_sentence, Message, ProprietaryBody, Sentence};
+
+/// Decodes the fields of a sentence the crate doesn't know about.
+///
+/// Closures taking the talker and the raw fields implement this trait.
+pub trait SentenceParser {
+    /// Message produced by the parser
+    type Output;
+
+    /// Parses everything between the message ID (or the manufacturer mnemonic
+    /// of a proprietary sentence) and the checksum.
+    fn parse(&self, talker: &Talker, fields: &str) -> Result<Self::Output, Error>;
+}
+
+impl<F, T> SentenceParser for F
+where
+    F: Fn(&Talker, &str) -> Result<T, Error>,
+{
+    type Output = T;
+
+    fn parse(&self, talker: &Talker, fields: &str) -> Result<T, Error> {
+        self(talker, fields)
+    }
+}
+
+/// A sentence parsed by a `ParserRegistry`
+#[derive(Debug, PartialEq)]
+pub struct ParsedSentence<'a, T> {
+    /// Sentence parsed by the crate, with a `Message::Unknown` or raw `Message::Proprietary`
+    /// message when a registered parser handled it, even for a message type the crate knows
+    pub sentence: Sentence<'a>,
+    /// Message decoded by the registered parser that handled the sentence, if any
+    pub custom: Option<T>,
+}
+
+struct Entry<T> {
+    talker: Option<Talker>,
+    id: String,
+    parser: Box<dyn SentenceParser<Output = T>>,
+}
+
+/// Parses sentences with the crate's parsers,
+/// and falls back on user provided parsers for the message types the crate doesn't know about,
+/// or when the crate's parser rejects the fields of a sentence.
+///
+/// # Examples
+///
+/// ```
+/// # use nmea_0183::registry::ParserRegistry;
+/// # use nmea_0183::fields::identity::Talker;
+/// # use nmea_0183::Error;
+/// let mut registry = ParserRegistry::new();
+/// registry.register(None, "BAT", |_: &Talker, fields: &str| {
+///     fields
+///         .split(',')
+///         .next()
+///         .and_then(|voltage| voltage.parse::<f64>().ok())
+///         .ok_or(Error::Framing)
+/// });
+///
+/// let parsed = registry.parse("$IIBAT,12.6,V*1A\r\n")?;
+/// assert_eq!(Some(12.6), parsed.custom);
+/// # Ok::<(), Error>(())
+/// ```
+pub struct ParserRegistry<T> {
+    entries: Vec<Entry<T>>,
+}
+
+impl<T> Default for ParserRegistry<T> {
+    fn default() -> Self {
+        ParserRegistry::new()
+    }
+}
+
+impl<T> ParserRegistry<T> {
+    pub fn new() -> Self {
+        ParserRegistry {
+            entries: Vec::new(),
+        }
+    }
+
+    /// Registers a parser for a message type, such as `BAT`,
+    /// or for a manufacturer mnemonic, such as `GRM` for the `$PGRM` proprietary sentences.
+    ///
+    /// The parser only handles sentences from the given talker, or from any talker when it is `None`.
+    /// Parsers are tried in the order they have been registered.
+    pub fn register<P>(&mut self, talker: Option<Talker>, id: &str, parser: P) -> &mut Self
+    where
+        P: SentenceParser<Output = T> + 'static,
+    {
+        self.entries.push(Entry {
+            talker,
+            id: id.to_string(),
+            parser: Box::new(parser),
+        });
+        self
+    }
+
+    /// Parses a sentence, consulting the registered parsers
+    /// when the crate doesn't know the message type or the manufacturer,
+    /// or when the crate's parser fails with `Error::InvalidField`.
+    pub fn parse<'a>(&self, input: &'a str) -> Result<ParsedSentence<'a, T>, Error> {
+        let sentence = match parse_sentence(input) {
+            Ok((_, sentence)) => sentence,
+            Err(err @ Error::InvalidField { .. }) => match parse_raw_sentence(input) {
+                Ok(sentence) if self.entry(&sentence).is_some() => sentence,
+                _ => return Err(err),
+            },
+            Err(err) => return Err(err),
+        };
+        let custom = match self.entry(&sentence) {
+            Some((entry, fields)) => Some(entry.parser.parse(&sentence.talker, fields)?),
+            None => None,
+        };
+        Ok(ParsedSentence { sentence, custom })
+    }
+
+    /// Registered parser for an unknown or raw proprietary message, and the fields to parse
+    fn entry<'a>(&self, sentence: &Sentence<'a>) -> Option<(&Entry<T>, &'a str)> {
+        let (id, fields) = match sentence.message {
+            Message::Unknown { id, fields } => (id, fields),
+            Message::Proprietary {
+                manufacturer,
+                body: ProprietaryBody::Raw(body),
+            } => (manufacturer, body),
+            _ => return None,
+        };
+        let entry = self.entries.iter().find(|entry| {
+            let same_talker = match &entry.talker {
+                Some(talker) => talker == &sentence.talker,
+                None => true,
+            };
+            entry.id == id && same_talker
+        })?;
+        Some((entry, fields))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::error_string;
+    use crate::fields::parameter::SentenceType;
+
+    #[derive(Debug, PartialEq)]
+    enum Custom {
+        Battery(f64),
+        Abc(String),
+        Status(String),
+    }
+
+    fn parse_battery(_: &Talker, fields: &str) -> Result<Custom, Error> {
+        let voltage = fields.split(',').next().unwrap_or(fields);
+        voltage
+            .parse()
+            .map(Custom::Battery)
+            .map_err(|_| Error::InvalidField {
+                message: "BAT",
+                field_index: 0,
+                field_name: "voltage",
+                raw: error_string(voltage),
+            })
+    }
+
+    fn registry() -> ParserRegistry<Custom> {
+        let mut registry = ParserRegistry::new();
+        registry
+            .register(
+                Some(Talker::IntegratedInstrumentation),
+                "BAT",
+                parse_battery,
+            )
+            .register(None, "ABC", |_: &Talker, body: &str| {
+                Ok(Custom::Abc(body.to_string()))
+            })
+            .register(Some(Talker::GPS), "RMC", |_: &Talker, fields: &str| {
+                Ok(Custom::Status(fields.to_string()))
+            });
+        registry
+    }
+
+    #[test]
+    fn test_parse_registered_sentence() {
+        let parsed = registry().parse("$IIBAT,12.6,V*1A\r\n").unwrap();
+        assert_eq!(Talker::IntegratedInstrumentation, parsed.sentence.talker);
+        assert_eq!(Some(Custom::Battery(12.6)), parsed.custom);
+    }
+
+    #[test]
+    fn test_parse_registered_proprietary_sentence() {
+        let parsed = registry().parse("$PABC,1,2*13\r\n").unwrap();
+        assert_eq!(Some(Custom::Abc(",1,2".to_string())), parsed.custom);
+    }
+
+    #[test]
+    fn test_parse_other_talker() {
+        let expected = ParsedSentence {
+            sentence: Sentence {
+                tag_block: None,
+                sentence_type: SentenceType::Parametric,
+                talker: Talker::GPS,
+                message: Message::Unknown {
+                    id: "BAT",
+                    fields: "12.6,V",
+                },
+            },
+            custom: None,
+        };
+        assert_eq!(Ok(expected), registry().parse("$GPBAT,12.6,V*0D\r\n"));
+    }
+
+    #[test]
+    fn test_parse_builtin_sentence() {
+        let parsed = registry().parse("$UPGBQ,RMC*21\r\n").unwrap();
+        assert!(matches!(parsed.sentence.message, Message::GBQ(_)));
+        assert_eq!(None, parsed.custom);
+    }
+
+    #[test]
+    fn test_parse_rejected_builtin_sentence() {
+        let parsed = registry().parse("$GPRMC,OFF,V*52\r\n").unwrap();
+        assert_eq!(
+            Message::Unknown {
+                id: "RMC",
+                fields: "OFF,V"
+            },
+            parsed.sentence.message
+        );
+        assert_eq!(Some(Custom::Status("OFF,V".to_string())), parsed.custom);
+
+        // No parser registered for this talker
+        let result = registry().parse("$GNRMC,OFF,V*4C\r\n");
+        assert!(matches!(
+            result,
+            Err(Error::InvalidField { message: "RMC", .. })
+        ));
+    }
+
+    #[test]
+    fn test_parse_registered_sentence_error() {
+        let expected = Error::InvalidField {
+            message: "BAT",
+            field_index: 0,
+            field_name: "voltage",
+            raw: error_string("high"),
+        };
+        assert_eq!(Err(expected), registry().parse("$IIBAT,high,V*0F\r\n"));
+    }
+}
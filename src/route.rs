@@ -0,0 +1,238 @@
+use crate::error::Error;
+use crate::fields::distance::Degree;
+use crate::fields::parameter::RouteMode;
+use crate::messages::{RTEMessage, WPLMessage};
+use std::collections::HashMap;
+
+#[derive(Debug, Clone, PartialEq)]
+/// Latitude and longitude, in decimal degrees, negative in the southern and western hemispheres
+pub struct WaypointPosition {
+    pub lat: Degree,
+    pub lon: Degree,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+/// A waypoint of a route
+pub struct RouteWaypoint {
+    /// Waypoint identifier
+    pub id: String,
+    /// Position received in a WPL sentence, if any
+    pub position: Option<WaypointPosition>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+/// A route combined from all the sentences of an RTE group
+pub struct Route {
+    /// Route identifier
+    pub id: String,
+    pub mode: RouteMode,
+    /// Waypoints, in route order
+    pub waypoints: Vec<RouteWaypoint>,
+}
+
+#[derive(Debug)]
+struct PendingRoute {
+    id: String,
+    mode: RouteMode,
+    total_msgs: u8,
+    received: u8,
+    waypoints: Vec<String>,
+}
+
+/// Combines RTE sentences into routes,
+/// and resolves their waypoints against the positions received in WPL sentences.
+///
+/// The sentences of a route must be received in order.
+/// WPL sentences received after a route has been completed can be applied with `resolve`.
+///
+/// # Examples
+///
+/// ```
+/// # use nmea_0183::route::RouteAssembler;
+/// # use nmea_0183::{parse, Message};
+/// let mut assembler = RouteAssembler::new();
+/// for line in &[
+///     "$GPWPL,4917.16,N,12310.64,W,003*65\r\n",
+///     "$GPRTE,1,1,c,0,003,004*00\r\n",
+/// ] {
+///     match parse(line).unwrap().message {
+///         Message::WPL(wpl) => assembler.push_wpl(&wpl),
+///         Message::RTE(rte) => {
+///             let route = assembler.push_rte(&rte).unwrap().unwrap();
+///             assert_eq!("003", route.waypoints[0].id);
+///             assert!(route.waypoints[0].position.is_some());
+///             assert!(route.waypoints[1].position.is_none());
+///         }
+///         _ => {}
+///     }
+/// }
+/// ```
+#[derive(Debug, Default)]
+pub struct RouteAssembler {
+    positions: HashMap<String, WaypointPosition>,
+    pending: Option<PendingRoute>,
+}
+
+impl RouteAssembler {
+    pub fn new() -> Self {
+        RouteAssembler::default()
+    }
+
+    /// Forgets the waypoint positions and drops the route being assembled.
+    pub fn reset(&mut self) {
+        self.positions.clear();
+        self.pending = None;
+    }
+
+    /// Records the position of a waypoint, WPL sentences without a position are ignored.
+    pub fn push_wpl(&mut self, message: &WPLMessage) {
+        if let Some((lat, lon)) = message.position() {
+            self.positions
+                .insert(message.waypoint.to_string(), WaypointPosition { lat, lon });
+        }
+    }
+
+    /// Position of a waypoint, as received in the latest WPL sentence for this identifier
+    pub fn position(&self, id: &str) -> Option<&WaypointPosition> {
+        self.positions.get(id)
+    }
+
+    /// Feeds an RTE sentence to the assembler.
+    ///
+    /// Returns the route once its last sentence has been received,
+    /// and `Error::UnexpectedFragment` if the sentence doesn't follow the previous one.
+    pub fn push_rte(&mut self, message: &RTEMessage) -> Result<Option<Route>, Error> {
+        if message.msg_num == 0 || message.msg_num > message.total_msgs {
+            self.pending = None;
+            return Err(Error::UnexpectedFragment);
+        }
+        if message.msg_num == 1 {
+            self.pending = Some(PendingRoute {
+                id: message.route.to_string(),
+                mode: message.mode,
+                total_msgs: message.total_msgs,
+                received: 0,
+                waypoints: Vec::new(),
+            });
+        }
+        let pending = match self.pending.as_mut() {
+            Some(pending)
+                if pending.id == message.route
+                    && pending.total_msgs == message.total_msgs
+                    && pending.received + 1 == message.msg_num =>
+            {
+                pending
+            }
+            _ => {
+                self.pending = None;
+                return Err(Error::UnexpectedFragment);
+            }
+        };
+        pending.received = message.msg_num;
+        pending
+            .waypoints
+            .extend(message.waypoints().map(str::to_string));
+        if pending.received < pending.total_msgs {
+            return Ok(None);
+        }
+
+        // Checked above
+        let pending = self.pending.take().unwrap();
+        let mut route = Route {
+            id: pending.id,
+            mode: pending.mode,
+            waypoints: pending
+                .waypoints
+                .into_iter()
+                .map(|id| RouteWaypoint { id, position: None })
+                .collect(),
+        };
+        self.resolve(&mut route);
+        Ok(Some(route))
+    }
+
+    /// Fills in the positions of the route waypoints received in WPL sentences.
+    pub fn resolve(&self, route: &mut Route) {
+        for waypoint in &mut route.waypoints {
+            if let Some(position) = self.positions.get(&waypoint.id) {
+                waypoint.position = Some(position.clone());
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::messages::{parse_rte, parse_wpl};
+
+    fn rte(input: &str) -> RTEMessage<'_> {
+        parse_rte(input).unwrap().1
+    }
+
+    fn wpl(input: &str) -> WPLMessage<'_> {
+        parse_wpl(input).unwrap().1
+    }
+
+    #[test]
+    fn test_assemble_route() {
+        let mut assembler = RouteAssembler::new();
+        assembler.push_wpl(&wpl("4917.16,N,12310.64,W,WP1"));
+        assembler.push_wpl(&wpl("4916.45,N,12311.12,W,WP3"));
+
+        assert_eq!(Ok(None), assembler.push_rte(&rte("2,1,c,HOME,WP1,WP2")));
+        let route = assembler.push_rte(&rte("2,2,c,HOME,WP3")).unwrap().unwrap();
+
+        assert_eq!("HOME", route.id);
+        assert_eq!(RouteMode::Complete, route.mode);
+        let ids: Vec<_> = route.waypoints.iter().map(|w| w.id.as_str()).collect();
+        assert_eq!(vec!["WP1", "WP2", "WP3"], ids);
+        let position = route.waypoints[0].position.as_ref().unwrap();
+        assert!((position.lat.0 - 49.286).abs() < 1e-9);
+        assert!((position.lon.0 + 123.17733333333334).abs() < 1e-9);
+        assert_eq!(None, route.waypoints[1].position);
+        assert!(route.waypoints[2].position.is_some());
+    }
+
+    #[test]
+    fn test_resolve_later_waypoints() {
+        let mut assembler = RouteAssembler::new();
+        let mut route = assembler
+            .push_rte(&rte("1,1,w,0,WP1,WP2"))
+            .unwrap()
+            .unwrap();
+        assert_eq!(None, route.waypoints[1].position);
+
+        assembler.push_wpl(&wpl("4916.45,N,12311.12,W,WP2"));
+        assembler.resolve(&mut route);
+        assert!(route.waypoints[1].position.is_some());
+        assert!(assembler.position("WP2").is_some());
+        assert_eq!(None, assembler.position("WP1"));
+    }
+
+    #[test]
+    fn test_unexpected_route_sentence() {
+        let mut assembler = RouteAssembler::new();
+        assert_eq!(
+            Err(Error::UnexpectedFragment),
+            assembler.push_rte(&rte("2,2,c,0,WP3"))
+        );
+
+        assert_eq!(Ok(None), assembler.push_rte(&rte("3,1,c,0,WP1")));
+        assert_eq!(
+            Err(Error::UnexpectedFragment),
+            assembler.push_rte(&rte("3,3,c,0,WP3"))
+        );
+        // The route has been dropped
+        assert_eq!(
+            Err(Error::UnexpectedFragment),
+            assembler.push_rte(&rte("3,2,c,0,WP2"))
+        );
+
+        assert_eq!(Ok(None), assembler.push_rte(&rte("2,1,c,A,WP1")));
+        assert_eq!(
+            Err(Error::UnexpectedFragment),
+            assembler.push_rte(&rte("2,2,c,B,WP2"))
+        );
+    }
+}
@@ -1,5 +1,6 @@
 pub(crate) mod parser;
 mod structs;
+mod writer;
 pub(crate) use parser::*;
 pub use structs::*;
 
@@ -11,14 +12,17 @@ mod sentence_tests {
     use crate::fields::identity::*;
     use crate::fields::parameter::*;
     use crate::fields::speed::*;
+    use crate::fields::transducer::*;
     use crate::messages::*;
 
     use chrono::naive::{NaiveDate, NaiveTime};
+    use heapless::Vec;
 
     #[test]
     fn test_parse_dtm_0_lat_lon_alt() {
         let input = "$GPDTM,W84,,0.0,N,0.0,E,0.0,W84*6F\r\n";
         let expected_sentence = Sentence {
+            tag_block: None,
             sentence_type: SentenceType::Parametric,
             talker: Talker::GPS,
             message: Message::DTM(DTMMessage {
@@ -41,6 +45,7 @@ mod sentence_tests {
     fn test_parse_dtm_lat_lon_alt() {
         let input = "$GPDTM,999,,0.08,N,0.07,E,-47.7,W84*1B\r\n";
         let expected_sentence = Sentence {
+            tag_block: None,
             sentence_type: SentenceType::Parametric,
             talker: Talker::GPS,
             message: Message::DTM(DTMMessage {
@@ -63,6 +68,7 @@ mod sentence_tests {
     fn test_parse_dtm_no_lat_lon_alt() {
         let input = "$GPDTM,999,,,N,,E,,W84*23\r\n";
         let expected_sentence = Sentence {
+            tag_block: None,
             sentence_type: SentenceType::Parametric,
             talker: Talker::GPS,
             message: Message::DTM(DTMMessage {
@@ -85,6 +91,7 @@ mod sentence_tests {
     fn test_parse_gbq() {
         let input = "$UPGBQ,RMC*21\r\n";
         let expected_sentence = Sentence {
+            tag_block: None,
             sentence_type: SentenceType::Parametric,
             talker: Talker::MicroprocessorController,
             message: Message::GBQ(GBQMessage { msg_id: "RMC" }),
@@ -98,6 +105,7 @@ mod sentence_tests {
     fn test_parse_gga() {
         let input = "$GPGGA,092725.00,4717.11399,N,00833.91590,E,1,08,1.01,499.6,M,48.0,M,,*5B\r\n";
         let expected_sentence = Sentence {
+            tag_block: None,
             sentence_type: SentenceType::Parametric,
             talker: Talker::GPS,
             message: Message::GGA(GGAMessage {
@@ -124,6 +132,7 @@ mod sentence_tests {
     fn test_parse_gsa() {
         let input = "$GNGSA,A,3,80,71,73,79,69,,,,,,,,1.83,1.09,1.47*17\r\n";
         let expected_sentence = Sentence {
+            tag_block: None,
             sentence_type: SentenceType::Parametric,
             talker: Talker::GPSGLONASS,
             message: Message::GSA(GSAMessage {
@@ -157,13 +166,14 @@ mod sentence_tests {
     fn test_parse_gsv() {
         let input = "$GPGSV,3,1,11,03,03,111,00,04,15,270,00,06,01,010,00,13,06,292,00*74\r\n";
         let expected_sentence = Sentence {
+            tag_block: None,
             sentence_type: SentenceType::Parametric,
             talker: Talker::GPS,
             message: Message::GSV(GSVMessage {
                 total_msgs: 3,
                 msg_num: 1,
                 satellite_num: 11,
-                satellites: vec![
+                satellites: Vec::from_slice(&[
                     SatelliteInView {
                         id: Some(3),
                         elv: Some(Degree(3.)),
@@ -188,7 +198,8 @@ mod sentence_tests {
                         az: Some(Degree(292.)),
                         cno: Some(DBHZ(0.)),
                     },
-                ],
+                ])
+                .unwrap(),
             }),
         };
 
@@ -200,6 +211,7 @@ mod sentence_tests {
     fn test_parse_gll() {
         let input = "$GPGLL,4717.11364,N,00833.91565,E,092321.00,A,A*60\r\n";
         let expected_sentence = Sentence {
+            tag_block: None,
             sentence_type: SentenceType::Parametric,
             talker: Talker::GPS,
             message: Message::GLL(GLLMessage {
@@ -221,6 +233,7 @@ mod sentence_tests {
     fn test_parse_zda() {
         let input = "$GPZDA,082710.00,16,09,2002,00,00*64\r\n";
         let expected_sentence = Sentence {
+            tag_block: None,
             sentence_type: SentenceType::Parametric,
             talker: Talker::GPS,
             message: Message::ZDA(ZDAMessage {
@@ -241,6 +254,7 @@ mod sentence_tests {
     fn test_parse_rmc() {
         let input = "$GPRMC,083559.00,A,4717.11437,N,00833.91522,E,0.004,77.52,091202,,,A,V*2D\r\n";
         let expected_sentence = Sentence {
+            tag_block: None,
             sentence_type: SentenceType::Parametric,
             talker: Talker::GPS,
             message: Message::RMC(RMCMessage {
@@ -268,6 +282,7 @@ mod sentence_tests {
     fn test_parse_glq() {
         let input = "$UPGLQ,RMC*2F\r\n";
         let expected_sentence = Sentence {
+            tag_block: None,
             sentence_type: SentenceType::Parametric,
             talker: Talker::MicroprocessorController,
             message: Message::GLQ(GLQMessage { msg_id: "RMC" }),
@@ -281,6 +296,7 @@ mod sentence_tests {
     fn test_parse_gnq() {
         let input = "$UPGNQ,RMC*2D\r\n";
         let expected_sentence = Sentence {
+            tag_block: None,
             sentence_type: SentenceType::Parametric,
             talker: Talker::MicroprocessorController,
             message: Message::GNQ(GNQMessage { msg_id: "RMC" }),
@@ -294,6 +310,7 @@ mod sentence_tests {
     fn test_parse_gpq() {
         let input = "$GPGPQ,RMC*21\r\n";
         let expected_sentence = Sentence {
+            tag_block: None,
             sentence_type: SentenceType::Parametric,
             talker: Talker::GPS,
             message: Message::GPQ(GPQMessage { msg_id: "RMC" }),
@@ -307,6 +324,7 @@ mod sentence_tests {
     fn test_parse_gbs() {
         let input = "$GPGBS,235458.00,1.4,1.3,3.1,03,,-21.4,3.8,1,0*5A\r\n";
         let expected_sentence = Sentence {
+            tag_block: None,
             sentence_type: SentenceType::Parametric,
             talker: Talker::GPS,
             message: Message::GBS(GBSMessage {
@@ -332,6 +350,7 @@ mod sentence_tests {
         let input =
             "$GNGNS,103600.01,5114.51176,N,00012.29380,W,ANNN,07,1.18,111.5,45.6,,,V*00\r\n";
         let expected_sentence = Sentence {
+            tag_block: None,
             sentence_type: SentenceType::Parametric,
             talker: Talker::GPSGLONASS,
             message: Message::GNS(GNSMessage {
@@ -340,7 +359,13 @@ mod sentence_tests {
                 ns: Some(NorthSouth::North),
                 lon: Some(Degree(0.12293799999999999)), // floats ¯\_(ツ)_/¯
                 ew: Some(EastWest::West),
-                pos_mode: vec![Fix::AutonomousGNSSFix, Fix::NoFix, Fix::NoFix, Fix::NoFix],
+                pos_mode: Vec::from_slice(&[
+                    Fix::AutonomousGNSSFix,
+                    Fix::NoFix,
+                    Fix::NoFix,
+                    Fix::NoFix,
+                ])
+                .unwrap(),
                 num_sv: Some(7),
                 hdop: Some(1.18),
                 alt: Some(Meter(111.5)),
@@ -359,6 +384,7 @@ mod sentence_tests {
     fn test_parse_grs() {
         let input = "$GNGRS,104148.00,1,2.6,2.2,-1.6,-1.1,-1.7,-1.5,5.8,1.7,,,,,1,1*52\r\n";
         let expected_sentence = Sentence {
+            tag_block: None,
             sentence_type: SentenceType::Parametric,
             talker: Talker::GPSGLONASS,
             message: Message::GRS(GRSMessage {
@@ -391,6 +417,7 @@ mod sentence_tests {
     fn test_parse_gst() {
         let input = "$GPGST,082356.00,1.8,,,,1.7,1.3,2.2*7E\r\n";
         let expected_sentence = Sentence {
+            tag_block: None,
             sentence_type: SentenceType::Parametric,
             talker: Talker::GPS,
             message: Message::GST(GSTMessage {
@@ -413,6 +440,7 @@ mod sentence_tests {
     fn test_parse_txt() {
         let input = "$GPTXT,01,01,02,ANTARIS ATR0620 HW 00000040*67\r\n";
         let expected_sentence = Sentence {
+            tag_block: None,
             sentence_type: SentenceType::Parametric,
             talker: Talker::GPS,
             message: Message::TXT(TXTMessage {
@@ -431,6 +459,7 @@ mod sentence_tests {
     fn test_parse_vlw() {
         let input = "$GPVLW,,N,,N,15.8,N,1.2,N*65\r\n";
         let expected_sentence = Sentence {
+            tag_block: None,
             sentence_type: SentenceType::Parametric,
             talker: Talker::GPS,
             message: Message::VLW(VLWMessage {
@@ -453,6 +482,7 @@ mod sentence_tests {
     fn test_parse_vtg() {
         let input = "$GPVTG,77.52,T,,M,0.004,N,0.008,K,A*06\r\n";
         let expected_sentence = Sentence {
+            tag_block: None,
             sentence_type: SentenceType::Parametric,
             talker: Talker::GPS,
             message: Message::VTG(VTGMessage {
@@ -471,6 +501,635 @@ mod sentence_tests {
         let expected_output = Ok(("", expected_sentence));
         assert_eq!(expected_output, parse_sentence(input));
     }
+
+    #[test]
+    fn test_parse_dbs() {
+        let input = "$SDDBS,,f,2.4,M,,F*07\r\n";
+        let expected_sentence = Sentence {
+            tag_block: None,
+            sentence_type: SentenceType::Parametric,
+            talker: Talker::SounderDepth,
+            message: Message::DBS(DBTMessage {
+                depth_feet: None,
+                depth_meters: Some(Meter(2.4)),
+                depth_fathoms: None,
+            }),
+        };
+
+        let expected_output = Ok(("", expected_sentence));
+        assert_eq!(expected_output, parse_sentence(input));
+    }
+
+    #[test]
+    fn test_parse_hdg() {
+        let input = "$HCHDG,101.1,,,7.1,W*3C\r\n";
+        let expected_sentence = Sentence {
+            tag_block: None,
+            sentence_type: SentenceType::Parametric,
+            talker: Talker::HeadingMagneticCompass,
+            message: Message::HDG(HDGMessage {
+                heading: Some(Degree(101.1)),
+                deviation: None,
+                deviation_ew: None,
+                variation: Some(Degree(7.1)),
+                variation_ew: Some(EastWest::West),
+            }),
+        };
+
+        let expected_output = Ok(("", expected_sentence));
+        assert_eq!(expected_output, parse_sentence(input));
+    }
+
+    #[test]
+    fn test_parse_hdt() {
+        let input = "$HEHDT,274.07,T*19\r\n";
+        let expected_sentence = Sentence {
+            tag_block: None,
+            sentence_type: SentenceType::Parametric,
+            talker: Talker::HeadingNorthSeekingGyro,
+            message: Message::HDT(HDTMessage {
+                heading: Some(Degree(274.07)),
+            }),
+        };
+
+        let expected_output = Ok(("", expected_sentence));
+        assert_eq!(expected_output, parse_sentence(input));
+    }
+
+    #[test]
+    fn test_parse_mwv() {
+        let input = "$WIMWV,45.5,T,10.4,M,A*27\r\n";
+        let expected_sentence = Sentence {
+            tag_block: None,
+            sentence_type: SentenceType::Parametric,
+            talker: Talker::WeatherInstruments,
+            message: Message::MWV(MWVMessage {
+                angle: Some(Degree(45.5)),
+                reference: WindReference::True,
+                speed: Some(10.4),
+                speed_unit: Some(SpeedOverGroundUnit::MetersPerSecond),
+                status: Status::DataValid,
+            }),
+        };
+
+        let expected_output = Ok(("", expected_sentence));
+        assert_eq!(expected_output, parse_sentence(input));
+    }
+
+    #[test]
+    fn test_parse_vwt() {
+        let input = "$IIVWT,30.,R,10.4,N,5.35,M,19.3,K*71\r\n";
+        let expected_sentence = Sentence {
+            tag_block: None,
+            sentence_type: SentenceType::Parametric,
+            talker: Talker::IntegratedInstrumentation,
+            message: Message::VWT(VWRMessage {
+                angle: Some(Degree(30.)),
+                side: Some(LeftRight::Right),
+                speed: Some(Knot(10.4)),
+                speed_ms: Some(MeterPerSecond(5.35)),
+                speed_kmh: Some(19.3),
+            }),
+        };
+
+        let expected_output = Ok(("", expected_sentence));
+        assert_eq!(expected_output, parse_sentence(input));
+    }
+
+    #[test]
+    fn test_parse_vhw() {
+        let input = "$VWVHW,245.1,T,245.1,M,6.3,N,11.7,K*66\r\n";
+        let expected_sentence = Sentence {
+            tag_block: None,
+            sentence_type: SentenceType::Parametric,
+            talker: Talker::VelocitySensorSpeedLogWaterMechanical,
+            message: Message::VHW(VHWMessage {
+                heading_true: Some(Degree(245.1)),
+                heading_magnetic: Some(Degree(245.1)),
+                speed: Some(Knot(6.3)),
+                speed_kmh: Some(11.7),
+            }),
+        };
+
+        let expected_output = Ok(("", expected_sentence));
+        assert_eq!(expected_output, parse_sentence(input));
+    }
+
+    #[test]
+    fn test_parse_xdr() {
+        let input = "$IIXDR,A,-1.5,D,PITCH,A,3.2,D,ROLL,U,12.6,V,BATT,H,65,P,*3D\r\n";
+        let (_, sentence) = parse_sentence(input).unwrap();
+        assert_eq!(Talker::IntegratedInstrumentation, sentence.talker);
+        if let Message::XDR(xdr) = sentence.message {
+            let measurements: std::vec::Vec<_> = xdr.measurements().collect();
+            assert_eq!(4, measurements.len());
+            assert_eq!("ROLL", measurements[1].name);
+            assert_eq!(TransducerType::Humidity, measurements[3].transducer_type);
+            assert_eq!("", measurements[3].name);
+        } else {
+            panic!("unexpected message {:?}", sentence.message);
+        }
+
+        let input = "$IIXDR,P,1.02481,B,ENGINE#0_OIL_PRESSURE*2E\r\n";
+        let (_, sentence) = parse_sentence(input).unwrap();
+        if let Message::XDR(xdr) = sentence.message {
+            let measurement = xdr.measurements().next().unwrap();
+            assert_eq!("ENGINE#0_OIL_PRESSURE", measurement.name);
+        } else {
+            panic!("unexpected message {:?}", sentence.message);
+        }
+    }
+
+    #[test]
+    fn test_parse_rot() {
+        let input = "$TIROT,-12.5,A*20\r\n";
+        let (_, sentence) = parse_sentence(input).unwrap();
+        assert_eq!(Talker::TurnRateIndicator, sentence.talker);
+        assert_eq!(
+            Message::ROT(ROTMessage {
+                rate: Some(DegreePerMinute(-12.5)),
+                status: Status::DataValid,
+            }),
+            sentence.message
+        );
+    }
+
+    #[test]
+    fn test_parse_rsa() {
+        let input = "$AGRSA,-4.5,A,,V*53\r\n";
+        let (_, sentence) = parse_sentence(input).unwrap();
+        assert_eq!(
+            Message::RSA(RSAMessage {
+                starboard: Some(Degree(-4.5)),
+                starboard_status: Status::DataValid,
+                port: None,
+                port_status: Some(Status::DataInvalid),
+            }),
+            sentence.message
+        );
+    }
+
+    #[test]
+    fn test_parse_apb() {
+        let input = "$GPAPB,A,A,0.1,L,N,A,V,45.2,T,WP2,44.8,T,43.5,T,D*60\r\n";
+        let (_, sentence) = parse_sentence(input).unwrap();
+        if let Message::APB(apb) = sentence.message {
+            assert_eq!(Some(NauticalMile(0.1)), apb.xte);
+            assert_eq!(Some(LeftRight::Left), apb.steer);
+            assert_eq!("WP2", apb.destination);
+            assert_eq!(Some(Degree(43.5)), apb.heading_to_steer);
+            assert_eq!(
+                Some(CourseOverGroundUnit::DegreesTrue),
+                apb.heading_to_steer_unit
+            );
+        } else {
+            panic!("unexpected message {:?}", sentence.message);
+        }
+    }
+
+    #[test]
+    fn test_parse_bwr() {
+        let input =
+            "$GPBWR,220516.00,5130.02000,N,00046.34000,W,213.7,T,217.9,M,4.6,N,EGLM,A*4A\r\n";
+        let (_, sentence) = parse_sentence(input).unwrap();
+        if let Message::BWR(bwr) = sentence.message {
+            assert_eq!(Some(NauticalMile(4.6)), bwr.distance);
+            assert_eq!("EGLM", bwr.waypoint);
+        } else {
+            panic!("unexpected message {:?}", sentence.message);
+        }
+    }
+
+    #[test]
+    fn test_parse_rte() {
+        let input = "$GPRTE,2,1,c,0,PBRCPK,PBRTO,PTELGR,PPLAND*58\r\n";
+        let (_, sentence) = parse_sentence(input).unwrap();
+        if let Message::RTE(rte) = sentence.message {
+            assert_eq!(2, rte.total_msgs);
+            assert_eq!(RouteMode::Complete, rte.mode);
+            assert_eq!(Some("PPLAND"), rte.waypoints().last());
+        } else {
+            panic!("unexpected message {:?}", sentence.message);
+        }
+    }
+
+    #[test]
+    fn test_parse_ttm() {
+        let input = "$RATTM,01,2.6,215.3,T,11.2,45.8,T,0.9,12.5,N,TGT01,T,,100032.00,A*75\r\n";
+        let (_, sentence) = parse_sentence(input).unwrap();
+        assert_eq!(Talker::RADAROrARPA, sentence.talker);
+        if let Message::TTM(ttm) = sentence.message {
+            assert_eq!(Some(1), ttm.target);
+            assert_eq!(Some(NauticalMile(0.9)), ttm.cpa_nautical_miles());
+            assert_eq!("TGT01", ttm.name);
+            assert_eq!(Some(TargetAcquisition::Automatic), ttm.acquisition);
+        } else {
+            panic!("unexpected message {:?}", sentence.message);
+        }
+    }
+
+    #[test]
+    fn test_parse_osd() {
+        let input = "$RAOSD,35.5,A,36,P,12.3,W,181.2,0.5,N*66\r\n";
+        let (_, sentence) = parse_sentence(input).unwrap();
+        if let Message::OSD(osd) = sentence.message {
+            assert_eq!(Some(Degree(35.5)), osd.heading);
+            assert_eq!(Some(Knot(12.3)), osd.speed_knots());
+        } else {
+            panic!("unexpected message {:?}", sentence.message);
+        }
+    }
+
+    #[test]
+    fn test_parse_unknown_message() {
+        let input =
+            "$GPALM,1,1,15,1159,00,441D,4E,16BE,FD5E,A10C9F,4A2DA4,686E81,58CBE1,0A4,001*77\r\n";
+        let expected_sentence = Sentence {
+            tag_block: None,
+            sentence_type: SentenceType::Parametric,
+            talker: Talker::GPS,
+            message: Message::Unknown {
+                id: "ALM",
+                fields: "1,1,15,1159,00,441D,4E,16BE,FD5E,A10C9F,4A2DA4,686E81,58CBE1,0A4,001",
+            },
+        };
+
+        let expected_output = Ok(("", expected_sentence));
+        assert_eq!(expected_output, parse_sentence(input));
+    }
+
+    #[test]
+    fn test_parse_unknown_message_without_fields() {
+        let input = "$GPXYZ*4C\r\n";
+        let expected_sentence = Sentence {
+            tag_block: None,
+            sentence_type: SentenceType::Parametric,
+            talker: Talker::GPS,
+            message: Message::Unknown {
+                id: "XYZ",
+                fields: "",
+            },
+        };
+
+        let expected_output = Ok(("", expected_sentence));
+        assert_eq!(expected_output, parse_sentence(input));
+    }
+
+    #[test]
+    fn test_parse_other_talker() {
+        let input = "$XXGBQ,RMC*24\r\n";
+        let expected_sentence = Sentence {
+            tag_block: None,
+            sentence_type: SentenceType::Parametric,
+            talker: Talker::Other('X', 'X'),
+            message: Message::GBQ(GBQMessage { msg_id: "RMC" }),
+        };
+
+        let expected_output = Ok(("", expected_sentence));
+        assert_eq!(expected_output, parse_sentence(input));
+    }
+
+    #[test]
+    fn test_parse_vdm() {
+        let input = "!AIVDM,1,1,,B,177KQJ5000G?tO`K>RA1wUbN0TKH,0*5C\r\n";
+        let expected_sentence = Sentence {
+            tag_block: None,
+            sentence_type: SentenceType::Encapsulation,
+            talker: Talker::MobileAISStation,
+            message: Message::VDM(VDMMessage {
+                fragment_count: 1,
+                fragment_number: 1,
+                message_id: None,
+                channel: Some(AISChannel::B),
+                payload: "177KQJ5000G?tO`K>RA1wUbN0TKH",
+                fill_bits: 0,
+            }),
+        };
+
+        let expected_output = Ok(("", expected_sentence));
+        assert_eq!(expected_output, parse_sentence(input));
+    }
+
+    #[test]
+    fn test_parse_pubx04() {
+        let input = "$PUBX,04,073731.00,091202,113851.00,1196,15D,1930035,-2660.664,43,*5D\r\n";
+        let expected_sentence = Sentence {
+            tag_block: None,
+            sentence_type: SentenceType::Parametric,
+            talker: Talker::Proprietary,
+            message: Message::Proprietary {
+                manufacturer: "UBX",
+                body: ProprietaryBody::PUBX04(PUBX04Message {
+                    time: Some(NaiveTime::from_hms_opt(7, 37, 31).unwrap()),
+                    date: Some(NaiveDate::from_ymd_opt(2002, 12, 9).unwrap()),
+                    utc_tow: Some(Second(113851.)),
+                    utc_wk: Some(1196),
+                    leap_sec: Some(15),
+                    leap_sec_default: true,
+                    clk_bias: Some(1930035.),
+                    clk_drift: Some(-2660.664),
+                    tp_gran: Some(43.),
+                }),
+            },
+        };
+
+        let expected_output = Ok(("", expected_sentence));
+        assert_eq!(expected_output, parse_sentence(input));
+    }
+
+    #[test]
+    fn test_parse_proprietary_without_decoder() {
+        let input = "$PUBX,40,GLL,1,0,0,0,0,0*5D\r\n";
+        let expected_sentence = Sentence {
+            tag_block: None,
+            sentence_type: SentenceType::Parametric,
+            talker: Talker::Proprietary,
+            message: Message::Proprietary {
+                manufacturer: "UBX",
+                body: ProprietaryBody::Raw(",40,GLL,1,0,0,0,0,0"),
+            },
+        };
+
+        let expected_output = Ok(("", expected_sentence));
+        assert_eq!(expected_output, parse_sentence(input));
+    }
+
+    #[test]
+    fn test_parse_proprietary() {
+        let input = "$PMTK001,604,3*32\r\n";
+        let expected_sentence = Sentence {
+            tag_block: None,
+            sentence_type: SentenceType::Parametric,
+            talker: Talker::Proprietary,
+            message: Message::Proprietary {
+                manufacturer: "MTK",
+                body: ProprietaryBody::Raw("001,604,3"),
+            },
+        };
+
+        let expected_output = Ok(("", expected_sentence));
+        assert_eq!(expected_output, parse_sentence(input));
+    }
+}
+
+#[cfg(test)]
+mod sentence_error_tests {
+    use super::*;
+    use crate::error::{error_string, Error};
+
+    #[test]
+    fn test_parse_invalid_start_delimiter() {
+        let input = "GPGBQ,RMC*33\r\n";
+        assert_eq!(Err(Error::InvalidStartDelimiter), parse_sentence(input));
+    }
+
+    #[test]
+    fn test_parse_missing_checksum() {
+        let input = "$GPGBQ,RMC\r\n";
+        assert_eq!(Err(Error::MissingChecksum), parse_sentence(input));
+    }
+
+    #[test]
+    fn test_parse_checksum_mismatch() {
+        let input = "$GPGBQ,RMC*34\r\n";
+        let expected = Error::ChecksumMismatch {
+            expected: 0x34,
+            computed: 0x33,
+        };
+        assert_eq!(Err(expected), parse_sentence(input));
+    }
+
+    #[test]
+    fn test_parse_unknown_talker() {
+        let input = "$xxGGA,092725.00,4717.11399,N,00833.91590,E,1,08,1.01,499.6,M,48.0,M,,*4C\r\n";
+        let expected = Error::UnknownTalker(error_string("xx"));
+        assert_eq!(Err(expected), parse_sentence(input));
+    }
+
+    #[test]
+    fn test_parse_invalid_manufacturer() {
+        let input = "$Pubx,00*13\r\n";
+        let expected = Error::UnknownTalker(error_string("Pubx"));
+        assert_eq!(Err(expected), parse_sentence(input));
+    }
+
+    #[test]
+    fn test_parse_unknown_message_type() {
+        let input = "$GPhdx,1.0,T*18\r\n";
+        let expected = Error::UnknownMessageType(error_string("hdx"));
+        assert_eq!(Err(expected), parse_sentence(input));
+    }
+
+    #[test]
+    fn test_parse_invalid_field() {
+        let input =
+            "$GPGGA,092725.00,47x17.11399,N,00833.91590,E,1,08,1.01,499.6,M,48.0,M,,*23\r\n";
+        let expected = Error::InvalidField {
+            message: "GGA",
+            field_index: 1,
+            field_name: "lat",
+            raw: error_string("47x17.11399"),
+        };
+        assert_eq!(Err(expected), parse_sentence(input));
+    }
+
+    #[test]
+    fn test_parse_invalid_xdr_unit() {
+        let input = "$IIXDR,C,19.5,C,AIR,C,12.1,CC,WATER*0D\r\n";
+        let expected = Error::InvalidField {
+            message: "XDR",
+            field_index: 6,
+            field_name: "measurements.unit",
+            raw: error_string("CC"),
+        };
+        assert_eq!(Err(expected), parse_sentence(input));
+    }
+
+    #[test]
+    fn test_parse_invalid_proprietary_field() {
+        let input = "$PUBX,04,073731.00,091202,113851.00,1196,15X,1930035,-2660.664,43,*41\r\n";
+        let expected = Error::InvalidField {
+            message: "PUBX04",
+            field_index: 4,
+            field_name: "leap_sec",
+            raw: error_string("15X"),
+        };
+        assert_eq!(Err(expected), parse_sentence(input));
+    }
+
+    #[test]
+    fn test_parse_invalid_satellite_status() {
+        let input = "$PUBX,03,2,23,-,,,45,010,08,U,067,31,42,02X*00\r\n";
+        let expected = Error::InvalidField {
+            message: "PUBX03",
+            field_index: 12,
+            field_name: "satellites.lck",
+            raw: error_string("02X"),
+        };
+        assert_eq!(Err(expected), parse_sentence(input));
+    }
+}
+
+#[cfg(test)]
+mod tag_block_tests {
+    use super::*;
+    use crate::error::{error_string, Error};
+    use crate::fields::identity::Talker;
+
+    #[test]
+    fn test_parse_tag_block() {
+        let input = "\\s:GP0001,c:1577096133*20\\$GPGGA,092725.00,4717.11399,N,00833.91590,E,1,08,1.01,499.6,M,48.0,M,,*5B\r\n";
+        let expected = TagBlock {
+            source: Some("GP0001"),
+            time: Some(1577096133),
+            ..TagBlock::default()
+        };
+        let (_, sentence) = parse_sentence(input).unwrap();
+        assert_eq!(Some(expected), sentence.tag_block);
+        assert_eq!(Talker::GPS, sentence.talker);
+    }
+
+    #[test]
+    fn test_parse_tag_block_group() {
+        let input = "\\g:1-2-73874,n:157036,s:r003669945,c:1241544035*4A\\$GPGGA,092725.00,4717.11399,N,00833.91590,E,1,08,1.01,499.6,M,48.0,M,,*5B\r\n";
+        let expected = TagBlock {
+            source: Some("r003669945"),
+            time: Some(1241544035),
+            line_count: Some(157036),
+            group: Some(TagBlockGroup {
+                sentence: 1,
+                total: 2,
+                id: 73874,
+            }),
+            ..TagBlock::default()
+        };
+        let (_, sentence) = parse_sentence(input).unwrap();
+        assert_eq!(Some(expected), sentence.tag_block);
+    }
+
+    #[test]
+    fn test_parse_tag_block_checksum_mismatch() {
+        let input = "\\s:GP0001,c:1577096133*1A\\$UPGBQ,RMC*21\r\n";
+        let expected = Error::ChecksumMismatch {
+            expected: 0x1A,
+            computed: 0x20,
+        };
+        assert_eq!(Err(expected), parse_sentence(input));
+    }
+
+    #[test]
+    fn test_parse_tag_block_unknown_parameter() {
+        let input = "\\s:GP0001,c:1577096133,x:1*7F\\$UPGBQ,RMC*21\r\n";
+        let expected = Error::InvalidTagBlock(error_string("x:1"));
+        assert_eq!(Err(expected), parse_sentence(input));
+    }
+
+    #[test]
+    fn test_parse_tag_block_invalid_time() {
+        let input = "\\s:GP0001,c:abc*4A\\$UPGBQ,RMC*21\r\n";
+        let expected = Error::InvalidTagBlock(error_string("c:abc"));
+        assert_eq!(Err(expected), parse_sentence(input));
+    }
+
+    #[test]
+    fn test_parse_unterminated_tag_block() {
+        let input = "\\s:GP0001,c:1577096133*20$UPGBQ,RMC*21\r\n";
+        assert_eq!(Err(Error::Framing), parse_sentence(input));
+    }
+}
+
+#[cfg(test)]
+mod sentence_writer_tests {
+    use super::*;
+
+    #[test]
+    fn test_write_sentence() {
+        let input = "$GPGGA,092725.00,4717.11399,N,00833.91590,E,1,08,1.01,499.6,M,48.0,M,,*5B\r\n";
+        let expected =
+            "$GPGGA,092725.00,4717.11399,N,00833.91590,E,1,08,1.01,499.6,M,48,M,,*45\r\n";
+        let (_, sentence) = parse_sentence(input).unwrap();
+        assert_eq!(expected, sentence.to_string());
+    }
+
+    #[test]
+    fn test_write_parsed_sentences() {
+        let inputs = [
+            "$GPDTM,999,,0.08,N,0.07,E,-47.7,W84*1B\r\n",
+            "$UPGBQ,RMC*21\r\n",
+            "$GPGBS,235458.00,1.4,1.3,3.1,03,,-21.4,3.8,1,0*5A\r\n",
+            "$GPGGA,092725.00,4717.11399,N,00833.91590,E,1,08,1.01,499.6,M,48.0,M,,*5B\r\n",
+            "$GPGLL,4717.11364,N,00833.91565,E,092321.00,A,A*60\r\n",
+            "$UPGLQ,RMC*2F\r\n",
+            "$UPGNQ,RMC*2D\r\n",
+            "$GNGNS,103600.01,5114.51176,N,00012.29380,W,ANNN,07,1.18,111.5,45.6,,,V*00\r\n",
+            "$GPGPQ,RMC*21\r\n",
+            "$GNGRS,104148.00,1,2.6,2.2,-1.6,-1.1,-1.7,-1.5,5.8,1.7,,,,,1,1*52\r\n",
+            "$GNGSA,A,3,80,71,73,79,69,,,,,,,,1.83,1.09,1.47*17\r\n",
+            "$GPGST,082356.00,1.8,,,,1.7,1.3,2.2*7E\r\n",
+            "$GPGSV,3,1,11,03,03,111,00,04,15,270,00,06,01,010,00,13,06,292,00*74\r\n",
+            "$GPRMC,083559.00,A,4717.11437,N,00833.91522,E,0.004,77.52,091202,,,A,V*2D\r\n",
+            "$GPTXT,01,01,02,ANTARIS ATR0620 HW 00000040*67\r\n",
+            "$GPVLW,,N,,N,15.8,N,1.2,N*65\r\n",
+            "$GPVTG,77.52,T,,M,0.004,N,0.008,K,A*06\r\n",
+            "$GPZDA,082710.00,16,09,2002,00,00*64\r\n",
+            "$SDDBK,25.6,f,7.8,M,4.3,F*20\r\n",
+            "$SDDBS,,f,2.4,M,,F*07\r\n",
+            "$SDDBT,7.8,f,2.4,M,1.3,F*0D\r\n",
+            "$SDDPT,2.4,-0.5,100*64\r\n",
+            "$INDPT,2.3,0.0*46\r\n",
+            "$HCHDG,98.3,0.5,E,12.6,W*52\r\n",
+            "$HCHDG,101.1,,,7.1,W*3C\r\n",
+            "$HCHDM,172.5,M*28\r\n",
+            "$HEHDT,274.07,T*19\r\n",
+            "$INTHS,77.52,E*24\r\n",
+            "$YXMTW,17.9,C*1D\r\n",
+            "$VWVHW,245.1,T,245.1,M,6.3,N,11.7,K*66\r\n",
+            "$VDVBW,12.3,-0.07,A,12.44,0.12,A*4B\r\n",
+            "$VDVBW,12.3,-0.07,A,,,V,0.03,A,,V*66\r\n",
+            "$YXXDR,C,19.52,C,TempAir,P,1.02481,B,Barometer*7F\r\n",
+            "$IIXDR,A,-1.5,D,PITCH,A,3.2,D,ROLL,U,12.6,V,BATT,H,65,P,*3D\r\n",
+            "$TIROT,-12.5,A*20\r\n",
+            "$AGRSA,-4.5,A,,V*53\r\n",
+            "$AGRSA,3.2,A,3.5,A*41\r\n",
+            "$GPAPB,A,A,0.1,L,N,A,V,45.2,T,WP2,44.8,T,43.5,T,D*60\r\n",
+            "$GPRMB,A,0.66,L,003,004,4917.24000,N,12309.57000,W,1.3,52.5,0.5,V,A*7D\r\n",
+            "$GPXTE,A,A,0.67,L,N,A*02\r\n",
+            "$APXTE,V,V,,,N*3A\r\n",
+            "$GPBWC,220516.00,5130.02000,N,00046.34000,W,213.8,T,218.1,M,4.6,N,EGLM,A*53\r\n",
+            "$GPBWR,220516.00,5130.02000,N,00046.34000,W,213.7,T,217.9,M,4.6,N,EGLM,A*4A\r\n",
+            "$GPBOD,99.3,T,105.6,M,POINTB,POINTA*75\r\n",
+            "$GPWPL,4917.16000,N,12310.64000,W,003*65\r\n",
+            "$GPRTE,2,1,c,0,PBRCPK,PBRTO,PTELGR,PPLAND*58\r\n",
+            "$GPRTE,1,1,w,HOME*2C\r\n",
+            "$RATTM,01,2.6,215.3,T,11.2,45.8,T,0.9,12.5,N,TGT01,T,,100032.00,A*75\r\n",
+            "$RATTM,12,5,30,R,0,,T,5,-3.5,K,,Q,R*09\r\n",
+            "$RATLL,01,4917.16000,N,12310.64000,W,TGT01,100032.00,T,*70\r\n",
+            "$RAOSD,35.5,A,36,P,12.3,W,181.2,0.5,N*66\r\n",
+            "$WIMWV,214.8,R,0.1,K,A*28\r\n",
+            "$WIMWV,45.5,T,10.4,M,A*27\r\n",
+            "$WIMWD,46.4,T,47.5,M,12.3,N,6.3,M*6F\r\n",
+            "$IIVWR,148.,L,02.4,N,01.2,M,04.4,K*5F\r\n",
+            "$IIVWT,30.,R,10.4,N,5.35,M,19.3,K*71\r\n",
+            "$GPALM,1,1,15,1159,00,441D,4E,16BE,FD5E,A10C9F,4A2DA4,686E81,58CBE1,0A4,001*77\r\n",
+            "$GPXYZ*4C\r\n",
+            "$XXGBQ,RMC*24\r\n",
+            "$PMTK001,604,3*32\r\n",
+            "$PGRME,15.0,M,45.0,M,25.0,M*1C\r\n",
+            "$PUBX,00,081350.00,4717.113210,N,00833.915187,E,546.589,G3,2.1,2.3,0.007,77.52,0.007,,0.92,1.19,0.77,9,0,0*5C\r\n",
+            "$PUBX,03,11,23,-,,,45,010,29,-,,,46,013,07,-,,,42,015,08,U,067,31,42,025,10,U,195,33,46,026,18,U,326,08,39,026,17,-,,,32,015,26,U,306,66,48,025,27,U,073,10,36,026,28,U,089,61,46,024,15,-,,,39,014*0D\r\n",
+            "$PUBX,04,073731.00,091202,113851.00,1196,15D,1930035,-2660.664,43,*5D\r\n",
+            "$PUBX,40,GLL,1,0,0,0,0,0*5D\r\n",
+            "!AIVDM,2,1,3,B,55P5TL01VIaAL@7WKO@mBplU@<PDhh000000001S;AJ::4A80?4i@E53,0*3E\r\n",
+            "!AIVDM,2,2,3,B,1@0000000000000,2*55\r\n",
+            "!AIVDO,1,1,,,B5NJ;PP005l4ot5Isbl03wsUkP06,0*35\r\n",
+            "\\s:r003669945,c:1241544035,n:157036,g:1-2-73874*4A\\$UPGBQ,RMC*21\r\n",
+        ];
+        for input in inputs.iter() {
+            let (_, sentence) = parse_sentence(input).unwrap();
+            let output = sentence.to_string();
+            assert_eq!(Ok(("", sentence)), parse_sentence(&output));
+        }
+    }
 }
 
 #[cfg(test)]
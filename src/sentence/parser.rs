@@ -1,18 +1,24 @@
 use super::structs::*;
+use crate::error::{error_string, Error};
 use crate::fields::identity::*;
 use crate::fields::parameter::SentenceType;
 use crate::messages::*;
-use nom::bytes::complete::take_until;
-use nom::character::complete::crlf;
-use nom::sequence::tuple;
 use nom::IResult;
 
 pub(crate) fn parse_message_type(input: &str) -> IResult<&str, MessageType> {
     if input.len() < 4 {
-        return Err(nom::Err::Failure((input, nom::error::ErrorKind::Complete)));
+        return parse_unknown_message_type(input);
     }
     let (maybe_message_type, remaining) = input.split_at(4);
     match maybe_message_type {
+        "APB," => Ok((remaining, MessageType::APB)),
+        "BOD," => Ok((remaining, MessageType::BOD)),
+        "BWC," => Ok((remaining, MessageType::BWC)),
+        "BWR," => Ok((remaining, MessageType::BWR)),
+        "DBK," => Ok((remaining, MessageType::DBK)),
+        "DBS," => Ok((remaining, MessageType::DBS)),
+        "DBT," => Ok((remaining, MessageType::DBT)),
+        "DPT," => Ok((remaining, MessageType::DPT)),
         "DTM," => Ok((remaining, MessageType::DTM)),
         "GBQ," => Ok((remaining, MessageType::GBQ)),
         "GBS," => Ok((remaining, MessageType::GBS)),
@@ -26,15 +32,59 @@ pub(crate) fn parse_message_type(input: &str) -> IResult<&str, MessageType> {
         "GSA," => Ok((remaining, MessageType::GSA)),
         "GST," => Ok((remaining, MessageType::GST)),
         "GSV," => Ok((remaining, MessageType::GSV)),
+        "HDG," => Ok((remaining, MessageType::HDG)),
+        "HDM," => Ok((remaining, MessageType::HDM)),
+        "HDT," => Ok((remaining, MessageType::HDT)),
+        "MTW," => Ok((remaining, MessageType::MTW)),
+        "MWD," => Ok((remaining, MessageType::MWD)),
+        "MWV," => Ok((remaining, MessageType::MWV)),
+        "OSD," => Ok((remaining, MessageType::OSD)),
+        "RMB," => Ok((remaining, MessageType::RMB)),
         "RMC," => Ok((remaining, MessageType::RMC)),
+        "ROT," => Ok((remaining, MessageType::ROT)),
+        "RSA," => Ok((remaining, MessageType::RSA)),
+        "RTE," => Ok((remaining, MessageType::RTE)),
+        "THS," => Ok((remaining, MessageType::THS)),
+        "TLL," => Ok((remaining, MessageType::TLL)),
+        "TTM," => Ok((remaining, MessageType::TTM)),
         "TXT," => Ok((remaining, MessageType::TXT)),
+        "VBW," => Ok((remaining, MessageType::VBW)),
+        "VDM," => Ok((remaining, MessageType::VDM)),
+        "VDO," => Ok((remaining, MessageType::VDO)),
+        "VHW," => Ok((remaining, MessageType::VHW)),
         "VLW," => Ok((remaining, MessageType::VLW)),
         "VTG," => Ok((remaining, MessageType::VTG)),
+        "VWR," => Ok((remaining, MessageType::VWR)),
+        "VWT," => Ok((remaining, MessageType::VWT)),
+        "WPL," => Ok((remaining, MessageType::WPL)),
+        "XDR," => Ok((remaining, MessageType::XDR)),
+        "XTE," => Ok((remaining, MessageType::XTE)),
         "ZDA," => Ok((remaining, MessageType::ZDA)),
-        _ => Err(nom::Err::Failure((input, nom::error::ErrorKind::OneOf))),
+        _ => parse_unknown_message_type(input),
     }
 }
 
+/// Accepts any well formed message type, so sentences the crate doesn't know about
+/// can still be passed through as `Message::Unknown`
+fn parse_unknown_message_type(input: &str) -> IResult<&str, MessageType> {
+    let end = input.find(',').unwrap_or(input.len());
+    let id = &input[..end];
+    if id.len() != 3
+        || !id
+            .chars()
+            .all(|c| c.is_ascii_uppercase() || c.is_ascii_digit())
+    {
+        return Err(nom::Err::Failure((input, nom::error::ErrorKind::OneOf)));
+    }
+    // Index subscription is safe because ',' is a single byte character
+    let remaining = if end < input.len() {
+        &input[end + 1..]
+    } else {
+        &input[end..]
+    };
+    Ok((remaining, MessageType::Unknown))
+}
+
 pub fn parse_sentence_type(input: &str) -> IResult<&str, SentenceType> {
     // Array slicing is safe here because nth(0) is Some(_)
     match input.chars().nth(0) {
@@ -45,10 +95,132 @@ pub fn parse_sentence_type(input: &str) -> IResult<&str, SentenceType> {
     }
 }
 
-pub fn parse_sentence(input: &str) -> IResult<&str, Sentence> {
-    let (remaining, sentence_type) = parse_sentence_type(input)?;
-    let (data_buffer, (talker, message_type)) = get_headers_if_sentence_valid(remaining)?;
+pub fn parse_sentence(input: &str) -> Result<(&str, Sentence<'_>), Error> {
+    let (after_tag_block, tag_block) = parse_tag_block(input)?;
+    let (remaining, sentence_type) =
+        parse_sentence_type(after_tag_block).map_err(|_| Error::InvalidStartDelimiter)?;
+    let (data_buffer, (header, talker, message_type)) = get_headers_if_sentence_valid(remaining)?;
+
+    let (remaining_data, message) = parse_message(&message_type, header, data_buffer)
+        .map_err(|err| invalid_field(&message_type, data_buffer, err_input(&err)))?;
 
+    if remaining_data.is_empty() {
+        Ok((
+            remaining_data,
+            Sentence {
+                tag_block,
+                sentence_type,
+                talker,
+                message,
+            },
+        ))
+    } else {
+        Err(invalid_field(&message_type, data_buffer, remaining_data))
+    }
+}
+
+/// Validates a sentence without decoding its fields: the message is a `Message::Unknown`,
+/// or a raw `Message::Proprietary` for `$P` sentences
+#[cfg(feature = "std")]
+pub(crate) fn parse_raw_sentence(input: &str) -> Result<Sentence<'_>, Error> {
+    let (after_tag_block, tag_block) = parse_tag_block(input)?;
+    let (remaining, sentence_type) =
+        parse_sentence_type(after_tag_block).map_err(|_| Error::InvalidStartDelimiter)?;
+    let (data_buffer, (header, talker, _)) = get_headers_if_sentence_valid(remaining)?;
+
+    let message = if talker == Talker::Proprietary {
+        // The checksum has been validated, so the '*' is there
+        let data = &remaining[..remaining.find('*').unwrap_or(remaining.len())];
+        Message::Proprietary {
+            manufacturer: &header[1..],
+            // Index subscription is safe because the header is made of 4 ASCII characters
+            body: ProprietaryBody::Raw(&data[header.len()..]),
+        }
+    } else {
+        Message::Unknown {
+            // The message type is the last 3 characters of the header
+            id: &header[header.len() - 3..],
+            fields: data_buffer,
+        }
+    };
+    Ok(Sentence {
+        tag_block,
+        sentence_type,
+        talker,
+        message,
+    })
+}
+
+/// Parses the tag block preceding the sentence, if any
+fn parse_tag_block(input: &str) -> Result<(&str, Option<TagBlock<'_>>), Error> {
+    let after_start = match input.strip_prefix('\\') {
+        Some(after_start) => after_start,
+        None => return Ok((input, None)),
+    };
+    let end = after_start.find('\\').ok_or(Error::Framing)?;
+    // Index subscription is safe because '\\' is a single byte character
+    let (block, remaining) = (&after_start[..end], &after_start[end + 1..]);
+
+    let star = block.find('*').ok_or(Error::MissingChecksum)?;
+    let (parameters, after_parameters) = block.split_at(star);
+    match parse_checksum(&after_parameters[1..])? {
+        (checksum, "") => {
+            let computed = compute_checksum(parameters);
+            if computed != checksum {
+                return Err(Error::ChecksumMismatch {
+                    expected: checksum,
+                    computed,
+                });
+            }
+        }
+        _ => return Err(Error::MissingChecksum),
+    }
+
+    let mut tag_block = TagBlock::default();
+    for parameter in parameters.split(',') {
+        let invalid = || Error::InvalidTagBlock(error_string(parameter));
+        let mut splitted = parameter.splitn(2, ':');
+        let (code, value) = match (splitted.next(), splitted.next()) {
+            (Some(code), Some(value)) => (code, value),
+            _ => return Err(invalid()),
+        };
+        match code {
+            "s" => tag_block.source = Some(value),
+            "d" => tag_block.destination = Some(value),
+            "c" => tag_block.time = Some(value.parse().map_err(|_| invalid())?),
+            "n" => tag_block.line_count = Some(value.parse().map_err(|_| invalid())?),
+            "g" => tag_block.group = Some(parse_tag_block_group(value).ok_or_else(invalid)?),
+            "r" => tag_block.relative_time = Some(value.parse().map_err(|_| invalid())?),
+            "t" => tag_block.text = Some(value),
+            _ => return Err(invalid()),
+        }
+    }
+    Ok((remaining, Some(tag_block)))
+}
+
+/// Parses a grouping parameter, such as `1-2-73874`
+fn parse_tag_block_group(input: &str) -> Option<TagBlockGroup> {
+    let mut splitted = input.split('-');
+    match (
+        splitted.next(),
+        splitted.next(),
+        splitted.next(),
+        splitted.next(),
+    ) {
+        (Some(sentence), Some(total), Some(id), None) => Some(TagBlockGroup {
+            sentence: sentence.parse().ok()?,
+            total: total.parse().ok()?,
+            id: id.parse().ok()?,
+        }),
+        _ => None,
+    }
+}
+
+fn parse_message<'a>(
+    message_type: &MessageType,
+    header: &'a str,
+    data_buffer: &'a str,
+) -> IResult<&'a str, Message<'a>> {
     let (remaining_data, message) = match message_type {
         MessageType::DTM => {
             let (remaining, data) = parse_dtm(data_buffer)?;
@@ -122,55 +294,290 @@ pub fn parse_sentence(input: &str) -> IResult<&str, Sentence> {
             let (remaining, data) = parse_vtg(data_buffer)?;
             (remaining, Message::VTG(data))
         }
+        MessageType::VDM => {
+            let (remaining, data) = parse_vdm(data_buffer)?;
+            (remaining, Message::VDM(data))
+        }
+        MessageType::VDO => {
+            let (remaining, data) = parse_vdm(data_buffer)?;
+            (remaining, Message::VDO(data))
+        }
+        MessageType::HDG => {
+            let (remaining, data) = parse_hdg(data_buffer)?;
+            (remaining, Message::HDG(data))
+        }
+        MessageType::HDM => {
+            let (remaining, data) = parse_hdm(data_buffer)?;
+            (remaining, Message::HDM(data))
+        }
+        MessageType::HDT => {
+            let (remaining, data) = parse_hdt(data_buffer)?;
+            (remaining, Message::HDT(data))
+        }
+        MessageType::THS => {
+            let (remaining, data) = parse_ths(data_buffer)?;
+            (remaining, Message::THS(data))
+        }
+        MessageType::DBT => {
+            let (remaining, data) = parse_dbt(data_buffer)?;
+            (remaining, Message::DBT(data))
+        }
+        MessageType::DPT => {
+            let (remaining, data) = parse_dpt(data_buffer)?;
+            (remaining, Message::DPT(data))
+        }
+        MessageType::DBK => {
+            let (remaining, data) = parse_dbt(data_buffer)?;
+            (remaining, Message::DBK(data))
+        }
+        MessageType::DBS => {
+            let (remaining, data) = parse_dbt(data_buffer)?;
+            (remaining, Message::DBS(data))
+        }
+        MessageType::MWD => {
+            let (remaining, data) = parse_mwd(data_buffer)?;
+            (remaining, Message::MWD(data))
+        }
+        MessageType::MWV => {
+            let (remaining, data) = parse_mwv(data_buffer)?;
+            (remaining, Message::MWV(data))
+        }
+        MessageType::VWR => {
+            let (remaining, data) = parse_vwr(data_buffer)?;
+            (remaining, Message::VWR(data))
+        }
+        MessageType::VWT => {
+            let (remaining, data) = parse_vwr(data_buffer)?;
+            (remaining, Message::VWT(data))
+        }
+        MessageType::MTW => {
+            let (remaining, data) = parse_mtw(data_buffer)?;
+            (remaining, Message::MTW(data))
+        }
+        MessageType::VBW => {
+            let (remaining, data) = parse_vbw(data_buffer)?;
+            (remaining, Message::VBW(data))
+        }
+        MessageType::VHW => {
+            let (remaining, data) = parse_vhw(data_buffer)?;
+            (remaining, Message::VHW(data))
+        }
+        MessageType::XDR => {
+            let (remaining, data) = parse_xdr(data_buffer)?;
+            (remaining, Message::XDR(data))
+        }
+        MessageType::ROT => {
+            let (remaining, data) = parse_rot(data_buffer)?;
+            (remaining, Message::ROT(data))
+        }
+        MessageType::RSA => {
+            let (remaining, data) = parse_rsa(data_buffer)?;
+            (remaining, Message::RSA(data))
+        }
+        MessageType::APB => {
+            let (remaining, data) = parse_apb(data_buffer)?;
+            (remaining, Message::APB(data))
+        }
+        MessageType::BOD => {
+            let (remaining, data) = parse_bod(data_buffer)?;
+            (remaining, Message::BOD(data))
+        }
+        MessageType::BWC => {
+            let (remaining, data) = parse_bwc(data_buffer)?;
+            (remaining, Message::BWC(data))
+        }
+        MessageType::BWR => {
+            let (remaining, data) = parse_bwc(data_buffer)?;
+            (remaining, Message::BWR(data))
+        }
+        MessageType::RMB => {
+            let (remaining, data) = parse_rmb(data_buffer)?;
+            (remaining, Message::RMB(data))
+        }
+        MessageType::XTE => {
+            let (remaining, data) = parse_xte(data_buffer)?;
+            (remaining, Message::XTE(data))
+        }
+        MessageType::RTE => {
+            let (remaining, data) = parse_rte(data_buffer)?;
+            (remaining, Message::RTE(data))
+        }
+        MessageType::WPL => {
+            let (remaining, data) = parse_wpl(data_buffer)?;
+            (remaining, Message::WPL(data))
+        }
+        MessageType::OSD => {
+            let (remaining, data) = parse_osd(data_buffer)?;
+            (remaining, Message::OSD(data))
+        }
+        MessageType::TLL => {
+            let (remaining, data) = parse_tll(data_buffer)?;
+            (remaining, Message::TLL(data))
+        }
+        MessageType::TTM => {
+            let (remaining, data) = parse_ttm(data_buffer)?;
+            (remaining, Message::TTM(data))
+        }
+        MessageType::Proprietary => {
+            // The manufacturer mnemonic follows the `P` of the header
+            let manufacturer = &header[1..];
+            (
+                "",
+                Message::Proprietary {
+                    manufacturer,
+                    body: ProprietaryBody::Raw(data_buffer),
+                },
+            )
+        }
+        MessageType::PUBX00 => {
+            let (remaining, data) = parse_pubx00(data_buffer)?;
+            (
+                remaining,
+                Message::Proprietary {
+                    manufacturer: &header[1..],
+                    body: ProprietaryBody::PUBX00(data),
+                },
+            )
+        }
+        MessageType::PUBX03 => {
+            let (remaining, data) = parse_pubx03(data_buffer)?;
+            (
+                remaining,
+                Message::Proprietary {
+                    manufacturer: &header[1..],
+                    body: ProprietaryBody::PUBX03(data),
+                },
+            )
+        }
+        MessageType::PUBX04 => {
+            let (remaining, data) = parse_pubx04(data_buffer)?;
+            (
+                remaining,
+                Message::Proprietary {
+                    manufacturer: &header[1..],
+                    body: ProprietaryBody::PUBX04(data),
+                },
+            )
+        }
+        MessageType::Unknown => {
+            // The message type is the last 3 characters of the header
+            let id = &header[header.len() - 3..];
+            (
+                "",
+                Message::Unknown {
+                    id,
+                    fields: data_buffer,
+                },
+            )
+        }
     };
+    Ok((remaining_data, message))
+}
 
-    if remaining_data.len() == 0 {
-        Ok((
-            remaining_data,
-            Sentence {
-                sentence_type,
-                talker,
-                message,
-            },
-        ))
+/// Describes the field of `data` that starts at, or contains, `rest`
+fn invalid_field(message_type: &MessageType, data: &str, rest: &str) -> Error {
+    let offset = data.len().saturating_sub(rest.len());
+    let offset = if data.is_char_boundary(offset) {
+        offset
     } else {
-        return Err(nom::Err::Failure((input, nom::error::ErrorKind::NonEmpty)));
+        0
+    };
+    let start = data[..offset].rfind(',').map_or(0, |comma| comma + 1);
+    let end = data[start..]
+        .find(',')
+        .map_or(data.len(), |comma| start + comma);
+    let field_index = data[..start].matches(',').count();
+    Error::InvalidField {
+        message: message_type.id(),
+        field_index,
+        field_name: message_type.field_name(field_index),
+        raw: error_string(&data[start..end]),
+    }
+}
+
+fn err_input<'a>(err: &nom::Err<(&'a str, nom::error::ErrorKind)>) -> &'a str {
+    match err {
+        nom::Err::Error((input, _)) | nom::Err::Failure((input, _)) => input,
+        nom::Err::Incomplete(_) => "",
     }
 }
 
-fn get_headers_if_sentence_valid(input: &str) -> IResult<&str, (Talker, MessageType)> {
-    let (after_data, data) = take_until("*")(input)?;
-    // Index subscription is safe because take_until does not consume the pattern
-    let (after_checksum, checksum) = parse_checksum(&after_data[1..])?;
-    if !sentence_is_valid(data, checksum) {
-        return Err(nom::Err::Failure((input, nom::error::ErrorKind::Verify)));
+/// Returns the fields of the sentence, along with its header (talker and message type)
+fn get_headers_if_sentence_valid(
+    input: &str,
+) -> Result<(&str, (&str, Talker, MessageType)), Error> {
+    let star = input.find('*').ok_or(Error::MissingChecksum)?;
+    let (data, after_data) = input.split_at(star);
+    // Index subscription is safe because '*' is a single byte character
+    let (checksum, after_checksum) = parse_checksum(&after_data[1..])?;
+    let computed = compute_checksum(data);
+    if computed != checksum {
+        return Err(Error::ChecksumMismatch {
+            expected: checksum,
+            computed,
+        });
+    }
+    if !after_checksum.starts_with("\r\n") {
+        return Err(Error::MissingCrLf);
     }
-    if crlf(after_checksum)?.0.len() != 0 {
-        return Err(nom::Err::Failure((input, nom::error::ErrorKind::NonEmpty)));
+    if after_checksum.len() != 2 {
+        return Err(Error::Framing);
     }
-    Ok(tuple((parse_talker, parse_message_type))(data)?)
+    if let Some(after_p) = data.strip_prefix('P') {
+        let (body, manufacturer) = parse_manufacturer(after_p)
+            .map_err(|_| Error::UnknownTalker(error_string(data.get(..4).unwrap_or(data))))?;
+        let (fields, message_type) = parse_proprietary_message_type(manufacturer, body);
+        // Index subscription is safe because the manufacturer is made of 3 ASCII characters
+        return Ok((fields, (&data[..4], Talker::Proprietary, message_type)));
+    }
+    let (after_talker, talker) = parse_talker(data)
+        .map_err(|_| Error::UnknownTalker(error_string(data.get(..2).unwrap_or(data))))?;
+    let (fields, message_type) = parse_message_type(after_talker).map_err(|_| {
+        let id = after_talker.split(',').next().unwrap_or(after_talker);
+        Error::UnknownMessageType(error_string(id))
+    })?;
+    let header = data[..data.len() - fields.len()].trim_end_matches(',');
+    Ok((fields, (header, talker, message_type)))
 }
 
-fn parse_checksum(input: &str) -> IResult<&str, u8> {
-    let (after_cs, maybe_cs) = take_until("\r")(input)?;
-    if let Ok(cs) = decode_cs(maybe_cs) {
-        Ok((after_cs, cs))
-    } else {
-        Err(nom::Err::Failure((input, nom::error::ErrorKind::Digit)))
+/// Parses the 3 characters manufacturer mnemonic of a proprietary sentence
+fn parse_manufacturer(input: &str) -> IResult<&str, &str> {
+    match input.get(..3) {
+        Some(manufacturer)
+            if manufacturer
+                .chars()
+                .all(|c| c.is_ascii_uppercase() || c.is_ascii_digit()) =>
+        {
+            Ok((&input[3..], manufacturer))
+        }
+        _ => Err(nom::Err::Failure((input, nom::error::ErrorKind::OneOf))),
+    }
+}
+
+/// Selects the vendor decoder of a proprietary sentence,
+/// the body is kept raw when there is none
+fn parse_proprietary_message_type<'a>(manufacturer: &str, body: &'a str) -> (&'a str, MessageType) {
+    match (manufacturer, body.get(..4)) {
+        // Index subscription is safe because the message ID is made of 4 ASCII characters
+        ("UBX", Some(",00,")) => (&body[4..], MessageType::PUBX00),
+        ("UBX", Some(",03,")) => (&body[4..], MessageType::PUBX03),
+        ("UBX", Some(",04,")) => (&body[4..], MessageType::PUBX04),
+        _ => (body, MessageType::Proprietary),
     }
 }
 
-fn decode_cs(s: &str) -> Result<u8, nom::Err<(&str, nom::error::ErrorKind)>> {
+fn parse_checksum(input: &str) -> Result<(u8, &str), Error> {
     // The checksum is supposed to be 2 characters wide
-    if s.chars().nth(1).is_none() {
-        return Err(nom::Err::Failure((s, nom::error::ErrorKind::Complete)));
-    } else {
-        u8::from_str_radix(&s[0..2], 16)
-            .map_err(|_| nom::Err::Failure((s, nom::error::ErrorKind::Digit)))
+    match (input.get(..2), input.get(2..)) {
+        (Some(cs), Some(remaining)) if cs.chars().all(|c| c.is_ascii_hexdigit()) => {
+            u8::from_str_radix(cs, 16)
+                .map(|cs| (cs, remaining))
+                .map_err(|_| Error::MissingChecksum)
+        }
+        _ => Err(Error::MissingChecksum),
     }
 }
 
-fn sentence_is_valid(data: &str, checksum: u8) -> bool {
-    let computed = data.chars().fold(0, |sum, c| sum ^ c as u8);
-    computed == checksum
+fn compute_checksum(data: &str) -> u8 {
+    data.bytes().fold(0, |sum, c| sum ^ c)
 }
@@ -4,13 +4,55 @@ use crate::messages::*;
 
 #[derive(Debug, PartialEq)]
 pub struct Sentence<'a> {
+    /// IEC 61162-450 tag block preceding the sentence
+    pub tag_block: Option<TagBlock<'a>>,
     pub sentence_type: SentenceType,
     pub talker: Talker,
     pub message: Message<'a>,
 }
 
+#[derive(Debug, Default, PartialEq)]
+/// IEC 61162-450 (NMEA 4.x) tag block, such as `\s:GP0001,c:1577096133*1A\`
+pub struct TagBlock<'a> {
+    /// Source identifier (`s`)
+    pub source: Option<&'a str>,
+    /// Destination identifier (`d`)
+    pub destination: Option<&'a str>,
+    /// UNIX time, in seconds (`c`)
+    pub time: Option<u64>,
+    /// Line count (`n`)
+    pub line_count: Option<u32>,
+    /// Sentence grouping (`g`)
+    pub group: Option<TagBlockGroup>,
+    /// Relative time (`r`)
+    pub relative_time: Option<u64>,
+    /// Free text (`t`)
+    pub text: Option<&'a str>,
+}
+
+#[derive(Debug, PartialEq)]
+/// Links the sentences of a group, such as `g:1-2-73874`
+pub struct TagBlockGroup {
+    /// Number of the sentence in the group, starting at 1
+    pub sentence: u16,
+    /// Number of sentences in the group
+    pub total: u16,
+    /// Identifier shared by the sentences of the group
+    pub id: u32,
+}
+
+// PUBX,03 messages list up to MAX_SATELLITES_STATUS satellites
+#[allow(clippy::large_enum_variant)]
 #[derive(Debug, PartialEq)]
 pub enum Message<'a> {
+    APB(APBMessage<'a>),
+    BOD(BODMessage<'a>),
+    BWC(BWCMessage<'a>),
+    BWR(BWCMessage<'a>),
+    DBK(DBTMessage),
+    DBS(DBTMessage),
+    DBT(DBTMessage),
+    DPT(DPTMessage),
     DTM(DTMMessage<'a>),
     GBQ(GBQMessage<'a>),
     GBS(GBSMessage),
@@ -24,15 +66,71 @@ pub enum Message<'a> {
     GSA(GSAMessage),
     GST(GSTMessage),
     GSV(GSVMessage),
+    HDG(HDGMessage),
+    HDM(HDMMessage),
+    HDT(HDTMessage),
+    MTW(MTWMessage),
+    MWD(MWDMessage),
+    MWV(MWVMessage),
+    OSD(OSDMessage),
+    RMB(RMBMessage<'a>),
     RMC(RMCMessage),
+    ROT(ROTMessage),
+    RSA(RSAMessage),
+    RTE(RTEMessage<'a>),
+    THS(THSMessage),
+    TLL(TLLMessage<'a>),
+    TTM(TTMMessage<'a>),
     TXT(TXTMessage<'a>),
+    VBW(VBWMessage),
+    VDM(VDMMessage<'a>),
+    VDO(VDMMessage<'a>),
+    VHW(VHWMessage),
     VLW(VLWMessage),
     VTG(VTGMessage),
+    VWR(VWRMessage),
+    VWT(VWRMessage),
+    WPL(WPLMessage<'a>),
+    XDR(XDRMessage<'a>),
+    XTE(XTEMessage),
     ZDA(ZDAMessage),
+    /// A message this crate doesn't know how to parse, its checksum has been validated
+    Unknown {
+        /// Message type, such as `ALM`
+        id: &'a str,
+        /// Raw comma separated fields
+        fields: &'a str,
+    },
+    /// A `$P` sentence, its checksum has been validated
+    Proprietary {
+        /// Manufacturer mnemonic, such as `UBX` for `$PUBX`
+        manufacturer: &'a str,
+        body: ProprietaryBody<'a>,
+    },
+}
+
+/// Content of a proprietary sentence, decoded when the manufacturer is known
+#[allow(clippy::large_enum_variant)]
+#[derive(Debug, PartialEq)]
+pub enum ProprietaryBody<'a> {
+    /// Everything following the manufacturer mnemonic,
+    /// such as `,00,...` for `$PUBX,00,...` or `001,604,3` for `$PMTK001,604,3`
+    Raw(&'a str),
+    PUBX00(PUBX00Message),
+    PUBX03(PUBX03Message),
+    PUBX04(PUBX04Message),
 }
 
 #[derive(Debug, PartialEq)]
 pub(crate) enum MessageType {
+    APB,
+    BOD,
+    BWC,
+    BWR,
+    DBK,
+    DBS,
+    DBT,
+    DPT,
     DTM,
     GBQ,
     GBS,
@@ -46,9 +144,162 @@ pub(crate) enum MessageType {
     GSA,
     GST,
     GSV,
+    HDG,
+    HDM,
+    HDT,
+    MTW,
+    MWD,
+    MWV,
+    OSD,
+    RMB,
     RMC,
+    ROT,
+    RSA,
+    RTE,
+    THS,
+    TLL,
+    TTM,
     TXT,
+    VBW,
+    VDM,
+    VDO,
+    VHW,
     VLW,
     VTG,
+    VWR,
+    VWT,
+    WPL,
+    XDR,
+    XTE,
     ZDA,
+    Unknown,
+    Proprietary,
+    PUBX00,
+    PUBX03,
+    PUBX04,
+}
+
+impl MessageType {
+    pub(crate) fn id(&self) -> &'static str {
+        match self {
+            MessageType::APB => "APB",
+            MessageType::BOD => "BOD",
+            MessageType::BWC => "BWC",
+            MessageType::BWR => "BWR",
+            MessageType::DBK => "DBK",
+            MessageType::DBS => "DBS",
+            MessageType::DBT => "DBT",
+            MessageType::DPT => "DPT",
+            MessageType::DTM => "DTM",
+            MessageType::GBQ => "GBQ",
+            MessageType::GBS => "GBS",
+            MessageType::GGA => "GGA",
+            MessageType::GLL => "GLL",
+            MessageType::GLQ => "GLQ",
+            MessageType::GNQ => "GNQ",
+            MessageType::GNS => "GNS",
+            MessageType::GPQ => "GPQ",
+            MessageType::GRS => "GRS",
+            MessageType::GSA => "GSA",
+            MessageType::GST => "GST",
+            MessageType::GSV => "GSV",
+            MessageType::HDG => "HDG",
+            MessageType::HDM => "HDM",
+            MessageType::HDT => "HDT",
+            MessageType::MTW => "MTW",
+            MessageType::MWD => "MWD",
+            MessageType::MWV => "MWV",
+            MessageType::OSD => "OSD",
+            MessageType::RMB => "RMB",
+            MessageType::RMC => "RMC",
+            MessageType::ROT => "ROT",
+            MessageType::RSA => "RSA",
+            MessageType::RTE => "RTE",
+            MessageType::THS => "THS",
+            MessageType::TLL => "TLL",
+            MessageType::TTM => "TTM",
+            MessageType::TXT => "TXT",
+            MessageType::VBW => "VBW",
+            MessageType::VDM => "VDM",
+            MessageType::VDO => "VDO",
+            MessageType::VHW => "VHW",
+            MessageType::VLW => "VLW",
+            MessageType::VTG => "VTG",
+            MessageType::VWR => "VWR",
+            MessageType::VWT => "VWT",
+            MessageType::WPL => "WPL",
+            MessageType::XDR => "XDR",
+            MessageType::XTE => "XTE",
+            MessageType::ZDA => "ZDA",
+            MessageType::PUBX00 => "PUBX00",
+            MessageType::PUBX03 => "PUBX03",
+            MessageType::PUBX04 => "PUBX04",
+            MessageType::Unknown | MessageType::Proprietary => "",
+        }
+    }
+
+    /// Name of the field at an index, the measurement fields of XDR messages
+    /// and the satellite fields of PUBX,03 messages repeat
+    pub(crate) fn field_name(&self, index: usize) -> &'static str {
+        let fields = self.fields();
+        let index = match self {
+            MessageType::XDR => index % fields.len(),
+            MessageType::PUBX03 if index > 0 => 1 + (index - 1) % (fields.len() - 1),
+            _ => index,
+        };
+        fields.get(index).copied().unwrap_or("")
+    }
+
+    pub(crate) fn fields(&self) -> &'static [&'static str] {
+        match self {
+            MessageType::APB => apb::FIELDS,
+            MessageType::BOD => bod::FIELDS,
+            MessageType::BWC | MessageType::BWR => bwc::FIELDS,
+            MessageType::DBK | MessageType::DBS | MessageType::DBT => dbt::FIELDS,
+            MessageType::DPT => dpt::FIELDS,
+            MessageType::DTM => dtm::FIELDS,
+            MessageType::GBQ => gbq::FIELDS,
+            MessageType::GBS => gbs::FIELDS,
+            MessageType::GGA => gga::FIELDS,
+            MessageType::GLL => gll::FIELDS,
+            MessageType::GLQ => glq::FIELDS,
+            MessageType::GNQ => gnq::FIELDS,
+            MessageType::GNS => gns::FIELDS,
+            MessageType::GPQ => gpq::FIELDS,
+            MessageType::GRS => grs::FIELDS,
+            MessageType::GSA => gsa::FIELDS,
+            MessageType::GST => gst::FIELDS,
+            MessageType::GSV => gsv::FIELDS,
+            MessageType::HDG => hdg::FIELDS,
+            MessageType::HDM => hdm::FIELDS,
+            MessageType::HDT => hdt::FIELDS,
+            MessageType::MTW => mtw::FIELDS,
+            MessageType::MWD => mwd::FIELDS,
+            MessageType::MWV => mwv::FIELDS,
+            MessageType::OSD => osd::FIELDS,
+            MessageType::RMB => rmb::FIELDS,
+            MessageType::RMC => rmc::FIELDS,
+            MessageType::ROT => rot::FIELDS,
+            MessageType::RSA => rsa::FIELDS,
+            MessageType::RTE => rte::FIELDS,
+            MessageType::THS => ths::FIELDS,
+            MessageType::TLL => tll::FIELDS,
+            MessageType::TTM => ttm::FIELDS,
+            MessageType::TXT => txt::FIELDS,
+            MessageType::VBW => vbw::FIELDS,
+            MessageType::VDM | MessageType::VDO => vdm::FIELDS,
+            MessageType::VHW => vhw::FIELDS,
+            MessageType::VLW => vlw::FIELDS,
+            MessageType::VTG => vtg::FIELDS,
+            MessageType::VWR | MessageType::VWT => vwr::FIELDS,
+            MessageType::WPL => wpl::FIELDS,
+            MessageType::XDR => xdr::FIELDS,
+            MessageType::XTE => xte::FIELDS,
+            MessageType::ZDA => zda::FIELDS,
+            MessageType::PUBX00 => pubx00::FIELDS,
+            MessageType::PUBX03 => pubx03::FIELDS,
+            MessageType::PUBX04 => pubx04::FIELDS,
+            MessageType::Unknown | MessageType::Proprietary => &[],
+        }
+    }
 }
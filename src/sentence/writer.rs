@@ -0,0 +1,149 @@
+use super::structs::*;
+use crate::writer_utils::ChecksumWriter;
+use core::fmt::{self, Write};
+
+impl<'a> fmt::Display for Message<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Message::APB(data) => write!(f, "APB,{}", data),
+            Message::BOD(data) => write!(f, "BOD,{}", data),
+            Message::BWC(data) => write!(f, "BWC,{}", data),
+            Message::BWR(data) => write!(f, "BWR,{}", data),
+            Message::DBK(data) => write!(f, "DBK,{}", data),
+            Message::DBS(data) => write!(f, "DBS,{}", data),
+            Message::DBT(data) => write!(f, "DBT,{}", data),
+            Message::DPT(data) => write!(f, "DPT,{}", data),
+            Message::DTM(data) => write!(f, "DTM,{}", data),
+            Message::GBQ(data) => write!(f, "GBQ,{}", data),
+            Message::GBS(data) => write!(f, "GBS,{}", data),
+            Message::GGA(data) => write!(f, "GGA,{}", data),
+            Message::GLL(data) => write!(f, "GLL,{}", data),
+            Message::GLQ(data) => write!(f, "GLQ,{}", data),
+            Message::GNQ(data) => write!(f, "GNQ,{}", data),
+            Message::GNS(data) => write!(f, "GNS,{}", data),
+            Message::GPQ(data) => write!(f, "GPQ,{}", data),
+            Message::GRS(data) => write!(f, "GRS,{}", data),
+            Message::GSA(data) => write!(f, "GSA,{}", data),
+            Message::GST(data) => write!(f, "GST,{}", data),
+            Message::GSV(data) => write!(f, "GSV,{}", data),
+            Message::HDG(data) => write!(f, "HDG,{}", data),
+            Message::HDM(data) => write!(f, "HDM,{}", data),
+            Message::HDT(data) => write!(f, "HDT,{}", data),
+            Message::MTW(data) => write!(f, "MTW,{}", data),
+            Message::MWD(data) => write!(f, "MWD,{}", data),
+            Message::MWV(data) => write!(f, "MWV,{}", data),
+            Message::OSD(data) => write!(f, "OSD,{}", data),
+            Message::RMB(data) => write!(f, "RMB,{}", data),
+            Message::RMC(data) => write!(f, "RMC,{}", data),
+            Message::ROT(data) => write!(f, "ROT,{}", data),
+            Message::RSA(data) => write!(f, "RSA,{}", data),
+            Message::RTE(data) => write!(f, "RTE,{}", data),
+            Message::THS(data) => write!(f, "THS,{}", data),
+            Message::TLL(data) => write!(f, "TLL,{}", data),
+            Message::TTM(data) => write!(f, "TTM,{}", data),
+            Message::TXT(data) => write!(f, "TXT,{}", data),
+            Message::VBW(data) => write!(f, "VBW,{}", data),
+            Message::VDM(data) => write!(f, "VDM,{}", data),
+            Message::VDO(data) => write!(f, "VDO,{}", data),
+            Message::VHW(data) => write!(f, "VHW,{}", data),
+            Message::VLW(data) => write!(f, "VLW,{}", data),
+            Message::VTG(data) => write!(f, "VTG,{}", data),
+            Message::VWR(data) => write!(f, "VWR,{}", data),
+            Message::VWT(data) => write!(f, "VWT,{}", data),
+            Message::WPL(data) => write!(f, "WPL,{}", data),
+            Message::XDR(data) => write!(f, "XDR,{}", data),
+            Message::XTE(data) => write!(f, "XTE,{}", data),
+            Message::ZDA(data) => write!(f, "ZDA,{}", data),
+            Message::Unknown { id, fields: "" } => f.write_str(id),
+            Message::Unknown { id, fields } => write!(f, "{},{}", id, fields),
+            Message::Proprietary { manufacturer, body } => write!(f, "{}{}", manufacturer, body),
+        }
+    }
+}
+
+impl<'a> fmt::Display for ProprietaryBody<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ProprietaryBody::Raw(body) => f.write_str(body),
+            ProprietaryBody::PUBX00(data) => write!(f, ",00,{}", data),
+            ProprietaryBody::PUBX03(data) => write!(f, ",03,{}", data),
+            ProprietaryBody::PUBX04(data) => write!(f, ",04,{}", data),
+        }
+    }
+}
+
+impl<'a> fmt::Display for TagBlock<'a> {
+    /// Writes the parameters in the s, d, c, n, g, r, t order, with the tag block delimiters
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("\\")?;
+        let mut checksummed = ChecksumWriter::new(f);
+        let mut separator = "";
+        let mut parameter = |code: char, value: &dyn fmt::Display| {
+            let result = write!(checksummed, "{}{}:{}", separator, code, value);
+            separator = ",";
+            result
+        };
+        if let Some(source) = self.source {
+            parameter('s', &source)?;
+        }
+        if let Some(destination) = self.destination {
+            parameter('d', &destination)?;
+        }
+        if let Some(time) = self.time {
+            parameter('c', &time)?;
+        }
+        if let Some(line_count) = self.line_count {
+            parameter('n', &line_count)?;
+        }
+        if let Some(group) = &self.group {
+            parameter('g', group)?;
+        }
+        if let Some(relative_time) = self.relative_time {
+            parameter('r', &relative_time)?;
+        }
+        if let Some(text) = self.text {
+            parameter('t', &text)?;
+        }
+        let checksum = checksummed.checksum;
+        write!(f, "*{:02X}\\", checksum)
+    }
+}
+
+impl fmt::Display for TagBlockGroup {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}-{}-{}", self.sentence, self.total, self.id)
+    }
+}
+
+impl<'a> Sentence<'a> {
+    /// Writes the sentence in the NMEA-0183 format,
+    /// with its checksum and the terminating CR LF.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use nmea_0183::parse;
+    /// let raw_nmea = "$GPGLL,4717.11364,N,00833.91565,E,092321.00,A,A*60\r\n";
+    /// let sentence = parse(raw_nmea).unwrap();
+    ///
+    /// let mut output = String::new();
+    /// sentence.write_to(&mut output).unwrap();
+    /// assert_eq!(raw_nmea, output);
+    /// ```
+    pub fn write_to<W: Write>(&self, w: &mut W) -> fmt::Result {
+        if let Some(tag_block) = &self.tag_block {
+            write!(w, "{}", tag_block)?;
+        }
+        write!(w, "{}", self.sentence_type)?;
+        let mut checksummed = ChecksumWriter::new(w);
+        write!(checksummed, "{}{}", self.talker, self.message)?;
+        let checksum = checksummed.checksum;
+        write!(w, "*{:02X}\r\n", checksum)
+    }
+}
+
+impl<'a> fmt::Display for Sentence<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.write_to(f)
+    }
+}
@@ -0,0 +1,277 @@
+use crate::fields::distance::Degree;
+use crate::fields::parameter::{Status, WindReference};
+use crate::fields::speed::{Knot, MeterPerSecond, SpeedOverGroundUnit};
+use crate::math_utils::{normalize, round};
+use crate::messages::{MWDMessage, MWVMessage, RMCMessage, VHWMessage, VTGMessage};
+
+#[derive(Debug, Clone, PartialEq)]
+/// Wind measured on board a moving vessel
+pub struct ApparentWind {
+    /// Angle the wind comes from, clockwise from the bow
+    pub angle: Degree,
+    pub speed: Knot,
+}
+
+impl ApparentWind {
+    /// Reads the apparent wind of a valid MWV message with a relative reference.
+    pub fn from_mwv(message: &MWVMessage) -> Option<Self> {
+        if message.reference != WindReference::Relative || message.status != Status::DataValid {
+            return None;
+        }
+        Some(ApparentWind {
+            angle: message.angle.clone()?,
+            speed: message.speed_knots()?,
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+/// Wind as if the vessel was stationary
+pub struct TrueWind {
+    /// Angle the wind comes from, clockwise from the bow
+    pub angle: Degree,
+    pub speed: Knot,
+    /// Direction the wind comes from, in degrees true, known when the heading is
+    pub direction: Option<Degree>,
+}
+
+/// Speed through water of a VHW message
+pub fn speed_from_vhw(message: &VHWMessage) -> Option<Knot> {
+    match (message.speed, message.speed_kmh) {
+        (Some(speed), _) => Some(speed),
+        (None, Some(speed)) => Some(SpeedOverGroundUnit::KilometersPerHour.to_knots(speed)),
+        _ => None,
+    }
+}
+
+/// Speed over ground of a VTG message
+pub fn speed_from_vtg(message: &VTGMessage) -> Option<Knot> {
+    match (
+        message.sogn,
+        &message.sogn_unit,
+        message.sogk,
+        &message.sogk_unit,
+    ) {
+        (Some(speed), Some(unit), _, _) | (_, _, Some(speed), Some(unit)) => {
+            Some(unit.to_knots(speed))
+        }
+        _ => None,
+    }
+}
+
+/// Speed over ground of a valid RMC message
+pub fn speed_from_rmc(message: &RMCMessage) -> Option<Knot> {
+    if message.status != Status::DataValid {
+        return None;
+    }
+    message.spd
+}
+
+/// Computes the true wind from the apparent wind and the vessel speed.
+///
+/// With the speed through water and no course, the vessel moves along its bow,
+/// and the result is the wind relative to the water.
+/// With the speed over ground and the course over ground, the result is the wind relative to the ground,
+/// the course being taken into account when the true heading is known.
+/// The wind direction is computed when the true heading is known.
+pub fn compute_true_wind(
+    apparent: &ApparentWind,
+    boat_speed: Knot,
+    heading: Option<Degree>,
+    course: Option<Degree>,
+) -> TrueWind {
+    let apparent_angle = apparent.angle.0.to_radians();
+    // Direction of the vessel motion, clockwise from the bow, away from it with leeway or current
+    let motion_angle = match (&heading, &course) {
+        (Some(heading), Some(course)) => (course.0 - heading.0).to_radians(),
+        _ => 0.,
+    };
+    // Components of the wind along the bow and towards starboard, minus the wind made by the vessel
+    let ahead = apparent.speed.0 * apparent_angle.cos() - boat_speed.0 * motion_angle.cos();
+    let abeam = apparent.speed.0 * apparent_angle.sin() - boat_speed.0 * motion_angle.sin();
+
+    let speed = ahead.hypot(abeam);
+    let angle = if speed == 0. {
+        0.
+    } else {
+        normalize(abeam.atan2(ahead).to_degrees())
+    };
+    TrueWind {
+        angle: Degree(angle),
+        speed: Knot(speed),
+        direction: heading.map(|heading| Degree(normalize(heading.0 + angle))),
+    }
+}
+
+impl TrueWind {
+    /// MWV message with a true reference, angle and speed rounded to a tenth
+    pub fn to_mwv(&self) -> MWVMessage {
+        MWVMessage {
+            angle: Some(Degree(round(self.angle.0, 1))),
+            reference: WindReference::True,
+            speed: Some(round(self.speed.0, 1)),
+            speed_unit: Some(SpeedOverGroundUnit::Knots),
+            status: Status::DataValid,
+        }
+    }
+
+    /// MWD message, direction and speeds rounded to a tenth, when the direction is known
+    pub fn to_mwd(&self) -> Option<MWDMessage> {
+        let direction = self.direction.as_ref()?;
+        Some(MWDMessage {
+            direction_true: Some(Degree(round(direction.0, 1))),
+            direction_magnetic: None,
+            speed: Some(Knot(round(self.speed.0, 1))),
+            speed_ms: Some(MeterPerSecond(round(MeterPerSecond::from(self.speed).0, 1))),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fields::identity::Talker;
+    use crate::fields::parameter::SentenceType;
+    use crate::parse;
+    use crate::sentence::{Message, Sentence};
+
+    fn assert_close(expected: f64, actual: f64) {
+        assert!(
+            (expected - actual).abs() < 1e-6,
+            "expected {}, got {}",
+            expected,
+            actual
+        );
+    }
+
+    fn apparent(angle: f64, speed: f64) -> ApparentWind {
+        ApparentWind {
+            angle: Degree(angle),
+            speed: Knot(speed),
+        }
+    }
+
+    #[test]
+    fn test_head_wind() {
+        let wind = compute_true_wind(&apparent(0., 15.), Knot(5.), Some(Degree(90.)), None);
+        assert_close(0., wind.angle.0);
+        assert_close(10., wind.speed.0);
+        assert_close(90., wind.direction.unwrap().0);
+    }
+
+    #[test]
+    fn test_beam_reach() {
+        // A 5 knots wind from port, and a 5 knots vessel speed, make an apparent wind 45 degrees to port
+        let apparent_speed = 50_f64.sqrt();
+        let wind = compute_true_wind(
+            &apparent(315., apparent_speed),
+            Knot(5.),
+            Some(Degree(10.)),
+            None,
+        );
+        assert_close(270., wind.angle.0);
+        assert_close(5., wind.speed.0);
+        assert_close(280., wind.direction.unwrap().0);
+    }
+
+    #[test]
+    fn test_ground_wind_with_current() {
+        // Heading north, set east by the current: the vessel moves along the apparent wind, no ground wind
+        let speed = 50_f64.sqrt();
+        let wind = compute_true_wind(
+            &apparent(45., speed),
+            Knot(speed),
+            Some(Degree(0.)),
+            Some(Degree(45.)),
+        );
+        assert_close(0., wind.speed.0);
+
+        // Without the heading, the motion is taken along the bow
+        let along_bow = compute_true_wind(&apparent(45., speed), Knot(speed), None, None);
+        let wind = compute_true_wind(&apparent(45., speed), Knot(speed), None, Some(Degree(45.)));
+        assert_eq!(along_bow, wind);
+    }
+
+    #[test]
+    fn test_stationary_vessel() {
+        let wind = compute_true_wind(&apparent(123.4, 8.), Knot(0.), None, None);
+        assert_close(123.4, wind.angle.0);
+        assert_close(8., wind.speed.0);
+        assert_eq!(None, wind.direction);
+    }
+
+    #[test]
+    fn test_apparent_wind_from_mwv() {
+        let sentence = parse("$WIMWV,214.8,R,0.1,N,A*2D\r\n").unwrap();
+        if let Message::MWV(mwv) = sentence.message {
+            assert_eq!(Some(apparent(214.8, 0.1)), ApparentWind::from_mwv(&mwv));
+        } else {
+            panic!("unexpected message");
+        }
+
+        let sentence = parse("$WIMWV,45.5,T,10.4,M,A*27\r\n").unwrap();
+        if let Message::MWV(mwv) = sentence.message {
+            assert_eq!(None, ApparentWind::from_mwv(&mwv));
+        } else {
+            panic!("unexpected message");
+        }
+    }
+
+    #[test]
+    fn test_speed_from_vhw() {
+        let sentence = parse("$VWVHW,245.1,T,245.1,M,6.3,N,11.7,K*66\r\n").unwrap();
+        if let Message::VHW(vhw) = sentence.message {
+            assert_eq!(Some(Knot(6.3)), speed_from_vhw(&vhw));
+        } else {
+            panic!("unexpected message");
+        }
+    }
+
+    #[test]
+    fn test_speed_from_vtg_and_rmc() {
+        let sentence = parse("$GPVTG,77.52,T,,M,0.004,N,0.008,K,A*06\r\n").unwrap();
+        if let Message::VTG(vtg) = sentence.message {
+            assert_eq!(Some(Knot(0.004)), speed_from_vtg(&vtg));
+        } else {
+            panic!("unexpected message");
+        }
+
+        let sentence =
+            parse("$GPRMC,083559.00,A,4717.11437,N,00833.91522,E,0.004,77.52,091202,,,A,V*2D\r\n")
+                .unwrap();
+        if let Message::RMC(rmc) = sentence.message {
+            assert_eq!(Some(Knot(0.004)), speed_from_rmc(&rmc));
+        } else {
+            panic!("unexpected message");
+        }
+    }
+
+    #[test]
+    fn test_write_true_wind() {
+        let wind = TrueWind {
+            angle: Degree(270.04),
+            speed: Knot(5.),
+            direction: Some(Degree(280.04)),
+        };
+        let mwv = Sentence {
+            tag_block: None,
+            sentence_type: SentenceType::Parametric,
+            talker: Talker::WeatherInstruments,
+            message: Message::MWV(wind.to_mwv()),
+        };
+        assert_eq!("$WIMWV,270,T,5,N,A*25\r\n", mwv.to_string());
+        let mwd = Sentence {
+            tag_block: None,
+            sentence_type: SentenceType::Parametric,
+            talker: Talker::WeatherInstruments,
+            message: Message::MWD(wind.to_mwd().unwrap()),
+        };
+        assert_eq!("$WIMWD,280,T,,M,5,N,2.6,M*7F\r\n", mwd.to_string());
+
+        let wind = TrueWind {
+            direction: None,
+            ..wind
+        };
+        assert_eq!(None, wind.to_mwd());
+    }
+}
@@ -0,0 +1,77 @@
+use core::fmt;
+
+/// Writes the value if there is one, and nothing otherwise,
+/// which is how NMEA represents a missing field.
+pub struct Optional<'a, T>(pub &'a Option<T>);
+
+impl<'a, T: fmt::Display> fmt::Display for Optional<'a, T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.0 {
+            Some(value) => value.fmt(f),
+            None => Ok(()),
+        }
+    }
+}
+
+/// Writes every optional item, separated by a comma.
+pub struct Separated<'a, T>(pub &'a [Option<T>]);
+
+impl<'a, T: fmt::Display> fmt::Display for Separated<'a, T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (i, item) in self.0.iter().enumerate() {
+            if i != 0 {
+                f.write_str(",")?;
+            }
+            Optional(item).fmt(f)?;
+        }
+        Ok(())
+    }
+}
+
+/// Forwards everything to the inner writer
+/// and computes the NMEA checksum of what has been written.
+pub struct ChecksumWriter<'a, W> {
+    inner: &'a mut W,
+    pub checksum: u8,
+}
+
+impl<'a, W: fmt::Write> ChecksumWriter<'a, W> {
+    pub fn new(inner: &'a mut W) -> Self {
+        ChecksumWriter { inner, checksum: 0 }
+    }
+}
+
+impl<'a, W: fmt::Write> fmt::Write for ChecksumWriter<'a, W> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.checksum = s.bytes().fold(self.checksum, |sum, c| sum ^ c);
+        self.inner.write_str(s)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core::fmt::Write;
+
+    #[test]
+    fn test_write_optional() {
+        assert_eq!("12.5", format!("{}", Optional(&Some(12.5))));
+        assert_eq!("", format!("{}", Optional::<f64>(&None)));
+    }
+
+    #[test]
+    fn test_write_separated() {
+        let items = [Some(1), None, Some(3)];
+        assert_eq!("1,,3", format!("{}", Separated(&items)));
+    }
+
+    #[test]
+    fn test_checksum_writer() {
+        let mut output = String::new();
+        let mut writer = ChecksumWriter::new(&mut output);
+        writer.write_str("UPGBQ,").unwrap();
+        writer.write_str("RMC").unwrap();
+        assert_eq!(0x21, writer.checksum);
+        assert_eq!("UPGBQ,RMC", output);
+    }
+}